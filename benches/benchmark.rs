@@ -1,7 +1,13 @@
 #![allow(unstable_features)]
+// Every year crate is a dependency of the bench target, but is only used through `aoc`.
+#![allow(unused_crate_dependencies)]
 #![feature(test)]
 extern crate test;
 
+#[cfg(feature = "compare")]
+#[path = "../tests/common/mod.rs"]
+mod common;
+
 macro_rules! benchmark {
     ($year:tt $($day:tt),*) => {
         mod $year {$(
@@ -90,3 +96,94 @@ benchmark!(year2024
     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
 );
+
+/// Compares building the expanded risk map against computing each risk on the fly.
+mod year2021_day15_variants {
+    use aoc::year2021::day15::*;
+    use std::fs::read_to_string;
+    use test::Bencher;
+
+    fn input() -> Square {
+        parse(&read_to_string("input/year2021/day15.txt").unwrap())
+    }
+
+    #[bench]
+    fn materialized_bench(b: &mut Bencher) {
+        let input = input();
+        b.iter(|| materialized(&input));
+    }
+
+    #[bench]
+    fn implicit_bench(b: &mut Bencher) {
+        let input = input();
+        b.iter(|| implicit(&input));
+    }
+}
+
+/// Measures a single generation of the sea cucumber simulation.
+mod year2021_day25_step {
+    use aoc::year2021::day25::*;
+    use std::fs::read_to_string;
+    use test::Bencher;
+
+    #[bench]
+    fn step_bench(b: &mut Bencher) {
+        let input = parse(&read_to_string("input/year2021/day25.txt").unwrap());
+        b.iter(|| step(input.clone()));
+    }
+}
+
+/// Compares hashing every step with the scalar fold against a single SIMD vector per step.
+#[cfg(feature = "compare")]
+mod year2023_day15_hash {
+    use crate::common::Lcg;
+    use aoc::year2023::day15::*;
+    use std::iter::repeat_with;
+    use test::Bencher;
+
+    /// Random steps drawn from a pool of labels, as bytes along with the span of each step.
+    fn input() -> (Vec<u8>, Vec<(usize, usize)>) {
+        let mut rng = Lcg::new(1);
+        let labels: Vec<Vec<_>> = repeat_with(|| {
+            let size = 2 + rng.below(5);
+            repeat_with(|| rng.pick(b"abcdefghijklmnopqrstuvwxyz")).take(size).collect()
+        })
+        .take(500)
+        .collect();
+
+        let mut input = Vec::new();
+        let mut spans = Vec::new();
+
+        for _ in 0..4000 {
+            let start = input.len();
+            input.extend_from_slice(&labels[rng.below(labels.len())]);
+            if rng.below(5) < 2 {
+                input.push(b'-');
+            } else {
+                input.push(b'=');
+                input.push(rng.pick(b"123456789"));
+            }
+            spans.push((start, input.len()));
+            input.push(b',');
+        }
+
+        (input, spans)
+    }
+
+    #[bench]
+    fn scalar_bench(b: &mut Bencher) {
+        let (input, spans) = input();
+        b.iter(|| spans.iter().map(|&(start, end)| hash(&input[start..end])).sum::<usize>());
+    }
+
+    #[bench]
+    fn simd_bench(b: &mut Bencher) {
+        let (input, spans) = input();
+        b.iter(|| {
+            spans
+                .iter()
+                .map(|&(start, end)| simd::hash_in(&input, end, &input[start..end]))
+                .sum::<usize>()
+        });
+    }
+}
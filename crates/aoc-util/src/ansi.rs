@@ -2,6 +2,13 @@
 //!
 //! These codes allow command line applications to show colored or styled text in most terminals.
 //! Advanced commands can move the cursor or clear the screen.
+//!
+//! ```
+//!   # use aoc_util::ansi::*;
+//!
+//!   let message = format!("{BOLD}{GREEN}Passed{RESET}");
+//!   assert_eq!(message, "\x1b[1m\x1b[32mPassed\x1b[0m");
+//! ```
 
 pub const RESET: &str = "\x1b[0m";
 pub const BOLD: &str = "\x1b[1m";
@@ -0,0 +1,190 @@
+//! Finds every occurrence of many patterns at once in a single pass over the text.
+//!
+//! The [Aho-Corasick](https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm) automaton
+//! starts as a trie of the patterns. Each node then gets a failure link to the longest proper
+//! suffix of its prefix that is also in the trie, and a dictionary link to the nearest node
+//! along the failure links that completes a pattern. Following the failure links ahead of time
+//! for every missing transition turns the trie into a deterministic automaton, so scanning the
+//! text is exactly one table lookup per byte, regardless of the number of patterns.
+//!
+//! Puzzles usually only use a handful of distinct bytes, so each byte is first mapped to a
+//! small equivalence class. Bytes that don't appear in any pattern share class zero, which
+//! always leads back to the root. This keeps the transition table compact.
+//!
+//! [`find_iter`] returns overlapping matches in order of their end position. Matches with the
+//! same end are returned longest first. [`count_ends`] is a faster alternative when only the
+//! number of matches ending at each position is needed.
+//!
+//! ```
+//!   # use aoc_util::automaton::*;
+//!
+//!   let automaton = Automaton::new(["he", "she", "his", "hers"]);
+//!   let matches: Vec<_> = automaton.find_iter(b"ushers").collect();
+//!
+//!   assert_eq!(matches, [
+//!       Match { pattern: 1, start: 1, end: 4 },
+//!       Match { pattern: 0, start: 2, end: 4 },
+//!       Match { pattern: 3, start: 2, end: 6 },
+//!   ]);
+//!   assert_eq!(automaton.count_ends(b"ushers"), [0, 0, 0, 0, 2, 0, 1]);
+//!   assert!(automaton.is_match(b"this"));
+//!   assert!(!automaton.is_match(b"ash"));
+//! ```
+//!
+//! [`find_iter`]: Automaton::find_iter
+//! [`count_ends`]: Automaton::count_ends
+use std::iter::successors;
+
+/// Occurrence of a pattern at `start..end` in the text. `pattern` is the index of the pattern
+/// in the order that they were passed to [`Automaton::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+pub struct Automaton {
+    /// Equivalence class of each byte.
+    classes: [usize; 256],
+    /// Number of classes, the width of each row of the transition table.
+    width: usize,
+    /// Next state for each state and class, indexed by `state * width + class`.
+    goto: Vec<usize>,
+    /// Patterns that end exactly at each state.
+    patterns: Vec<Vec<usize>>,
+    /// Nearest state along the failure links that has patterns, if any.
+    dictionary: Vec<Option<usize>>,
+    /// Total number of patterns that match on reaching each state.
+    counts: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+impl Automaton {
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let patterns: Vec<_> = patterns.into_iter().collect();
+        let lengths: Vec<_> = patterns.iter().map(|p| p.as_ref().len()).collect();
+
+        let mut classes = [0; 256];
+        let mut width = 1;
+
+        for &b in patterns.iter().flat_map(AsRef::as_ref) {
+            if classes[b as usize] == 0 {
+                classes[b as usize] = width;
+                width += 1;
+            }
+        }
+
+        // Build the trie. State zero is the root, so it also marks a missing child.
+        let mut goto = vec![0; width];
+        let mut ends = vec![Vec::new()];
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            let mut state = 0;
+
+            for &b in pattern.as_ref() {
+                let slot = state * width + classes[b as usize];
+                if goto[slot] == 0 {
+                    goto[slot] = ends.len();
+                    goto.resize(goto.len() + width, 0);
+                    ends.push(Vec::new());
+                }
+                state = goto[slot];
+            }
+
+            ends[state].push(index);
+        }
+
+        // Breadth first search guarantees that a failure link is always to a state that has
+        // already been completed, so its transitions can be copied for any missing children.
+        let size = ends.len();
+        let mut fail = vec![0; size];
+        let mut dictionary = vec![None; size];
+        let mut counts = vec![0; size];
+        let mut todo: Vec<_> = (1..width).map(|class| goto[class]).filter(|&s| s != 0).collect();
+        let mut index = 0;
+
+        counts[0] = ends[0].len();
+        for &state in &todo {
+            counts[state] = ends[state].len() + counts[0];
+            dictionary[state] = (!ends[0].is_empty()).then_some(0);
+        }
+
+        while index < todo.len() {
+            let state = todo[index];
+            index += 1;
+
+            for class in 1..width {
+                let next = goto[state * width + class];
+                let fallback = goto[fail[state] * width + class];
+
+                if next == 0 {
+                    goto[state * width + class] = fallback;
+                } else {
+                    fail[next] = fallback;
+                    dictionary[next] = if ends[fallback].is_empty() {
+                        dictionary[fallback]
+                    } else {
+                        Some(fallback)
+                    };
+                    counts[next] = ends[next].len() + counts[fallback];
+                    todo.push(next);
+                }
+            }
+        }
+
+        Automaton { classes, width, goto, patterns: ends, dictionary, counts, lengths }
+    }
+
+    #[inline]
+    fn step(&self, state: usize, b: u8) -> usize {
+        self.goto[state * self.width + self.classes[b as usize]]
+    }
+
+    /// Every match in `text`, including overlapping matches.
+    pub fn find_iter<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        let states = text.iter().scan(0, |state, &b| {
+            *state = self.step(*state, b);
+            Some(*state)
+        });
+
+        // An empty pattern also matches at the very start of the text.
+        let states = Some(0).into_iter().chain(states);
+
+        states.enumerate().flat_map(move |(end, state)| {
+            let first =
+                if self.patterns[state].is_empty() { self.dictionary[state] } else { Some(state) };
+            successors(first, |&s| self.dictionary[s])
+                .flat_map(|s| &self.patterns[s])
+                .map(move |&pattern| Match { pattern, start: end - self.lengths[pattern], end })
+        })
+    }
+
+    /// Number of matches that end at each position from `0` to `text.len()` inclusive.
+    pub fn count_ends(&self, text: &[u8]) -> Vec<usize> {
+        let mut state = 0;
+        let mut result = Vec::with_capacity(text.len() + 1);
+        result.push(self.patterns[0].len());
+
+        for &b in text {
+            state = self.step(state, b);
+            result.push(self.counts[state]);
+        }
+
+        result
+    }
+
+    /// Returns `true` if any pattern occurs in `text`, stopping at the first match.
+    pub fn is_match(&self, text: &[u8]) -> bool {
+        let mut state = 0;
+        self.counts[0] > 0
+            || text.iter().any(|&b| {
+                state = self.step(state, b);
+                self.counts[state] > 0
+            })
+    }
+}
@@ -0,0 +1,112 @@
+//! Add `biterator` method that treats an integer as a set, iterating over each element where
+//! the respective bit is set. For example `1101` would return 0, 2 and 3.
+//!
+//! [`BitGrid`] is a set of [`Point`] objects, using a single bit for each point in a fixed size
+//! bounding box. When the range of coordinates is known and small this is much faster and more
+//! compact than hashing.
+//!
+//! ```
+//!   # use aoc_util::bitset::*;
+//!   # use aoc_util::point::Point;
+//!
+//!   let bits: Vec<_> = 0b1101_u32.biterator().collect();
+//!   assert_eq!(bits, [0, 2, 3]);
+//!
+//!   let mut set = BitGrid::new(Point::new(-2, -2), Point::new(2, 2));
+//!   assert!(set.insert(Point::new(-1, 2)));
+//!   assert!(!set.insert(Point::new(-1, 2)));
+//!   assert!(set.contains(Point::new(-1, 2)));
+//!   assert_eq!(set.len(), 1);
+//! ```
+//!
+//! [`Point`]: crate::point
+use crate::integer::*;
+use crate::point::*;
+
+pub trait BitOps<T> {
+    fn biterator(self) -> Bitset<T>;
+}
+
+impl<T> BitOps<T> for T
+where
+    T: Integer<T>,
+{
+    fn biterator(self) -> Bitset<T> {
+        Bitset { t: self }
+    }
+}
+
+pub struct Bitset<T> {
+    t: T,
+}
+
+impl<T> Iterator for Bitset<T>
+where
+    T: Integer<T>,
+    T: TryInto<usize>,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.t == T::ZERO {
+            return None;
+        }
+
+        let tz = self.t.trailing_zeros();
+        self.t = self.t ^ (T::ONE << tz);
+
+        tz.try_into().ok()
+    }
+}
+
+pub struct BitGrid {
+    min: Point,
+    width: i32,
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl BitGrid {
+    /// Creates an empty set covering the bounding box from `min` to `max` inclusive.
+    pub fn new(min: Point, max: Point) -> Self {
+        let width = max.x - min.x + 1;
+        let height = max.y - min.y + 1;
+        let bits = vec![0; ((width * height) as usize).div_ceil(64)];
+        BitGrid { min, width, bits, len: 0 }
+    }
+
+    /// Returns `true` if the point was not already present.
+    #[inline]
+    pub fn insert(&mut self, point: Point) -> bool {
+        let (index, mask) = self.locate(point);
+        let absent = self.bits[index] & mask == 0;
+
+        self.bits[index] |= mask;
+        self.len += absent as usize;
+        absent
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        let (index, mask) = self.locate(point);
+        self.bits[index] & mask != 0
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn locate(&self, point: Point) -> (usize, u64) {
+        let offset = point - self.min;
+        let bit = (self.width * offset.y + offset.x) as usize;
+        (bit / 64, 1 << (bit % 64))
+    }
+}
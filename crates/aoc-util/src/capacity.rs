@@ -0,0 +1,30 @@
+//! Capacity hints for collections that grow during a search.
+//!
+//! Reserving space up front avoids repeatedly reallocating and copying a large heap or map
+//! in the middle of a search. However a fixed guess tuned for one input can be far too small
+//! or far too large for another. [`estimate`] scales the hint with some measure of the input size,
+//! for example the number of bytes or the number of pieces that can move, then clamps the result
+//! so that an unusual input can't reserve an excessive amount of memory.
+//!
+//! Setting the `AOC_CAPACITY` environment variable overrides every estimate, which is handy when
+//! profiling allocation behavior.
+//!
+//! ```
+//!   # use aoc_util::capacity::*;
+//!
+//!   assert_eq!(estimate(100, 50), 5_000);
+//!   assert_eq!(estimate(usize::MAX, 2), MAXIMUM);
+//! ```
+use std::env::var;
+use std::sync::OnceLock;
+
+/// Upper bound on any estimate.
+pub const MAXIMUM: usize = 1 << 20;
+
+/// Estimates the capacity needed for `size` units of input at `per_unit` elements each.
+pub fn estimate(size: usize, per_unit: usize) -> usize {
+    static OVERRIDE: OnceLock<Option<usize>> = OnceLock::new();
+
+    let capacity = OVERRIDE.get_or_init(|| var("AOC_CAPACITY").ok()?.trim().parse().ok());
+    capacity.unwrap_or_else(|| size.saturating_mul(per_unit).min(MAXIMUM))
+}
@@ -0,0 +1,76 @@
+//! [Cycle detection](https://en.wikipedia.org/wiki/Cycle_detection) for a deterministic
+//! sequence of states.
+//!
+//! Many puzzles ask for the state after an impossibly large number of steps, relying on the
+//! sequence eventually repeating. [`Cycle::find`] repeatedly applies a function to a state,
+//! remembering every state seen in a hash map, until the first repeat. [`Cycle::nth`] then
+//! extrapolates to any index by skipping forward a whole number of cycles.
+//!
+//! Storing each state needs more memory than Floyd's or Brent's algorithms but finds the cycle
+//! in a single pass, which is usually faster for the small states in Advent of Code.
+//!
+//! ```
+//!   # use aoc_util::cycle::*;
+//!
+//!   // 1, 4, 16, 64 % 21 = 1, 4, 16, 1, 4, 16...
+//!   let cycle = Cycle::find(1, |n| (n * 4) % 21);
+//!
+//!   assert_eq!(cycle.start(), 0);
+//!   assert_eq!(cycle.length(), 3);
+//!   assert_eq!(*cycle.nth(1_000_000_000), 4);
+//! ```
+use crate::hash::*;
+use std::hash::Hash;
+
+pub struct Cycle<T> {
+    start: usize,
+    length: usize,
+    history: Vec<T>,
+}
+
+impl<T: Clone + Hash + Eq> Cycle<T> {
+    /// Applies `next` starting from `initial` until a state repeats.
+    pub fn find<F>(initial: T, mut next: F) -> Self
+    where
+        F: FnMut(&T) -> T,
+    {
+        let mut seen = FastMap::with_capacity(1_000);
+        let mut history = Vec::with_capacity(1_000);
+        let mut state = initial;
+
+        loop {
+            if let Some(&start) = seen.get(&state) {
+                let length = history.len() - start;
+                return Cycle { start, length, history };
+            }
+
+            let following = next(&state);
+            seen.insert(state.clone(), history.len());
+            history.push(state);
+            state = following;
+        }
+    }
+}
+
+impl<T> Cycle<T> {
+    /// Index of the first state that is part of the cycle.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Number of distinct states in the cycle.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// State after applying the function `n` times to the initial state.
+    pub fn nth(&self, n: usize) -> &T {
+        if n < self.start {
+            &self.history[n]
+        } else {
+            &self.history[self.start + (n - self.start) % self.length]
+        }
+    }
+}
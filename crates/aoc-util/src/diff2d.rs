@@ -0,0 +1,61 @@
+//! Two dimensional prefix sums.
+//!
+//! A [summed-area table](https://en.wikipedia.org/wiki/Summed-area_table) stores at each
+//! position the sum of every value above and to the left. The sum of any rectangle is then
+//! found with only 4 lookups, no matter how large the rectangle is, which makes searching for
+//! the rectangle with the largest total much faster.
+//!
+//! The table has an extra row and column of zeroes at the top and left, so that rectangles
+//! touching the edge don't need special cases.
+//!
+//! ```
+//!   # use aoc_util::diff2d::*;
+//!
+//!   // 1 2 3
+//!   // 4 5 6
+//!   let table = SummedArea::new(3, 2, |x, y| 3 * y as i32 + x as i32 + 1);
+//!
+//!   assert_eq!(table.sum(0, 0, 3, 2), 21);
+//!   assert_eq!(table.sum(1, 0, 2, 2), 16);
+//!   assert_eq!(table.sum(2, 1, 1, 1), 6);
+//!   assert_eq!(table.sum(1, 1, 0, 1), 0);
+//! ```
+use crate::integer::*;
+
+pub struct SummedArea<T> {
+    stride: usize,
+    sums: Vec<T>,
+}
+
+impl<T: Integer<T>> SummedArea<T> {
+    /// Builds the table for a `width` by `height` area where `value` returns the value at each
+    /// `x` and `y` coordinate.
+    pub fn new<F>(width: usize, height: usize, value: F) -> Self
+    where
+        F: Fn(usize, usize) -> T,
+    {
+        let stride = width + 1;
+        let mut sums = vec![T::ZERO; stride * (height + 1)];
+
+        for y in 0..height {
+            for x in 0..width {
+                let index = stride * (y + 1) + (x + 1);
+                sums[index] =
+                    value(x, y) + sums[index - 1] + sums[index - stride] - sums[index - stride - 1];
+            }
+        }
+
+        SummedArea { stride, sums }
+    }
+
+    /// Sum of the rectangle with top left corner at `x` and `y` that is `width` wide and
+    /// `height` high.
+    #[inline]
+    pub fn sum(&self, x: usize, y: usize, width: usize, height: usize) -> T {
+        let top = self.stride * y;
+        let bottom = self.stride * (y + height);
+
+        self.sums[bottom + x + width] - self.sums[bottom + x] - self.sums[top + x + width]
+            + self.sums[top + x]
+    }
+}
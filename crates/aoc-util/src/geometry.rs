@@ -0,0 +1,227 @@
+//! Two dimensional lines and line segments with exact integer arithmetic.
+//!
+//! Coordinates are `[i64; 2]` arrays, large enough for puzzles with huge positions, and
+//! [`Point`] converts into this form. Products of two coordinates can overflow `i64` so are
+//! calculated using `i128`. Nothing is ever divided, so there is no floating point or
+//! rounding error.
+//!
+//! A [`Line`] is every point `origin + t * direction` for any `t`. The [`crossing`] of two lines
+//! is found with [Cramer's rule](https://en.wikipedia.org/wiki/Cramer%27s_rule), returning the
+//! values of `t` on the first line and `u` on the second line as fractions with a common
+//! positive denominator. [`exact`] checks if both values are whole numbers, for example to
+//! solve a pair of linear equations that only have meaningful integer solutions.
+//!
+//! ```
+//!   # use aoc_util::geometry::*;
+//!
+//!   // Solve 94a + 22b = 8400 and 34a + 67b = 5400
+//!   let first = Line::new([0, 0], [94, 34]);
+//!   let second = Line::new([8400, 5400], [-22, -67]);
+//!   assert_eq!(first.crossing(second).and_then(Crossing::exact), Some([80, 40]));
+//!
+//!   let diagonal = Line::new([0, 0], [1, 1]);
+//!   let crossing = diagonal.crossing(Line::new([1, 0], [0, 1])).unwrap();
+//!   assert_eq!(crossing, Crossing { t: 1, u: 1, denominator: 1 });
+//!
+//!   assert!(diagonal.contains([-3, -3]));
+//!   assert!(diagonal.is_parallel(Line::new([1, 0], [2, 2])));
+//!   assert!(diagonal.is_collinear(Line::new([5, 5], [-1, -1])));
+//!   assert_eq!(diagonal.crossing(Line::new([1, 0], [2, 2])), None);
+//! ```
+//!
+//! A [`Segment`] includes both of its endpoints. The [`intersection`] of two segments is either
+//! a single point, represented as a segment with the same start and end, or the overlap of two
+//! collinear segments. The result is ordered in the same direction as the first segment, so
+//! its start is the first shared point reached when travelling from start to end. As puzzles
+//! use grids, only intersections at integer coordinates are reported.
+//!
+//! ```
+//!   # use aoc_util::geometry::*;
+//!
+//!   let horizontal = Segment::new([0, 2], [5, 2]);
+//!   let vertical = Segment::new([3, 4], [3, 0]);
+//!   let collinear = Segment::new([7, 2], [4, 2]);
+//!
+//!   assert_eq!(horizontal.intersection(vertical), Some(Segment::new([3, 2], [3, 2])));
+//!   assert_eq!(horizontal.intersection(collinear), Some(Segment::new([4, 2], [5, 2])));
+//!   assert_eq!(collinear.intersection(horizontal), Some(Segment::new([5, 2], [4, 2])));
+//!   assert_eq!(vertical.intersection(collinear), None);
+//!
+//!   // Lines cross at (1.5, 1.5) which is not a grid point.
+//!   assert_eq!(Segment::new([0, 0], [3, 3]).intersection(Segment::new([3, 0], [0, 3])), None);
+//!   assert!(Segment::new([0, 0], [4, 2]).contains([2, 1]));
+//! ```
+//!
+//! [`Point`]: crate::point::Point
+//! [`crossing`]: Line::crossing
+//! [`exact`]: Crossing::exact
+//! [`intersection`]: Segment::intersection
+use crate::point::*;
+
+/// Every point `origin + t * direction`. The direction should not be zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line {
+    pub origin: [i64; 2],
+    pub direction: [i64; 2],
+}
+
+/// Where two lines cross, at `t / denominator` along the first line and `u / denominator` along
+/// the second line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crossing {
+    pub t: i128,
+    pub u: i128,
+    pub denominator: i128,
+}
+
+/// Straight line between two points, including both endpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub start: [i64; 2],
+    pub end: [i64; 2],
+}
+
+impl From<Point> for [i64; 2] {
+    #[inline]
+    fn from(point: Point) -> Self {
+        [point.x as i64, point.y as i64]
+    }
+}
+
+impl Line {
+    #[inline]
+    #[must_use]
+    pub const fn new(origin: [i64; 2], direction: [i64; 2]) -> Self {
+        Line { origin, direction }
+    }
+
+    /// Is `point` anywhere on the line?
+    #[inline]
+    pub fn contains(self, point: [i64; 2]) -> bool {
+        cross(sub(point, self.origin), self.direction) == 0
+    }
+
+    /// Do the lines have the same or opposite direction? Includes collinear lines.
+    #[inline]
+    pub fn is_parallel(self, other: Line) -> bool {
+        cross(self.direction, other.direction) == 0
+    }
+
+    /// Are both lines the same set of points?
+    #[inline]
+    pub fn is_collinear(self, other: Line) -> bool {
+        self.is_parallel(other) && self.contains(other.origin)
+    }
+
+    /// Solves `origin + t * direction = other.origin + u * other.direction`, returning `None`
+    /// if the lines are parallel so either never cross or cross everywhere.
+    pub fn crossing(self, other: Line) -> Option<Crossing> {
+        let determinant = cross(self.direction, other.direction);
+        if determinant == 0 {
+            return None;
+        }
+
+        let offset = sub(other.origin, self.origin);
+        let t = cross(offset, other.direction);
+        let u = cross(offset, self.direction);
+
+        // Keep the denominator positive so that the signs of `t` and `u` are meaningful.
+        let sign = determinant.signum();
+        Some(Crossing { t: sign * t, u: sign * u, denominator: sign * determinant })
+    }
+}
+
+impl Crossing {
+    /// Values of `t` and `u` if both are whole numbers.
+    #[inline]
+    pub fn exact(self) -> Option<[i128; 2]> {
+        let Crossing { t, u, denominator } = self;
+        (t % denominator == 0 && u % denominator == 0).then(|| [t / denominator, u / denominator])
+    }
+}
+
+impl Segment {
+    #[inline]
+    #[must_use]
+    pub const fn new(start: [i64; 2], end: [i64; 2]) -> Self {
+        Segment { start, end }
+    }
+
+    /// Line through both endpoints, with `t` from `0` at the start to `1` at the end.
+    #[inline]
+    pub fn line(self) -> Line {
+        Line::new(self.start, sub(self.end, self.start))
+    }
+
+    /// Is `point` between the endpoints inclusive?
+    #[inline]
+    pub fn contains(self, point: [i64; 2]) -> bool {
+        let inside = |i: usize| {
+            self.start[i].min(self.end[i]) <= point[i] && point[i] <= self.start[i].max(self.end[i])
+        };
+        self.line().contains(point) && inside(0) && inside(1)
+    }
+
+    /// Points shared by both segments, ordered in the direction of this segment, or `None` if
+    /// the segments don't touch at integer coordinates.
+    pub fn intersection(self, other: Segment) -> Option<Segment> {
+        let line = self.line();
+
+        if let Some(Crossing { t, u, denominator }) = line.crossing(other.line()) {
+            // Both parameters must be between 0 and 1 inclusive.
+            if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+                return None;
+            }
+
+            let [x, y] = line.direction.map(|delta| t * delta as i128);
+            if x % denominator != 0 || y % denominator != 0 {
+                return None;
+            }
+
+            let point = [
+                self.start[0] + (x / denominator) as i64,
+                self.start[1] + (y / denominator) as i64,
+            ];
+            return Some(Segment::new(point, point));
+        }
+
+        // Degenerate single point segments are parallel to everything.
+        if self.start == self.end {
+            return other.contains(self.start).then_some(self);
+        }
+        if other.start == other.end {
+            return self.contains(other.start).then_some(other);
+        }
+        if !line.contains(other.start) {
+            return None;
+        }
+
+        // Project the collinear endpoints onto this segment, which runs from `0` to `length`.
+        let length = dot(line.direction, line.direction);
+        let projection = |point| dot(sub(point, self.start), line.direction);
+
+        let (mut near, mut far) = (other.start, other.end);
+        if projection(near) > projection(far) {
+            (near, far) = (far, near);
+        }
+
+        let start = if projection(near) > 0 { near } else { self.start };
+        let end = if projection(far) < length { far } else { self.end };
+        (projection(start) <= projection(end)).then_some(Segment::new(start, end))
+    }
+}
+
+#[inline]
+fn sub(a: [i64; 2], b: [i64; 2]) -> [i64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+#[inline]
+fn cross(a: [i64; 2], b: [i64; 2]) -> i128 {
+    a[0] as i128 * b[1] as i128 - a[1] as i128 * b[0] as i128
+}
+
+#[inline]
+fn dot(a: [i64; 2], b: [i64; 2]) -> i128 {
+    a[0] as i128 * b[0] as i128 + a[1] as i128 * b[1] as i128
+}
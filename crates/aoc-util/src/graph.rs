@@ -0,0 +1,224 @@
+//! Connected components and cliques of undirected graphs.
+//!
+//! [`UnionFind`] is a [disjoint set](https://en.wikipedia.org/wiki/Disjoint-set_data_structure)
+//! using path halving and union by size, so that each operation takes almost constant time.
+//! [`components`] builds one from an edge list then returns the size of every component.
+//! Components are ordered by their lowest numbered vertex, so the component containing vertex
+//! `0` is always first.
+//!
+//! ```
+//!   # use aoc_util::graph::*;
+//!
+//!   let sizes = components(6, [(0, 1), (1, 2), (4, 5)]);
+//!   assert_eq!(sizes, [3, 1, 2]);
+//!
+//!   let mut sets = UnionFind::new(3);
+//!   assert!(sets.union(0, 2));
+//!   assert!(!sets.union(2, 0));
+//!   assert_eq!(sets.find(2), sets.find(0));
+//!   assert_eq!(sets.size(0), 2);
+//! ```
+//!
+//! [`BitGraph`] is an [adjacency matrix](https://en.wikipedia.org/wiki/Adjacency_matrix) where
+//! each row is a bitset of neighbours packed into `u64` words, so that set operations on
+//! neighbourhoods are a bitwise AND over a few words. [`max_clique`] finds a
+//! [maximum clique](https://en.wikipedia.org/wiki/Clique_problem) using the
+//! [Bron–Kerbosch algorithm](https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm)
+//! with pivoting. Choosing the pivot with the most candidate neighbours prunes the search
+//! enough to handle the sparse graphs found in puzzle inputs.
+//!
+//! ```
+//!   # use aoc_util::graph::*;
+//!
+//!   let mut graph = BitGraph::new(5);
+//!   for (a, b) in [(0, 1), (0, 2), (1, 2), (2, 3), (3, 4), (1, 3), (0, 3)] {
+//!       graph.insert(a, b);
+//!   }
+//!
+//!   assert!(graph.contains(3, 1));
+//!   assert_eq!(graph.neighbours(3).collect::<Vec<_>>(), [0, 1, 2, 4]);
+//!   assert_eq!(max_clique(&graph), [0, 1, 2, 3]);
+//! ```
+use crate::bitset::*;
+
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Creates `n` sets each containing a single vertex.
+    pub fn new(n: usize) -> Self {
+        UnionFind { parent: (0..n).collect(), size: vec![1; n] }
+    }
+
+    /// Representative vertex of the set containing `x`.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `a` and `b`, returning `false` if they were already joined.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+
+    /// Number of vertices in the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+/// Sizes of the connected components of a graph with `n` vertices, ordered by the lowest
+/// numbered vertex in each component. The number of components is the length of the result.
+pub fn components<I>(n: usize, edges: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut sets = UnionFind::new(n);
+    for (a, b) in edges {
+        sets.union(a, b);
+    }
+
+    let mut seen = vec![false; n];
+    let mut sizes = Vec::new();
+
+    for vertex in 0..n {
+        let root = sets.find(vertex);
+        if !seen[root] {
+            seen[root] = true;
+            sizes.push(sets.size[root]);
+        }
+    }
+
+    sizes
+}
+
+/// Undirected graph with a fixed number of vertices, storing each edge as a single bit.
+#[derive(Clone)]
+pub struct BitGraph {
+    words: usize,
+    rows: Vec<u64>,
+}
+
+impl BitGraph {
+    /// Creates a graph with `n` vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        let words = n.div_ceil(64);
+        BitGraph { words, rows: vec![0; n * words] }
+    }
+
+    /// Number of vertices.
+    pub fn len(&self) -> usize {
+        self.rows.len().checked_div(self.words).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Adds an edge in both directions.
+    pub fn insert(&mut self, a: usize, b: usize) {
+        self.rows[a * self.words + b / 64] |= 1 << (b % 64);
+        self.rows[b * self.words + a / 64] |= 1 << (a % 64);
+    }
+
+    pub fn contains(&self, a: usize, b: usize) -> bool {
+        self.rows[a * self.words + b / 64] & (1 << (b % 64)) != 0
+    }
+
+    /// Bitset of neighbours of vertex `a`, bit `b % 64` of word `b / 64` for vertex `b`.
+    pub fn row(&self, a: usize) -> &[u64] {
+        &self.rows[a * self.words..(a + 1) * self.words]
+    }
+
+    /// Neighbours of vertex `a` in ascending order.
+    pub fn neighbours(&self, a: usize) -> impl Iterator<Item = usize> + '_ {
+        members(self.row(a))
+    }
+}
+
+/// Vertices of one largest clique in ascending order. If more than one clique has the maximum
+/// size then any one of them may be returned.
+pub fn max_clique(graph: &BitGraph) -> Vec<usize> {
+    let words = graph.words;
+    let mut clique = Vec::new();
+    let mut largest = Vec::new();
+
+    // Every vertex is initially a candidate.
+    let mut candidates = vec![u64::MAX; words];
+    let excess = words * 64 - graph.len();
+    if excess > 0 {
+        candidates[words - 1] >>= excess;
+    }
+    let excluded = vec![0; words];
+
+    bron_kerbosch(graph, &mut clique, candidates, excluded, &mut largest);
+    largest.sort_unstable();
+    largest
+}
+
+fn bron_kerbosch(
+    graph: &BitGraph,
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<u64>,
+    mut excluded: Vec<u64>,
+    largest: &mut Vec<usize>,
+) {
+    if candidates.iter().all(|&word| word == 0) {
+        if excluded.iter().all(|&word| word == 0) && clique.len() > largest.len() {
+            largest.clone_from(clique);
+        }
+        return;
+    }
+
+    // Prune branches that can't possibly beat the best clique found so far.
+    let remaining: u32 = candidates.iter().map(|word| word.count_ones()).sum();
+    if clique.len() + remaining as usize <= largest.len() {
+        return;
+    }
+
+    // Pick the pivot with the most neighbours among the candidates.
+    let union: Vec<_> = candidates.iter().zip(&excluded).map(|(p, x)| p | x).collect();
+    let pivot = members(&union)
+        .max_by_key(|&u| {
+            let row = graph.row(u);
+            row.iter().zip(&candidates).map(|(n, p)| (n & p).count_ones()).sum::<u32>()
+        })
+        .unwrap();
+
+    let pivot_row = graph.row(pivot);
+    let todo: Vec<_> = candidates.iter().zip(pivot_row).map(|(p, n)| p & !n).collect();
+
+    for vertex in members(&todo) {
+        let row = graph.row(vertex);
+        let next_candidates = candidates.iter().zip(row).map(|(p, n)| p & n).collect();
+        let next_excluded = excluded.iter().zip(row).map(|(x, n)| x & n).collect();
+
+        clique.push(vertex);
+        bron_kerbosch(graph, clique, next_candidates, next_excluded, largest);
+        clique.pop();
+
+        candidates[vertex / 64] &= !(1 << (vertex % 64));
+        excluded[vertex / 64] |= 1 << (vertex % 64);
+    }
+}
+
+/// Index of every set bit in ascending order.
+fn members(bits: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    bits.iter().enumerate().flat_map(|(i, &word)| word.biterator().map(move |bit| 64 * i + bit))
+}
@@ -0,0 +1,468 @@
+//! Fast 2 dimensional Grid backed by a single `vec`. This module is designed to work with [`Point`].
+//!
+//! The traits [`Index`] and [`IndexMut`] are implemented for [`Point`] to allow usage like:
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!   # use aoc_util::point::Point;
+//!
+//!   let mut grid = Grid::parse("1");
+//!   let point = Point::new(0, 0);
+//!   assert_eq!(grid.find(b'1'), Some(point));
+//!
+//!   let foo = grid[point];
+//!   assert_eq!(foo, b'1');
+//!
+//!   grid[point] = foo + 1;
+//!   assert_eq!(grid[point], b'2');
+//!
+//!   let visited = grid.same_size_with(false);
+//!   assert!(!visited[point]);
+//!   assert_eq!(Grid::new(2, 2, 0).bytes, [0; 4]);
+//! ```
+//!
+//! A convenience [`parse`] method creates a `Grid` directly from a 2 dimenionsal set of
+//! ASCII characters, a common occurence in Advent of Code inputs. The [`same_size_with`] function
+//! creates a grid of the same size, that can be used for in BFS algorithms for tracking visited
+//! location or for tracking cost in Djikstra.
+//!
+//! Hot loops can borrow an entire row as a slice with [`row`] and [`row_mut`].
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!
+//!   let mut grid = Grid::parse("123\n456");
+//!   assert_eq!(grid.row(1), b"456");
+//!
+//!   grid.row_mut(0).fill(b'0');
+//!   assert_eq!(grid.row(0), b"000");
+//! ```
+//!
+//! Connected regions are a common theme. [`label_regions`] assigns every cell the label of its
+//! orthogonally connected region using an iterative flood fill, so large regions can't overflow
+//! the stack, and also returns the size of each region indexed by label.
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!   # use aoc_util::point::Point;
+//!
+//!   let grid = Grid::parse("AAB\nABB\nCCB");
+//!   let (labels, sizes) = grid.label_regions(|a, b| a == b);
+//!
+//!   assert_eq!(sizes, [3, 4, 2]);
+//!   assert_eq!(labels[Point::new(2, 2)], 1);
+//! ```
+//!
+//! [`blit`] copies a smaller grid into a larger one a row at a time, for example to surround
+//! the input with padding. [`tiled`] repeats a grid in both directions.
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!   # use aoc_util::point::Point;
+//!
+//!   let mut grid = Grid::new(4, 3, b'.');
+//!   grid.blit(&Grid::parse("ab\ncd"), Point::new(1, 1));
+//!   assert_eq!(grid.bytes, b".....ab..cd.");
+//!
+//!   let tiled = Grid::parse("ab").tiled(2);
+//!   assert_eq!(tiled.bytes, b"abababab");
+//! ```
+//!
+//! [`distance_from`] is a multi source [BFS](https://en.wikipedia.org/wiki/Breadth-first_search)
+//! that finds the number of steps from the nearest source to every open cell, also known as a
+//! distance transform. Cells that can't be reached keep the sentinel value `u32::MAX`.
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!   # use aoc_util::point::Point;
+//!
+//!   let grid = Grid::parse("S..\n.#.\n..#");
+//!   let distance = grid.distance_from([Point::new(0, 0)], |b| b != b'#');
+//!
+//!   assert_eq!(distance.bytes, [0, 1, 2, 1, u32::MAX, 3, 2, 3, u32::MAX]);
+//! ```
+//!
+//! [`distance_by`] checks each step using both the current and next cell instead, for example
+//! to only allow climbing one level at a time.
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!   # use aoc_util::point::Point;
+//!
+//!   let grid = Grid::parse("0123\n9654");
+//!   let distance = grid.distance_by([Point::new(0, 0)], |from, to| to <= from + 1);
+//!
+//!   assert_eq!(distance.bytes, [0, 1, 2, 3, u32::MAX, 6, 5, 4]);
+//! ```
+//!
+//! [`find_iter`] iterates over every point where a predicate holds, scanning row by row, and
+//! [`count`] counts them, replacing the common nested loop over `x` and `y` coordinates.
+//!
+//! ```
+//!   # use aoc_util::grid::Grid;
+//!   # use aoc_util::point::Point;
+//!
+//!   let grid = Grid::parse(".#.\n#..");
+//!   let points: Vec<_> = grid.find_iter(|&b| b == b'#').collect();
+//!
+//!   assert_eq!(points, [Point::new(1, 0), Point::new(0, 1)]);
+//!   assert_eq!(grid.count(|&b| b == b'.'), 4);
+//! ```
+//!
+//! Engine schematics and similar puzzles embed numbers in a grid of symbols. [`numbers`]
+//! iterates over each horizontal run of digits as a [`Span`] and [`symbols`] over every cell
+//! that is neither a digit nor the `.` background.
+//!
+//! ```
+//!   # use aoc_util::grid::*;
+//!   # use aoc_util::point::Point;
+//!
+//!   let grid = Grid::parse("12.*\n.#.7");
+//!   let numbers: Vec<_> = grid.numbers().map(|span| (span.start, span.value)).collect();
+//!   let symbols: Vec<_> = grid.symbols().collect();
+//!
+//!   assert_eq!(numbers, [(Point::new(0, 0), 12), (Point::new(3, 1), 7)]);
+//!   assert_eq!(symbols, [(Point::new(3, 0), b'*'), (Point::new(1, 1), b'#')]);
+//! ```
+//!
+//! Some puzzles work with a sparse set of points instead, for example dots on paper or stars
+//! that spell out a message. [`bounding_box`] finds the smallest rectangle enclosing the points
+//! and [`from_points`] draws them as `#` on a background of `.`, moved so that the top left
+//! corner of the bounding box is the origin. [`render`] converts a grid to a multi-line string.
+//!
+//! ```
+//!   # use aoc_util::grid::*;
+//!   # use aoc_util::point::Point;
+//!
+//!   let points = [Point::new(3, -1), Point::new(1, 0), Point::new(2, 1)];
+//!   assert_eq!(bounding_box(&points), Some((Point::new(1, -1), Point::new(3, 1))));
+//!
+//!   let grid = Grid::from_points(&points);
+//!   assert_eq!(grid.render(), "..#\n#..\n.#.");
+//! ```
+//!
+//! [`Point`]: crate::point
+//! [`find_iter`]: Grid::find_iter
+//! [`count`]: Grid::count
+//! [`numbers`]: Grid::numbers
+//! [`symbols`]: Grid::symbols
+//! [`bounding_box`]: bounding_box
+//! [`from_points`]: Grid::from_points
+//! [`render`]: Grid::render
+//! [`blit`]: Grid::blit
+//! [`distance_from`]: Grid::distance_from
+//! [`distance_by`]: Grid::distance_by
+//! [`tiled`]: Grid::tiled
+//! [`label_regions`]: Grid::label_regions
+//! [`parse`]: Grid::parse
+//! [`same_size_with`]: Grid::same_size_with
+//! [`row`]: Grid::row
+//! [`row_mut`]: Grid::row_mut
+use crate::point::*;
+use std::collections::VecDeque;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: i32,
+    pub height: i32,
+    pub bytes: Vec<T>,
+}
+
+impl Grid<u8> {
+    #[inline]
+    pub fn parse(input: &str) -> Self {
+        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
+        let width = raw[0].len() as i32;
+        let height = raw.len() as i32;
+        let mut bytes = Vec::with_capacity((width * height) as usize);
+        raw.iter().for_each(|slice| bytes.extend_from_slice(slice));
+        Grid { width, height, bytes }
+    }
+
+    /// Draws points as `#` on a background of `.` in a grid exactly the size of their bounding box.
+    pub fn from_points(points: &[Point]) -> Self {
+        let Some((min, max)) = bounding_box(points) else {
+            return Grid::new(0, 0, b'.');
+        };
+
+        let mut grid = Grid::new(max.x - min.x + 1, max.y - min.y + 1, b'.');
+        for &point in points {
+            grid[point - min] = b'#';
+        }
+        grid
+    }
+
+    /// Every horizontal run of ASCII digits, scanning row by row.
+    pub fn numbers(&self) -> impl Iterator<Item = Span> + '_ {
+        self.bytes.chunks_exact(self.width.max(1) as usize).zip(0..).flat_map(|(row, y)| {
+            let mut x = 0;
+
+            row.chunk_by(|a, b| a.is_ascii_digit() == b.is_ascii_digit()).filter_map(move |run| {
+                let start = Point::new(x, y);
+                x += run.len() as i32;
+
+                run[0].is_ascii_digit().then(|| {
+                    let value = run.iter().fold(0, |acc, &b| 10 * acc + (b - b'0') as u32);
+                    Span { start, width: run.len() as i32, value }
+                })
+            })
+        })
+    }
+
+    /// Every cell that is neither a digit nor the `.` background, along with its value.
+    pub fn symbols(&self) -> impl Iterator<Item = (Point, u8)> + '_ {
+        self.find_iter(|&b| b != b'.' && !b.is_ascii_digit()).map(|point| (point, self[point]))
+    }
+
+    /// Each row as a line of ASCII text, separated by newlines.
+    pub fn render(&self) -> String {
+        let lines: Vec<_> = self.bytes.chunks_exact(self.width.max(1) as usize).collect();
+        String::from_utf8(lines.join(&b'\n')).unwrap()
+    }
+
+    pub fn print(&self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let point = Point::new(x, y);
+                print!("{}", self[point] as char);
+            }
+            println!();
+        }
+        println!();
+    }
+}
+
+/// Horizontal run of digits found by [`Grid::numbers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    /// Position of the leftmost digit.
+    pub start: Point,
+    pub width: i32,
+    pub value: u32,
+}
+
+impl Span {
+    /// Position of every digit in the span.
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        (0..self.width).map(move |x| self.start + Point::new(x, 0))
+    }
+}
+
+/// Top left and bottom right corners of the smallest rectangle containing every point, or `None`
+/// if there are no points.
+pub fn bounding_box<'a, I>(points: I) -> Option<(Point, Point)>
+where
+    I: IntoIterator<Item = &'a Point>,
+{
+    points.into_iter().fold(None, |bounds, &point| match bounds {
+        None => Some((point, point)),
+        Some((min, max)) => Some((
+            Point::new(min.x.min(point.x), min.y.min(point.y)),
+            Point::new(max.x.max(point.x), max.y.max(point.y)),
+        )),
+    })
+}
+
+impl<T: Copy + PartialEq> Grid<T> {
+    #[inline]
+    pub fn find(&self, needle: T) -> Option<Point> {
+        let to_point = |index| {
+            let x = (index as i32) % self.width;
+            let y = (index as i32) / self.width;
+            Point::new(x, y)
+        };
+        self.bytes.iter().position(|&h| h == needle).map(to_point)
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn new(width: i32, height: i32, value: T) -> Grid<T> {
+        Grid { width, height, bytes: vec![value; (width * height) as usize] }
+    }
+
+    /// Labels connected regions where `is_same` is true for each pair of orthogonal neighbours.
+    /// Labels are numbered from zero in the order that each region is first found scanning
+    /// row by row. Returns the grid of labels and the size of each region.
+    pub fn label_regions<F>(&self, is_same: F) -> (Grid<usize>, Vec<usize>)
+    where
+        F: Fn(T, T) -> bool,
+    {
+        let mut labels = self.same_size_with(usize::MAX);
+        let mut sizes = Vec::new();
+        let mut todo = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let start = Point::new(x, y);
+                if labels[start] != usize::MAX {
+                    continue;
+                }
+
+                let label = sizes.len();
+                let mut size = 0;
+                labels[start] = label;
+                todo.push(start);
+
+                while let Some(point) = todo.pop() {
+                    size += 1;
+
+                    for next in ORTHOGONAL.map(|o| point + o) {
+                        if self.contains(next)
+                            && labels[next] == usize::MAX
+                            && is_same(self[point], self[next])
+                        {
+                            labels[next] = label;
+                            todo.push(next);
+                        }
+                    }
+                }
+
+                sizes.push(size);
+            }
+        }
+
+        (labels, sizes)
+    }
+
+    /// Finds the distance from the nearest of `sources` to every cell reachable by orthogonal
+    /// steps through cells where `is_open` is true. Unreachable cells are `u32::MAX`.
+    pub fn distance_from<I, F>(&self, sources: I, is_open: F) -> Grid<u32>
+    where
+        I: IntoIterator<Item = Point>,
+        F: Fn(T) -> bool,
+    {
+        self.distance_by(sources, |_, to| is_open(to))
+    }
+
+    /// Same as [`distance_from`] but `can_step` checks each step using the values of both the
+    /// current cell and the next cell, for puzzles where movement depends on the difference.
+    ///
+    /// [`distance_from`]: Grid::distance_from
+    pub fn distance_by<I, F>(&self, sources: I, can_step: F) -> Grid<u32>
+    where
+        I: IntoIterator<Item = Point>,
+        F: Fn(T, T) -> bool,
+    {
+        let mut distance = self.same_size_with(u32::MAX);
+        // Each cell is queued at most once so the deque never needs to grow.
+        let mut todo = VecDeque::with_capacity(self.bytes.len());
+
+        for source in sources {
+            if distance[source] == u32::MAX {
+                distance[source] = 0;
+                todo.push_back(source);
+            }
+        }
+
+        while let Some(point) = todo.pop_front() {
+            let next_distance = distance[point].saturating_add(1);
+
+            for next in ORTHOGONAL.map(|o| point + o) {
+                if self.contains(next)
+                    && distance[next] == u32::MAX
+                    && can_step(self[point], self[next])
+                {
+                    distance[next] = next_distance;
+                    todo.push_back(next);
+                }
+            }
+        }
+
+        distance
+    }
+
+    /// Copies `src` into this grid with its top left corner at `offset`.
+    /// The whole of `src` must fit inside this grid.
+    pub fn blit(&mut self, src: &Grid<T>, offset: Point) {
+        let start = offset.x as usize;
+        let end = start + src.width as usize;
+
+        for y in 0..src.height {
+            self.row_mut(offset.y + y)[start..end].copy_from_slice(src.row(y));
+        }
+    }
+
+    /// Repeats the grid `n` times both horizontally and vertically.
+    #[must_use]
+    pub fn tiled(&self, n: i32) -> Grid<T> {
+        let width = self.width * n;
+        let height = self.height * n;
+        let mut bytes = Vec::with_capacity((width * height) as usize);
+
+        for _ in 0..n {
+            for y in 0..self.height {
+                for _ in 0..n {
+                    bytes.extend_from_slice(self.row(y));
+                }
+            }
+        }
+
+        Grid { width, height, bytes }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Every point where `predicate` is true, in row by row order.
+    pub fn find_iter<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = Point> + 'a
+    where
+        F: Fn(&T) -> bool + Copy + 'a,
+    {
+        self.bytes.chunks_exact(self.width.max(1) as usize).zip(0..).flat_map(move |(row, y)| {
+            row.iter()
+                .zip(0..)
+                .filter(move |(b, _)| predicate(b))
+                .map(move |(_, x)| Point::new(x, y))
+        })
+    }
+
+    /// Number of cells where `predicate` is true.
+    pub fn count<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.bytes.iter().filter(|b| predicate(b)).count()
+    }
+
+    #[inline]
+    pub fn same_size_with<U: Copy>(&self, value: U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            bytes: vec![value; (self.width * self.height) as usize],
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.x < self.width && point.y >= 0 && point.y < self.height
+    }
+
+    #[inline]
+    pub fn row(&self, y: i32) -> &[T] {
+        let start = (self.width * y) as usize;
+        &self.bytes[start..start + self.width as usize]
+    }
+
+    #[inline]
+    pub fn row_mut(&mut self, y: i32) -> &mut [T] {
+        let start = (self.width * y) as usize;
+        &mut self.bytes[start..start + self.width as usize]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.bytes[(self.width * index.y + index.x) as usize]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        &mut self.bytes[(self.width * index.y + index.x) as usize]
+    }
+}
@@ -4,6 +4,20 @@
 //
 //! By default, Rust's [`HashMap`] and [`HashSet`] use a [DDoS](https://en.wikipedia.org/wiki/Denial-of-service_attack)
 //! resistant but slower hashing algorithm. [`FxHasher`] is much faster (between 2x to 5x from my testing).
+//!
+//! ```
+//!   # use aoc_util::hash::*;
+//!
+//!   let mut seen = FastSet::new();
+//!   assert!(seen.insert("a"));
+//!   assert!(!seen.insert("a"));
+//!
+//!   let map = FastMap::build([("a", 1), ("b", 2)]);
+//!   assert_eq!(map["b"], 2);
+//!
+//!   let set = FastSet::build([1, 2, 2, 3]);
+//!   assert_eq!(set.len(), 3);
+//! ```
 use std::collections::{HashMap, HashSet};
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::ops::BitXor as _;
@@ -0,0 +1,153 @@
+//! [Min heap] more suitable for algorithms such as [Dijkstra] and [A*] than Rust's default
+//! max heap. Splits the sorting key and value, so that you can order items without having
+//! to implement [`Ord`] on the value type.
+//!
+//! [`TopK`] is a bounded accumulator that keeps only the `N` largest items seen so far in a small
+//! fixed size array. This avoids sorting a whole collection when only the first few items are
+//! needed. Wrap items in [`Reverse`] to keep the `N` smallest instead.
+//!
+//! ```
+//!   # use aoc_util::heap::*;
+//!
+//!   let mut heap = MinHeap::new();
+//!   heap.push(3, "three");
+//!   heap.push(1, "one");
+//!   heap.push(2, "two");
+//!
+//!   assert_eq!(heap.peek(), Some((&1, &"one")));
+//!   assert_eq!(heap.pop(), Some((1, "one")));
+//!   assert_eq!(heap.pop(), Some((2, "two")));
+//!
+//!   let top: TopK<_, 2> = [5, 1, 9, 3].into_iter().collect();
+//!   assert_eq!(top.as_slice(), [9, 5]);
+//! ```
+//!
+//! [Min heap]: https://en.wikipedia.org/wiki/Heap_(data_structure)
+//! [Dijkstra]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+//! [A*]: https://en.wikipedia.org/wiki/A*_search_algorithm
+//! [`Reverse`]: std::cmp::Reverse
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+struct Wrapper<K: Ord, V> {
+    key: K,
+    value: V,
+}
+
+impl<K: Ord, V> PartialEq for Wrapper<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> Eq for Wrapper<K, V> {}
+
+impl<K: Ord, V> PartialOrd for Wrapper<K, V> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V> Ord for Wrapper<K, V> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.cmp(&self.key)
+    }
+}
+
+#[derive(Default)]
+pub struct MinHeap<K: Ord, V> {
+    heap: BinaryHeap<Wrapper<K, V>>,
+}
+
+impl<K: Ord, V> MinHeap<K, V> {
+    pub fn new() -> Self {
+        MinHeap { heap: BinaryHeap::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        MinHeap { heap: BinaryHeap::with_capacity(capacity) }
+    }
+
+    #[inline]
+    pub fn push(&mut self, key: K, value: V) {
+        self.heap.push(Wrapper { key, value });
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.heap.pop().map(|w| (w.key, w.value))
+    }
+
+    #[inline]
+    pub fn peek(&self) -> Option<(&K, &V)> {
+        self.heap.peek().map(|w| (&w.key, &w.value))
+    }
+}
+
+/// Keeps the `N` largest items pushed so far, sorted in descending order.
+///
+/// ```
+/// # use aoc_util::heap::TopK;
+/// let top: TopK<u32, 3> = [5, 1, 9, 7, 3].into_iter().collect();
+/// assert_eq!(top.as_slice(), &[9, 7, 5]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TopK<T, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
+
+impl<T: Copy + Default + Ord, const N: usize> TopK<T, N> {
+    pub fn new() -> Self {
+        TopK { items: [T::default(); N], len: 0 }
+    }
+
+    /// Insertion sort is faster than a binary heap for the small values of `N` typically needed.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        let mut index = if self.len < N {
+            self.len += 1;
+            self.len - 1
+        } else if N > 0 && item > self.items[N - 1] {
+            N - 1
+        } else {
+            return;
+        };
+
+        while index > 0 && self.items[index - 1] < item {
+            self.items[index] = self.items[index - 1];
+            index -= 1;
+        }
+
+        self.items[index] = item;
+    }
+
+    /// Largest item first. Contains fewer than `N` items if fewer were pushed.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+impl<T: Copy + Default + Ord, const N: usize> Default for TopK<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Default + Ord, const N: usize> Extend<T> for TopK<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<T: Copy + Default + Ord, const N: usize> FromIterator<T> for TopK<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut top = Self::new();
+        top.extend(iter);
+        top
+    }
+}
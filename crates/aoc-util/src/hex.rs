@@ -0,0 +1,132 @@
+//! Hexagonal grid coordinates.
+//!
+//! [`Hex`] uses [axial coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-axial)
+//! exactly as described in the excellent [Red Blob Games](https://www.redblobgames.com/) blog.
+//! The third cube coordinate `s` is implied by `q + r + s = 0`, so only two values are stored.
+//! The six neighbors have the same axial offsets regardless of whether the hexagons are drawn
+//! with pointy tops or flat tops, only the names of the directions change.
+//!
+//! ```
+//!   # use aoc_util::hex::*;
+//!
+//!   let a = Hex::new(1, -3);
+//!   assert_eq!(a.distance(ORIGIN), 3);
+//!   assert!(NEIGHBORS.iter().all(|&n| (a + n).distance(a) == 1));
+//! ```
+//!
+//! Puzzles describe paths as a stream of direction tokens. [`pointy`] parses the `e`, `se`, `sw`,
+//! `w`, `nw` and `ne` directions of hexagons with pointy tops. [`flat`] parses the `n`, `ne`,
+//! `se`, `s`, `sw` and `nw` directions of hexagons with flat tops. Both ignore any separators
+//! such as commas, returning an iterator of single step offsets.
+//!
+//! ```
+//!   # use aoc_util::hex::*;
+//!
+//!   let end = pointy("nwwswee").fold(ORIGIN, |hex, step| hex + step);
+//!   assert_eq!(end, ORIGIN);
+//!
+//!   let end = flat("ne,ne,s,s").fold(ORIGIN, |hex, step| hex + step);
+//!   assert_eq!(end.distance(ORIGIN), 2);
+//! ```
+use std::ops::{Add, AddAssign};
+use std::str::Bytes;
+
+pub const ORIGIN: Hex = Hex::new(0, 0);
+pub const NEIGHBORS: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(-1, 0),
+    Hex::new(0, 1),
+    Hex::new(0, -1),
+    Hex::new(1, -1),
+    Hex::new(-1, 1),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    #[inline]
+    #[must_use]
+    pub const fn new(q: i32, r: i32) -> Self {
+        Hex { q, r }
+    }
+
+    /// Fewest steps between two hexagons.
+    #[inline]
+    #[must_use]
+    pub fn distance(self, other: Self) -> i32 {
+        let q = self.q - other.q;
+        let r = self.r - other.r;
+        (q.abs() + r.abs() + (q + r).abs()) / 2
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+/// Iterator over the steps of a path of direction tokens.
+pub struct Steps<'a> {
+    bytes: Bytes<'a>,
+    flat: bool,
+}
+
+/// Parses directions for hexagons with pointy tops.
+pub fn pointy(input: &str) -> Steps<'_> {
+    Steps { bytes: input.bytes(), flat: false }
+}
+
+/// Parses directions for hexagons with flat tops.
+pub fn flat(input: &str) -> Steps<'_> {
+    Steps { bytes: input.bytes(), flat: true }
+}
+
+impl Iterator for Steps<'_> {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Hex> {
+        loop {
+            let step = match (self.bytes.next()?, self.flat) {
+                (b'e', false) => Hex::new(1, 0),
+                (b'w', false) => Hex::new(-1, 0),
+                (b'n', false) => match self.bytes.next() {
+                    Some(b'e') => Hex::new(1, -1),
+                    _ => Hex::new(0, -1),
+                },
+                (b's', false) => match self.bytes.next() {
+                    Some(b'e') => Hex::new(0, 1),
+                    _ => Hex::new(-1, 1),
+                },
+                // A lone `n` or `s` consumes the following separator, which is harmless.
+                (b'n', true) => match self.bytes.next() {
+                    Some(b'e') => Hex::new(1, -1),
+                    Some(b'w') => Hex::new(-1, 0),
+                    _ => Hex::new(0, -1),
+                },
+                (b's', true) => match self.bytes.next() {
+                    Some(b'e') => Hex::new(1, 0),
+                    Some(b'w') => Hex::new(-1, 1),
+                    _ => Hex::new(0, 1),
+                },
+                _ => continue,
+            };
+            return Some(step);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Combines common [operators](https://doc.rust-lang.org/book/appendix-02-operators.html)
+//! and constants `0`, `1` and `10` to enable generic methods on integer types.
+//!
+//! ```
+//!   # use aoc_util::integer::*;
+//!
+//!   fn digits<T: Integer<T>>(mut n: T) -> usize {
+//!       let mut count = 1;
+//!       while n >= T::TEN {
+//!           n = n / T::TEN;
+//!           count += 1;
+//!       }
+//!       count
+//!   }
+//!
+//!   assert_eq!(digits(12345_u32), 5);
+//!   assert_eq!(digits(-7_i64), 1);
+//! ```
+//!
+//! Helpers for common arithmetic that is easy to get subtly wrong inline. [`mid`] finds the
+//! midpoint of two values without overflowing, rounding down, which is handy for binary search.
+//! [`ceil_div`] rounds a quotient towards positive infinity. [`clamp_to`] and [`distance`] are
+//! built from `min` and `max`, which compile to branchless conditional moves. The distance between
+//! two signed values can be too large for the signed type, so it is returned as the unsigned type
+//! of the same width, like the standard library's `abs_diff`.
+//!
+//! The names intentionally differ from similar inherent methods in the standard library, some of
+//! which are unstable for signed integers or newer than the minimum supported Rust version.
+//!
+//! ```
+//!   # use aoc_util::integer::*;
+//!
+//!   assert_eq!(u8::MAX.mid(u8::MAX - 2), u8::MAX - 1);
+//!   assert_eq!((-7_i32).mid(2), -3);
+//!   assert_eq!(7_i32.ceil_div(2), 4);
+//!   assert_eq!((-7_i32).ceil_div(2), -3);
+//!   assert_eq!(15_u32.clamp_to(0, 10), 10);
+//!   assert_eq!(3_i64.distance(-4), 7_u64);
+//!   assert_eq!(i16::MIN.distance(i16::MAX), u16::MAX);
+//! ```
+//!
+//! [`mid`]: Integer::mid
+//! [`ceil_div`]: Integer::ceil_div
+//! [`clamp_to`]: Integer::clamp_to
+//! [`distance`]: Integer::distance
+use std::ops::*;
+
+pub trait Integer<T>:
+    Copy
+    + From<u8>
+    + PartialEq
+    + PartialOrd
+    + Add<Output = T>
+    + BitAnd<Output = T>
+    + BitOr<Output = T>
+    + BitXor<Output = T>
+    + Div<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Shl<Output = T>
+    + Shr<Output = T>
+    + Sub<Output = T>
+{
+    const ZERO: T;
+    const ONE: T;
+    const TEN: T;
+
+    /// Unsigned type of the same width, for values that can't be negative.
+    type Unsigned;
+
+    fn trailing_zeros(self) -> T;
+
+    /// Midpoint of `self` and `other` rounded down, without overflowing.
+    fn mid(self, other: T) -> T;
+
+    /// Quotient rounded towards positive infinity.
+    fn ceil_div(self, rhs: T) -> T;
+
+    /// Restricts the value to the inclusive range `low..=high`.
+    fn clamp_to(self, low: T, high: T) -> T;
+
+    /// Absolute difference between `self` and `other`, which never overflows.
+    fn distance(self, other: T) -> Self::Unsigned;
+}
+
+pub trait Unsigned<T>: Integer<T> {}
+
+pub trait Signed<T>: Integer<T> + Neg<Output = T> {}
+
+macro_rules! integer {
+    ($($t:ty => $u:ty),*) => ($(
+        impl Integer<$t> for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const TEN: $t = 10;
+
+            type Unsigned = $u;
+
+            #[inline]
+            #[allow(trivial_numeric_casts)]
+            fn trailing_zeros(self) -> $t {
+                <$t>::trailing_zeros(self) as $t
+            }
+
+            #[inline]
+            fn mid(self, other: $t) -> $t {
+                (self & other) + ((self ^ other) >> 1)
+            }
+
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn ceil_div(self, rhs: $t) -> $t {
+                let quotient = self / rhs;
+                let remainder = self % rhs;
+                // Round up when there is a remainder with the same sign as the divisor.
+                quotient + <$t>::from((remainder != 0) & ((remainder > 0) == (rhs > 0)))
+            }
+
+            #[inline]
+            fn clamp_to(self, low: $t, high: $t) -> $t {
+                self.max(low).min(high)
+            }
+
+            #[inline]
+            #[allow(trivial_numeric_casts)]
+            fn distance(self, other: $t) -> $u {
+                // The difference always fits in the unsigned type, even when the signed
+                // subtraction wraps around.
+                self.max(other).wrapping_sub(self.min(other)) as $u
+            }
+        }
+    )*)
+}
+
+macro_rules! empty_trait {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name<$t> for $t {}
+    )*)
+}
+
+integer!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i16 => u16, i32 => u32, i64 => u64, i128 => u128
+);
+empty_trait!(Unsigned for u8 u16 u32 u64 u128 usize);
+empty_trait!(Signed for i16 i32 i64 i128);
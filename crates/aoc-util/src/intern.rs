@@ -0,0 +1,125 @@
+//! Maps names to contiguous `u32` ids on a first come first served basis, so that graphs with
+//! string keys can be stored in a `vec` indexed by id instead of a `HashMap`.
+//!
+//! [`Interner`] accepts names of any length. [`FixedInterner`] is a faster alternative when
+//! every name is exactly `N` lowercase ASCII letters. It converts each name to a base 26
+//! number as a [perfect hash](https://en.wikipedia.org/wiki/Perfect_hash_function), then uses
+//! a lookup table of size 26ᴺ to find the id, avoiding hashing entirely.
+//!
+//! ```
+//!   # use aoc_util::intern::*;
+//!
+//!   let mut interner = Interner::new();
+//!   assert_eq!(interner.get_or_insert("AA"), 0);
+//!   assert_eq!(interner.get_or_insert("BB"), 1);
+//!   assert_eq!(interner.get_or_insert("AA"), 0);
+//!   assert_eq!(interner.name(1), "BB");
+//!
+//!   let mut fixed = FixedInterner::<3>::new();
+//!   assert_eq!(fixed.get_or_insert(b"xyz"), 0);
+//!   assert_eq!(fixed.get(b"abc"), None);
+//!   assert_eq!(fixed.len(), 1);
+//! ```
+use crate::hash::*;
+
+#[derive(Default)]
+pub struct Interner<'a> {
+    ids: FastMap<&'a str, u32>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Interner { ids: FastMap::with_capacity(capacity), names: Vec::with_capacity(capacity) }
+    }
+
+    /// Returns the existing id for `name` or assigns the next available id.
+    #[inline]
+    pub fn get_or_insert(&mut self, name: &'a str) -> u32 {
+        let next = self.names.len() as u32;
+        let id = *self.ids.entry(name).or_insert(next);
+
+        if id == next {
+            self.names.push(name);
+        }
+
+        id
+    }
+
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    /// Reverse lookup of the name for an id.
+    #[inline]
+    pub fn name(&self, id: u32) -> &'a str {
+        self.names[id as usize]
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+pub struct FixedInterner<const N: usize> {
+    lookup: Vec<u32>,
+    len: u32,
+}
+
+impl<const N: usize> FixedInterner<N> {
+    pub fn new() -> Self {
+        FixedInterner { lookup: vec![u32::MAX; 26_usize.pow(N as u32)], len: 0 }
+    }
+
+    /// Returns the existing id for the first `N` bytes of `name` or assigns the next available id.
+    #[inline]
+    pub fn get_or_insert(&mut self, name: &[u8]) -> u32 {
+        let hash = Self::hash(name);
+
+        if self.lookup[hash] == u32::MAX {
+            self.lookup[hash] = self.len;
+            self.len += 1;
+        }
+
+        self.lookup[hash]
+    }
+
+    #[inline]
+    pub fn get(&self, name: &[u8]) -> Option<u32> {
+        let id = self.lookup[Self::hash(name)];
+        (id != u32::MAX).then_some(id)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Base 26 number.
+    #[inline]
+    fn hash(name: &[u8]) -> usize {
+        name[..N].iter().fold(0, |acc, b| 26 * acc + (b - b'a') as usize)
+    }
+}
+
+impl<const N: usize> Default for FixedInterner<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,104 @@
+//! Half open [intervals](https://en.wikipedia.org/wiki/Interval_(mathematics)) of integers that
+//! include the start but exclude the end, matching Rust's [`Range`] convention.
+//!
+//! Intervals are `Copy` unlike [`Range`] so can be stored in arrays and passed around freely.
+//! [`split`] divides an interval into the parts before, inside and after another interval,
+//! a common operation when mapping ranges of values through a series of rules.
+//!
+//! [`merge`] combines a collection of intervals into the minimum number of disjoint intervals
+//! by sorting on the start, then joining each interval that overlaps or touches the previous one.
+//!
+//! ```
+//!   # use aoc_util::interval::*;
+//!
+//!   let interval = Interval::new(1, 10);
+//!   let (before, inside, after) = interval.split(Interval::new(4, 6));
+//!
+//!   assert_eq!(before, Interval::new(1, 4));
+//!   assert_eq!(inside, Interval::new(4, 6));
+//!   assert_eq!(after, Interval::new(6, 10));
+//!   assert_eq!(inside.len(), 2);
+//!
+//!   let merged = merge([Interval::new(5, 9), Interval::new(0, 3), Interval::new(3, 4)]);
+//!   assert_eq!(merged, [Interval::new(0, 4), Interval::new(5, 9)]);
+//! ```
+//!
+//! [`Range`]: std::ops::Range
+//! [`split`]: Interval::split
+//! [`merge`]: merge
+use crate::integer::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer<T>> Interval<T> {
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.start >= self.end
+    }
+
+    #[inline]
+    pub fn len(self) -> T {
+        if self.is_empty() { T::ZERO } else { self.end - self.start }
+    }
+
+    #[inline]
+    pub fn contains(self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Values in both intervals. The result is empty if the intervals do not overlap.
+    #[inline]
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Self {
+        Interval::new(max(self.start, other.start), min(self.end, other.end))
+    }
+
+    /// Splits into the parts before, inside and after `other`, any of which may be empty.
+    #[inline]
+    pub fn split(self, other: Self) -> (Self, Self, Self) {
+        let before = Interval::new(self.start, min(self.end, other.start));
+        let after = Interval::new(max(self.start, other.end), self.end);
+        (before, self.intersection(other), after)
+    }
+}
+
+/// Returns disjoint intervals sorted in ascending order, ignoring empty intervals.
+pub fn merge<T, I>(intervals: I) -> Vec<Interval<T>>
+where
+    T: Integer<T> + Ord,
+    I: IntoIterator<Item = Interval<T>>,
+{
+    let mut sorted: Vec<_> = intervals.into_iter().filter(|i| !i.is_empty()).collect();
+    sorted.sort_unstable_by_key(|i| i.start);
+
+    let mut merged: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+
+    for next in sorted {
+        match merged.last_mut() {
+            // Ends are not sorted so only increase.
+            Some(last) if next.start <= last.end => last.end = max(last.end, next.end),
+            _ => merged.push(next),
+        }
+    }
+
+    merged
+}
+
+#[inline]
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if a < b { a } else { b }
+}
+
+#[inline]
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
@@ -4,7 +4,30 @@
 //! Using Rust's const generics, concrete implementations are provided for sizes 2 to 8 to handle
 //! the most common situations. Once [`array_chunks`] is stablized then this module can be removed.
 //!
+//! Also adds a [`tally`] method that counts the frequency of each item into a [`FastMap`] and a
+//! [`tally_into`] method that counts into a fixed size array when the keys are small integers.
+//!
+//! ```
+//!   # use aoc_util::iter::*;
+//!
+//!   let freq = "abca".bytes().tally();
+//!   assert_eq!(freq[&b'a'], 2);
+//!   assert_eq!(freq[&b'c'], 1);
+//!
+//!   let pairs: Vec<_> = [1, 2, 3, 4].into_iter().chunk::<2>().collect();
+//!   assert_eq!(pairs, [[1, 2], [3, 4]]);
+//!
+//!   let mut freq = [0; 26];
+//!   "abca".bytes().tally_into(&mut freq, |b| (b - b'a') as usize);
+//!   assert_eq!(freq[..3], [2, 1, 1]);
+//! ```
+//!
 //! [`array_chunks`]: std::iter::Iterator::array_chunks
+//! [`tally`]: TallyOps::tally
+//! [`tally_into`]: TallyOps::tally_into
+use crate::hash::*;
+use std::hash::Hash;
+
 pub struct Chunk<I: Iterator, const N: usize> {
     iter: I,
 }
@@ -116,3 +139,38 @@ impl<I: Iterator> Iterator for Chunk<I, 8> {
         Some([a, b, c, d, e, f, g, h])
     }
 }
+
+pub trait TallyOps: Iterator + Sized {
+    fn tally(self) -> FastMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash;
+
+    fn tally_into<const N: usize, F>(self, freq: &mut [usize; N], key: F)
+    where
+        F: FnMut(Self::Item) -> usize;
+}
+
+impl<I: Iterator> TallyOps for I {
+    /// Counts the number of times that each distinct item occurs.
+    fn tally(self) -> FastMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash,
+    {
+        let mut freq = FastMap::with_capacity(self.size_hint().0);
+        for item in self {
+            *freq.entry(item).or_insert(0) += 1;
+        }
+        freq
+    }
+
+    /// Adds the count of each item to the array index returned by `key`.
+    #[inline]
+    fn tally_into<const N: usize, F>(self, freq: &mut [usize; N], mut key: F)
+    where
+        F: FnMut(Self::Item) -> usize,
+    {
+        for item in self {
+            freq[key(item)] += 1;
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! # Utility modules to handle common recurring Advent of Code patterns.
+
+// Portable SIMD API is enabled by "simd" feature.
+#![cfg_attr(feature = "simd", allow(unstable_features), feature(portable_simd))]
+
+pub mod ansi;
+pub mod automaton;
+pub mod bitset;
+pub mod capacity;
+pub mod cycle;
+pub mod diff2d;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod hash;
+pub mod heap;
+pub mod hex;
+pub mod integer;
+pub mod intern;
+pub mod interval;
+pub mod iter;
+pub mod math;
+pub mod md5;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod slice;
+pub mod stable;
+pub mod thread;
+pub mod tsp;
+
+/// Builds a lookup table at compile time.
+///
+/// Closures can't be called in a `const` context, so instead the macro takes the *body* of a
+/// closure and evaluates it once for every index using a `while` loop, which is allowed in
+/// `const` expressions. The result is an ordinary array that can be assigned to a `const` item,
+/// costing nothing at runtime.
+///
+/// The index is a `usize` and the element type is inferred from the type of the array, so any
+/// numeric type works. The body can use `if` expressions, local variables and `const fn` calls.
+///
+/// ```
+///   # use aoc_util::lookup_table;
+///
+///   const SQUARES: [u32; 5] = lookup_table!(5, |i| (i * i) as u32);
+///   assert_eq!(SQUARES, [0, 1, 4, 9, 16]);
+///
+///   const PARITY: [u8; 8] = lookup_table!(8, |i| {
+///       let bits = i.count_ones();
+///       if bits % 2 == 0 { 0 } else { 1 }
+///   });
+///   assert_eq!(PARITY, [0, 1, 1, 0, 1, 0, 0, 1]);
+/// ```
+#[macro_export]
+macro_rules! lookup_table {
+    ($size:expr, |$i:ident| $body:expr) => {{
+        let mut table = [0; $size];
+        let mut $i = 0;
+        while $i < $size {
+            table[$i] = $body;
+            $i += 1;
+        }
+        table
+    }};
+}
@@ -0,0 +1,309 @@
+//! Extended mathematical operations.
+//!
+//! * [Greatest common divisor](https://en.wikipedia.org/wiki/Greatest_common_divisor)
+//!   of 2 numbers using the
+//!   [Euclidean algorithm](https://en.wikipedia.org/wiki/Euclidean_algorithm).
+//!
+//! * [Least common multiple](https://en.wikipedia.org/wiki/Least_common_multiple)
+//!
+//! * [Modular exponentation](https://en.wikipedia.org/wiki/Modular_exponentiation).
+//!   Calculates bᵉ mod m efficiently using
+//!   [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring).
+//!
+//! * [Modular multiplicative inverse](https://en.wikipedia.org/wiki/Modular_multiplicative_inverse)
+//!   calculated using the [extended Euclidean algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm).
+//!
+//! * [Integer square root](https://en.wikipedia.org/wiki/Integer_square_root).
+//!
+//! * Polygon area using the [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula),
+//!   boundary length and number of interior points using
+//!   [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem). Vertices are
+//!   supplied in order, with the closing edge from the last vertex back to the first implied.
+//!
+//! * [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem)
+//!   combining congruences one at a time. Moduli do not need to be pairwise coprime, however
+//!   congruences that contradict each other have no solution.
+//!
+//! * [Binomial coefficients](https://en.wikipedia.org/wiki/Binomial_coefficient) and
+//!   [Lagrange interpolation](https://en.wikipedia.org/wiki/Lagrange_polynomial) to extrapolate
+//!   the unique polynomial passing through a set of points. Both use `i128` internally and
+//!   return `None` instead of silently overflowing.
+//!
+//! * First repeated running total when summing a list of deltas cyclically forever. Instead of
+//!   cycling the list many times, totals are bucketed by their remainder modulo the sum of one
+//!   pass, as only totals in the same bucket can ever collide.
+//!
+//! ```
+//!   # use aoc_util::math::*;
+//!   # use aoc_util::point::Point;
+//!
+//!   let square = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)];
+//!
+//!   assert_eq!(polygon_area(square), 16);
+//!   assert_eq!(perimeter(square), 16);
+//!   assert_eq!(interior_points(16, 16), 9);
+//!
+//!   assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+//!   assert_eq!(crt([(1, 4), (2, 6)]), None);
+//!
+//!   assert_eq!(binomial(5, 2), Some(10));
+//!   assert_eq!(lagrange_extrapolate(&[(0, 1), (1, 4), (2, 9)], 5), Some(36));
+//!   assert_eq!(lagrange_extrapolate(&[(0, 1), (1, 4), (1, 9)], 5), None);
+//!
+//!   assert_eq!(first_repeated_sum(&[1, -2, 3, 1]), Some(2));
+//! ```
+use crate::hash::*;
+use crate::integer::*;
+use crate::point::*;
+
+pub trait IntegerMathOps<T: Integer<T>> {
+    fn gcd(self, b: T) -> T;
+    fn lcm(self, b: T) -> T;
+    fn mod_pow(self, e: T, m: T) -> T;
+}
+
+pub trait SignedMathOps<T: Signed<T>> {
+    fn mod_inv(self, m: T) -> Option<T>;
+}
+
+impl<T: Integer<T>> IntegerMathOps<T> for T {
+    /// Greatest common divisor
+    fn gcd(self, mut b: T) -> T {
+        let mut a = self;
+
+        while b != T::ZERO {
+            (a, b) = (b, a % b);
+        }
+
+        a
+    }
+
+    // Least common multiple
+    fn lcm(self, b: T) -> T {
+        self * (b / self.gcd(b))
+    }
+
+    // Modular exponentation
+    fn mod_pow(self, mut e: T, m: T) -> T {
+        let mut b = self;
+        let mut c = T::ONE;
+
+        while e > T::ZERO {
+            if e & T::ONE == T::ONE {
+                c = (c * b) % m;
+            }
+            b = (b * b) % m;
+            e = e >> T::ONE;
+        }
+
+        c
+    }
+}
+
+impl<T: Signed<T>> SignedMathOps<T> for T {
+    // Modular multiplicative inverse
+    fn mod_inv(self, m: T) -> Option<T> {
+        let mut t = T::ZERO;
+        let mut newt = T::ONE;
+        let mut r = m;
+        let mut newr = self;
+
+        while newr != T::ZERO {
+            let quotient = r / newr;
+            (t, newt) = (newt, t - quotient * newt);
+            (r, newr) = (newr, r - quotient * newr);
+        }
+
+        if r > T::ONE {
+            return None;
+        }
+        if t < T::ZERO {
+            t = t + m;
+        }
+
+        Some(t)
+    }
+}
+
+/// Area of a simple polygon with integer vertices. Polygons with a fractional area are
+/// rounded down.
+pub fn polygon_area<I>(vertices: I) -> i64
+where
+    I: IntoIterator<Item = Point>,
+{
+    let determinant =
+        |(a, b): (Point, Point)| (a.x as i64) * (b.y as i64) - (a.y as i64) * (b.x as i64);
+    edges(vertices).map(determinant).sum::<i64>().abs() / 2
+}
+
+/// Number of integer points on the boundary of a polygon. For edges that are horizontal or
+/// vertical this is the same as the length.
+pub fn perimeter<I>(vertices: I) -> i64
+where
+    I: IntoIterator<Item = Point>,
+{
+    let points = |(a, b): (Point, Point)| {
+        let Point { x, y } = b - a;
+        x.abs().gcd(y.abs()) as i64
+    };
+    edges(vertices).map(points).sum()
+}
+
+/// Number of integer points strictly inside a polygon, rearranging Pick's theorem
+/// `A = i + b / 2 - 1` to `i = A - b / 2 + 1`.
+#[inline]
+pub fn interior_points(area: i64, perimeter: i64) -> i64 {
+    area - perimeter / 2 + 1
+}
+
+/// Smallest non-negative `x` where `x ≡ remainder (mod modulus)` for every `(remainder, modulus)`
+/// pair, returned with the least common multiple of the moduli. Returns `None` if no solution
+/// exists or an intermediate value overflows.
+pub fn crt<I>(congruences: I) -> Option<(i128, i128)>
+where
+    I: IntoIterator<Item = (i128, i128)>,
+{
+    let mut result = 0;
+    let mut step = 1;
+
+    for (remainder, modulus) in congruences {
+        // Moduli that share a factor can only be combined if both remainders agree on it.
+        let gcd = step.gcd(modulus);
+        let difference = (remainder - result).rem_euclid(modulus);
+        if difference % gcd != 0 {
+            return None;
+        }
+
+        let reduced = modulus / gcd;
+        let inverse = (step / gcd).mod_inv(reduced)?;
+        let multiple = (difference / gcd).checked_mul(inverse)? % reduced;
+
+        result = multiple.checked_mul(step)?.checked_add(result)?;
+        step = step.checked_mul(reduced)?;
+        result = result.rem_euclid(step);
+    }
+
+    Some((result, step))
+}
+
+/// Number of ways to choose `k` items from `n`, calculated with the multiplicative formula.
+/// Each intermediate product is exactly divisible so no rounding occurs.
+pub fn binomial(n: u64, k: u64) -> Option<i128> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k) as i128;
+    let n = n as i128;
+    let mut result: i128 = 1;
+
+    for i in 0..k {
+        result = result.checked_mul(n - i)? / (i + 1);
+    }
+
+    Some(result)
+}
+
+/// Evaluates the lowest degree polynomial passing through `points` at `x`. Fractions are kept
+/// exact, returning `None` if the result is not an integer or an intermediate value overflows.
+/// Two points with the same `x` don't define a polynomial, so also return `None`.
+pub fn lagrange_extrapolate(points: &[(i128, i128)], x: i128) -> Option<i128> {
+    // Running total as the fraction `numerator / denominator`.
+    let mut numerator: i128 = 0;
+    let mut denominator: i128 = 1;
+
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let mut term_numerator = yi;
+        let mut term_denominator: i128 = 1;
+
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                if xi == xj {
+                    return None;
+                }
+                term_numerator = term_numerator.checked_mul(x - xj)?;
+                term_denominator = term_denominator.checked_mul(xi - xj)?;
+                (term_numerator, term_denominator) = reduce(term_numerator, term_denominator);
+            }
+        }
+
+        let left = numerator.checked_mul(term_denominator)?;
+        let right = term_numerator.checked_mul(denominator)?;
+        numerator = left.checked_add(right)?;
+        denominator = denominator.checked_mul(term_denominator)?;
+        (numerator, denominator) = reduce(numerator, denominator);
+    }
+
+    (numerator % denominator == 0).then(|| numerator / denominator)
+}
+
+/// Divides both parts of a fraction by their greatest common divisor.
+fn reduce(numerator: i128, denominator: i128) -> (i128, i128) {
+    let divisor = numerator.abs().gcd(denominator.abs());
+    if divisor > 1 {
+        (numerator / divisor, denominator / divisor)
+    } else {
+        (numerator, denominator)
+    }
+}
+
+/// First running total to be seen twice when `deltas` are summed cyclically forever starting
+/// from zero.
+///
+/// Each pass through the list increases every total by the same amount, so a total can only
+/// catch up with a larger total that has the same remainder modulo the sum. Sorting each bucket
+/// is cheap as buckets are small, so in practice this takes linear time.
+pub fn first_repeated_sum(deltas: &[i64]) -> Option<i64> {
+    let mut seen = FastSet::with_capacity(deltas.len());
+    let mut sums = Vec::with_capacity(deltas.len());
+    let mut sum = 0;
+
+    // A total that repeats during the first pass always wins.
+    for delta in deltas {
+        if !seen.insert(sum) {
+            return Some(sum);
+        }
+        sums.push(sum);
+        sum += delta;
+    }
+
+    // If the totals don't change between passes then the first pass repeats immediately.
+    if sum == 0 {
+        return Some(0);
+    }
+
+    // Mirror the totals if needed, so that they always increase each pass.
+    let sign = sum.signum();
+    let step = sum.abs();
+    let mut buckets: FastMap<_, Vec<_>> = FastMap::new();
+
+    for (index, &sum) in sums.iter().enumerate() {
+        let sum = sign * sum;
+        buckets.entry(sum.rem_euclid(step)).or_default().push((sum, index));
+    }
+
+    // The smaller total of each adjacent pair reaches the larger after `gap / step` passes,
+    // ties broken by whichever appears first in the list.
+    let first = buckets
+        .into_values()
+        .filter_map(|mut bucket| {
+            bucket.sort_unstable();
+            bucket.windows(2).map(|w| (w[1].0 - w[0].0, w[0].1, w[1].0)).min()
+        })
+        .min();
+
+    first.map(|(_, _, sum)| sign * sum)
+}
+
+/// Pairs each vertex with the next, wrapping around from the last vertex to the first.
+fn edges<I>(vertices: I) -> impl Iterator<Item = (Point, Point)>
+where
+    I: IntoIterator<Item = Point>,
+{
+    let mut iter = vertices.into_iter().peekable();
+    let first = iter.peek().copied();
+
+    iter.chain(first)
+        .scan(None, |previous, next| Some(previous.replace(next).map(|p| (p, next))))
+        .flatten()
+}
@@ -11,6 +11,16 @@
 //! [`#[inline]`](https://doc.rust-lang.org/reference/attributes/codegen.html#the-inline-attribute).
 //!
 //! An optional SIMD variant that computes multiple hashes in parallel is also implemented.
+//!
+//! ```
+//!   # use aoc_util::md5::*;
+//!
+//!   let mut buffer = vec![0; buffer_size(3)];
+//!   buffer[..3].copy_from_slice(b"abc");
+//!
+//!   let (a, b, c, d) = hash(&mut buffer, 3);
+//!   assert_eq!(format!("{a:08x}{b:08x}{c:08x}{d:08x}"), "900150983cd24fb0d6963f7d28e17f72");
+//! ```
 
 pub fn buffer_size(n: usize) -> usize {
     (n + 9).next_multiple_of(64)
@@ -151,18 +161,15 @@ fn common(f: u32, a: u32, b: u32, m: u32, s: u32, k: u32) -> u32 {
 #[cfg(feature = "simd")]
 pub mod simd {
     use std::array;
+    use std::simd::Simd;
     use std::simd::num::SimdUint as _;
-    use std::simd::{LaneCount, Simd, SupportedLaneCount};
 
     #[inline]
     #[expect(clippy::too_many_lines)]
     pub fn hash<const N: usize>(
         buffers: &mut [[u8; 64]],
         size: usize,
-    ) -> ([u32; N], [u32; N], [u32; N], [u32; N])
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) -> ([u32; N], [u32; N], [u32; N], [u32; N]) {
         // Assume all buffers are the same size.
         let end = 64 - 8;
         let bits = size * 8;
@@ -280,10 +287,7 @@ pub mod simd {
     }
 
     #[inline]
-    fn message<const N: usize>(buffers: &mut [[u8; 64]], i: usize) -> Simd<u32, N>
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    fn message<const N: usize>(buffers: &mut [[u8; 64]], i: usize) -> Simd<u32, N> {
         let start = 4 * i;
         let end = start + 4;
         Simd::from_array(array::from_fn(|lane| {
@@ -301,10 +305,7 @@ pub mod simd {
         m: Simd<u32, N>,
         s: u32,
         k: u32,
-    ) -> Simd<u32, N>
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) -> Simd<u32, N> {
         let f = (b & c) | (!b & d);
         common(f, a, b, m, s, k)
     }
@@ -318,10 +319,7 @@ pub mod simd {
         m: Simd<u32, N>,
         s: u32,
         k: u32,
-    ) -> Simd<u32, N>
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) -> Simd<u32, N> {
         let f = (b & d) | (c & !d);
         common(f, a, b, m, s, k)
     }
@@ -335,10 +333,7 @@ pub mod simd {
         m: Simd<u32, N>,
         s: u32,
         k: u32,
-    ) -> Simd<u32, N>
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) -> Simd<u32, N> {
         let f = b ^ c ^ d;
         common(f, a, b, m, s, k)
     }
@@ -352,10 +347,7 @@ pub mod simd {
         m: Simd<u32, N>,
         s: u32,
         k: u32,
-    ) -> Simd<u32, N>
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) -> Simd<u32, N> {
         let f = c ^ (b | !d);
         common(f, a, b, m, s, k)
     }
@@ -368,10 +360,7 @@ pub mod simd {
         m: Simd<u32, N>,
         s: u32,
         k: u32,
-    ) -> Simd<u32, N>
-    where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) -> Simd<u32, N> {
         let k = Simd::splat(k);
         let first = f + a + k + m;
         let second = (first << s) | (first >> (32 - s));
@@ -0,0 +1,79 @@
+//! Reads the blocky capital letters that several puzzles draw as their answer.
+//!
+//! Each letter is 4 pixels wide and 6 pixels tall, followed by a blank column separating it
+//! from the next letter. A letter is packed into a 24 bit integer, one row of 4 bits at a time
+//! starting from the top, with the most significant bit of each row on the left. Only letters
+//! that have appeared in puzzle answers are known. `Y` is 5 pixels wide but its first 4 columns
+//! are unique, so it's matched the same way as the others.
+//!
+//! The pixels are supplied by a closure so that any representation, such as a [`Grid`],
+//! a `Vec<bool>` or a bitmask, can be read without first converting it.
+//!
+//! ```
+//!   # use aoc_util::ocr::*;
+//!
+//!   let image = [
+//!       "#..#.####.#....#.....##.",
+//!       "#..#.#....#....#....#..#",
+//!       "####.###..#....#....#..#",
+//!       "#..#.#....#....#....#..#",
+//!       "#..#.#....#....#....#..#",
+//!       "#..#.####.####.####..##.",
+//!   ];
+//!
+//!   let letters = decode(24, |x, y| image[y as usize].as_bytes()[x as usize] == b'#');
+//!   assert_eq!(letters.as_deref(), Some("HELLO"));
+//!
+//!   // Unknown shapes are not guessed.
+//!   assert_eq!(decode(4, |x, _| x == 0), None);
+//! ```
+//!
+//! [`Grid`]: crate::grid::Grid
+
+/// Letter width in pixels, not including the blank separating column.
+pub const WIDTH: i32 = 4;
+/// Letter height in pixels.
+pub const HEIGHT: i32 = 6;
+
+const FONT: [(u32, char); 18] = [
+    (0b0110_1001_1001_1111_1001_1001, 'A'),
+    (0b1110_1001_1110_1001_1001_1110, 'B'),
+    (0b0110_1001_1000_1000_1001_0110, 'C'),
+    (0b1111_1000_1110_1000_1000_1111, 'E'),
+    (0b1111_1000_1110_1000_1000_1000, 'F'),
+    (0b0110_1001_1000_1011_1001_0111, 'G'),
+    (0b1001_1001_1111_1001_1001_1001, 'H'),
+    (0b0111_0010_0010_0010_0010_0111, 'I'),
+    (0b0011_0001_0001_0001_1001_0110, 'J'),
+    (0b1001_1010_1100_1010_1010_1001, 'K'),
+    (0b1000_1000_1000_1000_1000_1111, 'L'),
+    (0b0110_1001_1001_1001_1001_0110, 'O'),
+    (0b1110_1001_1001_1110_1000_1000, 'P'),
+    (0b1110_1001_1001_1110_1010_1001, 'R'),
+    (0b0111_1000_1000_0110_0001_1110, 'S'),
+    (0b1001_1001_1001_1001_1001_0110, 'U'),
+    (0b1000_1000_0101_0010_0010_0010, 'Y'),
+    (0b1111_0001_0010_0100_1000_1111, 'Z'),
+];
+
+/// Decodes an image `width` pixels wide whose top left corner is `(0, 0)`. Returns `None` if
+/// any letter isn't recognized. A trailing blank column after the last letter is optional.
+pub fn decode<F>(width: i32, is_lit: F) -> Option<String>
+where
+    F: Fn(i32, i32) -> bool,
+{
+    (0..width)
+        .step_by((WIDTH + 1) as usize)
+        .map(|left| {
+            let mut bits = 0;
+
+            for y in 0..HEIGHT {
+                for x in left..left + WIDTH {
+                    bits = (bits << 1) | u32::from(x < width && is_lit(x, y));
+                }
+            }
+
+            FONT.iter().find(|&&(glyph, _)| glyph == bits).map(|&(_, letter)| letter)
+        })
+        .collect()
+}
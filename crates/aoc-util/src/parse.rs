@@ -0,0 +1,361 @@
+//! Extracts and parses signed and unsigned integers from surrounding text and whitespace.
+//!
+//! A common pattern in Advent of Code is to parse and return `123`, `456` and `789` from input
+//! resembling:
+//!
+//! ```none
+//!   Lorem ipsum 123 dolor 456 sit 789 amet
+//! ```
+//!
+//! This module provides two [`&str`] extension methods [`iter_signed`] and [`iter_unsigned`]. The
+//! reason for the separate methods is that some Advent of Code inputs contains the `-` character
+//! as a delimeter and this would cause numbers to be incorrectly parsed as negative.
+//!
+//! ```
+//!   # use aoc_util::parse::*;
+//!
+//!   let input = "Lorem ipsum 123 dolor -456 sit 789 amet";
+//!
+//!   let unsigned: Vec<u32> = input.iter_unsigned().collect();
+//!   assert_eq!(unsigned, [123, 456, 789]);
+//!
+//!   let signed: Vec<i32> = input.iter_signed().collect();
+//!   assert_eq!(signed, [123, -456, 789]);
+//!
+//!   assert_eq!("x=42".unsigned::<u8>(), 42);
+//!   assert_eq!("-7".signed::<i64>(), -7);
+//!   assert_eq!(b'9'.to_decimal(), 9);
+//! ```
+//!
+//! When every line should contain a fixed amount of numbers, [`extract_unsigned`] and
+//! [`extract_signed`] parse directly into an array. Unlike grouping a flat iterator into chunks,
+//! a line with too few or too many numbers panics with the text of the offending line instead of
+//! silently misaligning every following value.
+//!
+//! ```
+//!   # use aoc_util::parse::*;
+//!
+//!   let [x, y, dx, dy] = "p=0,4 v=3,-3".extract_signed::<i32, 4>();
+//!   assert_eq!((x, y, dx, dy), (0, 4, 3, -3));
+//!
+//!   let [id, width, height] = "#7 @ 12x34".extract_unsigned::<u32, 3>();
+//!   assert_eq!((id, width, height), (7, 12, 34));
+//! ```
+//!
+//! ```should_panic
+//!   # use aoc_util::parse::*;
+//!
+//!   // Panics with: Expected 3 numbers but found 2 in "1,2"
+//!   let [a, b, c] = "1,2".extract_unsigned::<u8, 3>();
+//! ```
+//!
+//! Some inputs are grammar like, for example nested arithmetic expressions, snailfish numbers or
+//! JSON. [`Cursor`] is a tiny tokenizer for writing
+//! [recursive descent parsers](https://en.wikipedia.org/wiki/Recursive_descent_parser) over these
+//! without juggling indices. Every token method first skips ASCII whitespace. [`peek`] looks at the
+//! next byte without consuming it, [`eat`] consumes a byte only if it matches and [`expect`]
+//! panics with the position and text if the next byte is anything else.
+//!
+//! ```
+//!   # use aoc_util::parse::*;
+//!
+//!   fn sum(cursor: &mut Cursor<'_>) -> i32 {
+//!       cursor.expect(b'[');
+//!       let mut total = 0;
+//!
+//!       while cursor.peek() != Some(b']') {
+//!           total += if cursor.peek() == Some(b'[') { sum(cursor) } else { cursor.signed() };
+//!           cursor.eat(b',');
+//!       }
+//!
+//!       cursor.expect(b']');
+//!       total
+//!   }
+//!
+//!   let mut cursor = Cursor::new("[1, [2, -3], 40]");
+//!   assert_eq!(sum(&mut cursor), 40);
+//!   assert!(cursor.is_empty());
+//! ```
+//!
+//! ```should_panic
+//!   # use aoc_util::parse::*;
+//!
+//!   // Panics with: Expected ')' at position 2 in "(1"
+//!   let mut cursor = Cursor::new("(1");
+//!   cursor.expect(b'(');
+//!   let _: u8 = cursor.unsigned();
+//!   cursor.expect(b')');
+//! ```
+//!
+//! [`iter_unsigned`]: ParseOps::iter_unsigned
+//! [`iter_signed`]: ParseOps::iter_signed
+//! [`extract_unsigned`]: ParseOps::extract_unsigned
+//! [`extract_signed`]: ParseOps::extract_signed
+//! [`peek`]: Cursor::peek
+//! [`eat`]: Cursor::eat
+//! [`expect`]: Cursor::expect
+use crate::integer::*;
+use std::array::from_fn;
+use std::marker::PhantomData;
+use std::str::Bytes;
+
+pub trait ParseByte {
+    fn to_decimal(self) -> u8;
+}
+
+impl ParseByte for u8 {
+    #[inline]
+    fn to_decimal(self) -> u8 {
+        self.wrapping_sub(b'0')
+    }
+}
+
+pub struct ParseUnsigned<'a, T> {
+    bytes: Bytes<'a>,
+    phantom: PhantomData<T>,
+}
+
+pub struct ParseSigned<'a, T> {
+    bytes: Bytes<'a>,
+    phantom: PhantomData<T>,
+}
+
+pub trait ParseOps {
+    fn unsigned<T: Unsigned<T>>(&self) -> T;
+    fn signed<T: Signed<T>>(&self) -> T;
+    fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T>;
+    fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T>;
+    fn extract_unsigned<T: Unsigned<T>, const N: usize>(&self) -> [T; N];
+    fn extract_signed<T: Signed<T>, const N: usize>(&self) -> [T; N];
+}
+
+impl ParseOps for &str {
+    fn unsigned<T: Unsigned<T>>(&self) -> T {
+        match try_unsigned(&mut self.bytes()) {
+            Some(t) => t,
+            None => panic!("Unable to parse \"{self}\""),
+        }
+    }
+
+    fn signed<T: Signed<T>>(&self) -> T {
+        match try_signed(&mut self.bytes()) {
+            Some(t) => t,
+            None => panic!("Unable to parse \"{self}\""),
+        }
+    }
+
+    fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T> {
+        ParseUnsigned { bytes: self.bytes(), phantom: PhantomData }
+    }
+
+    fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T> {
+        ParseSigned { bytes: self.bytes(), phantom: PhantomData }
+    }
+
+    fn extract_unsigned<T: Unsigned<T>, const N: usize>(&self) -> [T; N] {
+        extract(self, self.iter_unsigned())
+    }
+
+    fn extract_signed<T: Signed<T>, const N: usize>(&self) -> [T; N] {
+        extract(self, self.iter_signed())
+    }
+}
+
+/// Collects exactly `N` numbers, panicking with the original text if there are more or less.
+fn extract<T, I, const N: usize>(text: &str, mut iter: I) -> [T; N]
+where
+    I: Iterator<Item = T>,
+{
+    let mut found = 0;
+    let result = from_fn(|_| {
+        found += 1;
+        iter.next()
+            .unwrap_or_else(|| panic!("Expected {N} numbers but found {} in \"{text}\"", found - 1))
+    });
+
+    if iter.next().is_some() {
+        let found = N + 1 + iter.count();
+        panic!("Expected {N} numbers but found {found} in \"{text}\"");
+    }
+
+    result
+}
+
+impl<T: Unsigned<T>> Iterator for ParseUnsigned<'_, T> {
+    type Item = T;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.bytes.size_hint();
+        (lower / 3, upper.map(|u| u / 3))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        try_unsigned(&mut self.bytes)
+    }
+}
+
+impl<T: Signed<T>> Iterator for ParseSigned<'_, T> {
+    type Item = T;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.bytes.size_hint();
+        (lower / 3, upper.map(|u| u / 3))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        try_signed(&mut self.bytes)
+    }
+}
+
+fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
+    let mut n = loop {
+        let byte = bytes.next()?;
+        let digit = byte.to_decimal();
+
+        if digit < 10 {
+            break T::from(digit);
+        }
+    };
+
+    loop {
+        let Some(byte) = bytes.next() else { break Some(n) };
+        let digit = byte.to_decimal();
+
+        if digit < 10 {
+            n = T::TEN * n + T::from(digit);
+        } else {
+            break Some(n);
+        }
+    }
+}
+
+fn try_signed<T: Signed<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
+    let (mut n, negative) = loop {
+        let byte = bytes.next()?;
+        let digit = byte.to_decimal();
+
+        if digit == 253 {
+            break (T::ZERO, true);
+        }
+        if digit < 10 {
+            break (T::from(digit), false);
+        }
+    };
+
+    loop {
+        let Some(byte) = bytes.next() else {
+            break Some(if negative { -n } else { n });
+        };
+        let digit = byte.to_decimal();
+
+        if digit < 10 {
+            n = T::TEN * n + T::from(digit);
+        } else {
+            break Some(if negative { -n } else { n });
+        }
+    }
+}
+
+/// Position in the input of a recursive descent parser.
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Cursor { bytes: input.as_bytes(), index: 0 }
+    }
+
+    /// Index of the next unconsumed byte.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.index
+    }
+
+    /// True if only whitespace remains.
+    #[inline]
+    pub fn is_empty(&mut self) -> bool {
+        self.peek().is_none()
+    }
+
+    /// Returns the next non whitespace byte without consuming it.
+    #[inline]
+    pub fn peek(&mut self) -> Option<u8> {
+        while self.index < self.bytes.len() && self.bytes[self.index].is_ascii_whitespace() {
+            self.index += 1;
+        }
+        self.bytes.get(self.index).copied()
+    }
+
+    /// Consumes and returns the next non whitespace byte.
+    #[inline]
+    pub fn take(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.index += 1;
+        Some(byte)
+    }
+
+    /// Consumes the next byte only if it equals `byte`, returning whether it did.
+    #[inline]
+    pub fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        self.index += usize::from(found);
+        found
+    }
+
+    /// Consumes the next byte, panicking if it isn't `byte`.
+    pub fn expect(&mut self, byte: u8) {
+        if !self.eat(byte) {
+            self.fail(&format!("Expected '{}'", byte as char));
+        }
+    }
+
+    /// Consumes raw bytes up to but not including the next `byte`, then consumes `byte` too.
+    /// Whitespace is not skipped, so this is suitable for the contents of a quoted string.
+    pub fn take_until(&mut self, byte: u8) -> &'a [u8] {
+        let start = self.index;
+        let Some(length) = self.bytes[start..].iter().position(|&b| b == byte) else {
+            self.fail(&format!("Expected '{}'", byte as char));
+        };
+
+        self.index += length + 1;
+        &self.bytes[start..start + length]
+    }
+
+    /// Consumes consecutive digits, panicking if there are none.
+    pub fn unsigned<T: Unsigned<T>>(&mut self) -> T {
+        self.peek();
+        self.digits()
+    }
+
+    /// Consumes digits with an optional leading `-`, panicking if there are none.
+    pub fn signed<T: Signed<T>>(&mut self) -> T {
+        if self.eat(b'-') { -self.digits::<T>() } else { self.digits() }
+    }
+
+    fn digits<T: Integer<T>>(&mut self) -> T {
+        let start = self.index;
+        let mut n = T::ZERO;
+
+        while let Some(&byte) = self.bytes.get(self.index) {
+            let digit = byte.to_decimal();
+            if digit >= 10 {
+                break;
+            }
+            n = T::TEN * n + T::from(digit);
+            self.index += 1;
+        }
+
+        if self.index == start {
+            self.fail("Expected number");
+        }
+        n
+    }
+
+    #[cold]
+    fn fail(&self, message: &str) -> ! {
+        let text = String::from_utf8_lossy(self.bytes);
+        panic!("{message} at position {} in \"{text}\"", self.index);
+    }
+}
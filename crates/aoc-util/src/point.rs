@@ -6,7 +6,7 @@
 //! operator overloading, that allows shorthand expressions such as:
 //!
 //! ```
-//!   # use aoc::util::point::Point;
+//!   # use aoc_util::point::Point;
 //!
 //!   let a = Point::new(1, 2);
 //!   let b = Point::new(3, 4);
@@ -21,10 +21,36 @@
 //! and a [`manhattan`] function for the
 //! [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between 2 points.
 //!
+//! ```
+//!   # use aoc_util::point::*;
+//!
+//!   let a = Point::new(1, 2);
+//!
+//!   assert_eq!(UP.clockwise(), RIGHT);
+//!   assert_eq!(UP.counter_clockwise(), LEFT);
+//!   assert_eq!(a.manhattan(ORIGIN), 3);
+//!   assert_eq!(a.signum(ORIGIN), Point::new(1, 1));
+//!   assert_eq!(Point::from(b'^'), UP);
+//! ```
+//!
+//! Folding a sheet of paper along a line reflects every point on the far side with [`fold_x`]
+//! and [`fold_y`], leaving points on the near side unchanged.
+//!
+//! ```
+//!   # use aoc_util::point::*;
+//!
+//!   let a = Point::new(7, 2);
+//!
+//!   assert_eq!(a.fold_x(5), Point::new(3, 2));
+//!   assert_eq!(a.fold_y(5), a);
+//! ```
+//!
 //! [`clockwise`]: Point::clockwise
 //! [`counter_clockwise`]: Point::counter_clockwise
 //! [`manhattan`]: Point::manhattan
-//! [`Grid`]: crate::util::grid
+//! [`fold_x`]: Point::fold_x
+//! [`fold_y`]: Point::fold_y
+//! [`Grid`]: crate::grid
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
@@ -82,6 +108,20 @@ impl Point {
     pub fn signum(self, other: Self) -> Self {
         Point::new((self.x - other.x).signum(), (self.y - other.y).signum())
     }
+
+    /// Reflects points to the right of the vertical line at `x` onto the left side.
+    #[inline]
+    #[must_use]
+    pub fn fold_x(self, x: i32) -> Self {
+        if self.x > x { Point::new(2 * x - self.x, self.y) } else { self }
+    }
+
+    /// Reflects points below the horizontal line at `y` onto the upper side.
+    #[inline]
+    #[must_use]
+    pub fn fold_y(self, y: i32) -> Self {
+        if self.y > y { Point::new(self.x, 2 * y - self.y) } else { self }
+    }
 }
 
 impl From<u8> for Point {
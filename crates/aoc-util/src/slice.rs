@@ -13,6 +13,16 @@
 //!
 //! Accumulates a slice of digits from 0 to 9 inclusive into a single integer.
 //!
+//! ```
+//!   # use aoc_util::slice::*;
+//!
+//!   let mut count = 0;
+//!   [1, 2, 3].as_mut_slice().permutations(|_| count += 1);
+//!   assert_eq!(count, 6);
+//!
+//!   assert_eq!([1, 2, 3].fold_decimal(), 123);
+//! ```
+//!
 //! [`permutations`]: SliceOps::permutations
 //! [`fold_decimal`]: SliceOps2::fold_decimal
 use super::integer::*;
@@ -0,0 +1,79 @@
+//! Drives a simulation until it reaches a fixed point.
+//!
+//! Cellular automata and similar puzzles repeatedly apply a rule to a state until nothing
+//! changes. The `step` function mutates the state in place and returns the number of changes
+//! it made, so the driver owns the loop, the round counting and the early exit. The round that
+//! makes no changes is included in the count, matching how puzzles usually phrase the answer
+//! ("the first round where no elf moved").
+//!
+//! [`run_until_stable_within`] stops after a maximum number of rounds even if the state is
+//! still changing, reporting whether a fixed point was reached and the total number of changes.
+//!
+//! ```
+//!   # use aoc_util::stable::*;
+//!
+//!   // Halve every odd number, stopping once all numbers are even.
+//!   let (state, rounds) = run_until_stable(vec![8, 5, 3], |numbers| {
+//!       let mut changes = 0;
+//!       for n in numbers.iter_mut().filter(|n| **n % 2 == 1) {
+//!           *n /= 2;
+//!           changes += 1;
+//!       }
+//!       changes
+//!   });
+//!
+//!   assert_eq!(state, [8, 2, 0]);
+//!   assert_eq!(rounds, 3);
+//!
+//!   let counter = run_until_stable_within(0, 5, |n| {
+//!       *n += 1;
+//!       1
+//!   });
+//!
+//!   assert_eq!(counter.state, 5);
+//!   assert_eq!(counter.rounds, 5);
+//!   assert_eq!(counter.changes, 5);
+//!   assert!(!counter.stable);
+//! ```
+
+/// Outcome of [`run_until_stable_within`].
+pub struct Stable<T> {
+    pub state: T,
+    /// Number of rounds run, including the final round with no changes if `stable` is true.
+    pub rounds: usize,
+    /// Total number of changes across all rounds.
+    pub changes: usize,
+    /// True if a round made no changes before the limit was reached.
+    pub stable: bool,
+}
+
+/// Applies `step` until a round makes no changes, returning the final state and number of
+/// rounds.
+pub fn run_until_stable<T, F>(state: T, step: F) -> (T, usize)
+where
+    F: FnMut(&mut T) -> usize,
+{
+    let Stable { state, rounds, .. } = run_until_stable_within(state, usize::MAX, step);
+    (state, rounds)
+}
+
+/// Applies `step` until a round makes no changes or `max_rounds` rounds have run.
+pub fn run_until_stable_within<T, F>(mut state: T, max_rounds: usize, mut step: F) -> Stable<T>
+where
+    F: FnMut(&mut T) -> usize,
+{
+    let mut rounds = 0;
+    let mut changes = 0;
+
+    while rounds < max_rounds {
+        let delta = step(&mut state);
+        rounds += 1;
+        changes += delta;
+
+        if delta == 0 {
+            return Stable { state, rounds, changes, stable: true };
+        }
+    }
+
+    Stable { state, rounds, changes, stable: false }
+}
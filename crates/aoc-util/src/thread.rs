@@ -0,0 +1,403 @@
+//! Utility methods to spawn a number of
+//! [scoped](https://doc.rust-lang.org/stable/std/thread/fn.scope.html)
+//! threads equals to the number of cores on the machine. Unlike normal threads, scoped threads
+//! can borrow data from their environment.
+//!
+//! Targets without thread support, such as `wasm32`, can enable the `no-threads` feature.
+//! Each function then runs the closure once on the current thread, which processes every item
+//! sequentially as the work stealing iterator has no other workers to share with.
+//!
+//! Scoped threads can migrate between cores, which makes benchmarks noisy on machines with many
+//! cores or multiple sockets. Setting the `AOC_PIN_THREADS` environment variable disables work
+//! stealing, so each worker processes exactly the same contiguous chunk of items on every run.
+//! Pinning each worker to its own core needs `unsafe` calls to the operating system, so is only
+//! available on Linux with the opt-in `pin` feature. Neighboring chunks go to neighboring cores
+//! which usually share a cache or memory node.
+//!
+//! The number of workers can be capped with [`limit_threads`], for example so that benchmarks
+//! from a machine with many cores can be reproduced on one with fewer.
+//!
+//! ```
+//!   # use aoc_util::thread::*;
+//!   # use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//!   let items: Vec<_> = (1..=100).collect();
+//!   let total = AtomicUsize::new(0);
+//!
+//!   spawn_parallel_iterator(&items, |iter| {
+//!       let sum: usize = iter.sum();
+//!       total.fetch_add(sum, Ordering::Relaxed);
+//!   });
+//!
+//!   assert_eq!(total.into_inner(), 5050);
+//!   assert!(threads() >= 1);
+//! ```
+//!
+//! Producer and consumer threads can communicate over a [`bounded`] channel. Unlike the
+//! unbounded channels in the standard library, a sender blocks once `capacity` messages are
+//! waiting, so a fast producer can't use an unlimited amount of memory getting ahead of a slow
+//! consumer. The channel is a ring buffer protected by a mutex, with one condition variable to
+//! wake a waiting receiver and another to wake waiting senders. Senders can be cloned.
+//! With the `no-threads` feature there is no other thread to make room, so the capacity is
+//! ignored and sending never blocks.
+//!
+//! ```
+//!   # use aoc_util::thread::*;
+//!
+//!   let (tx, rx) = bounded(2);
+//!   std::thread::spawn(move || (1..=100).for_each(|n| tx.send(n).unwrap()));
+//!
+//!   assert_eq!(rx.into_iter().sum::<u32>(), 5050);
+//! ```
+use std::collections::VecDeque;
+use std::env::var_os;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+#[cfg(not(feature = "no-threads"))]
+use std::thread::{available_parallelism, scope};
+
+/// Upper bound on the number of worker threads.
+static LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Caps the number of worker threads used by every later call, always allowing at least one.
+pub fn limit_threads(n: usize) {
+    LIMIT.store(n.max(1), Relaxed);
+}
+
+/// Number of worker threads, usually the number of physical cores.
+#[cfg(not(feature = "no-threads"))]
+pub fn threads() -> usize {
+    available_parallelism().unwrap().get().min(LIMIT.load(Relaxed))
+}
+
+/// Number of worker threads, always one when threads are unavailable.
+#[cfg(feature = "no-threads")]
+pub fn threads() -> usize {
+    1
+}
+
+/// Do workers process deterministic chunks of work, pinned to cores with the `pin` feature?
+pub fn pinned() -> bool {
+    static PINNED: OnceLock<bool> = OnceLock::new();
+    *PINNED.get_or_init(|| var_os("AOC_PIN_THREADS").is_some_and(|value| value != "0"))
+}
+
+/// Spawn `n` scoped threads, where `n` is the available parallelism.
+pub fn spawn<F>(f: F)
+where
+    F: Fn() + Copy + Send,
+{
+    #[cfg(not(feature = "no-threads"))]
+    scope(|scope| {
+        for id in 0..threads() {
+            scope.spawn(move || {
+                pin(id);
+                f();
+            });
+        }
+    });
+
+    #[cfg(feature = "no-threads")]
+    f();
+}
+
+/// Spawns `n` scoped threads that each receive a
+/// [work stealing](https://en.wikipedia.org/wiki/Work_stealing) iterator.
+/// Work stealing is an efficient strategy that keeps each CPU core busy when some items take longer
+/// than other to process, used by popular libraries such as [rayon](https://github.com/rayon-rs/rayon).
+/// Processing at different rates also happens on many modern CPUs with
+/// [heterogeneous performance and efficiency cores](https://en.wikipedia.org/wiki/ARM_big.LITTLE).
+pub fn spawn_parallel_iterator<F, T>(items: &[T], f: F)
+where
+    F: Fn(ParIter<'_, T>) + Copy + Send,
+    T: Sync,
+{
+    let threads = threads();
+    let size = items.len().div_ceil(threads);
+    let steal = !pinned();
+
+    // Initially divide work as evenly as possible amongst each worker thread.
+    let workers: Vec<_> = (0..threads)
+        .map(|id| {
+            let start = (id * size).min(items.len());
+            let end = (start + size).min(items.len());
+            CachePadding::new(pack(start, end))
+        })
+        .collect();
+    let workers = workers.as_slice();
+
+    #[cfg(not(feature = "no-threads"))]
+    scope(|scope| {
+        for id in 0..threads {
+            scope.spawn(move || {
+                pin(id);
+                f(ParIter { id, items, workers, steal });
+            });
+        }
+    });
+
+    #[cfg(feature = "no-threads")]
+    f(ParIter { id: 0, items, workers, steal });
+}
+
+pub struct ParIter<'a, T> {
+    id: usize,
+    items: &'a [T],
+    workers: &'a [CachePadding],
+    steal: bool,
+}
+
+impl<'a, T> Iterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        // First try taking from our own queue.
+        let worker = &self.workers[self.id];
+        let current = worker.increment();
+        let (start, end) = unpack(current);
+
+        // There's still items to process.
+        if start < end {
+            return Some(&self.items[start]);
+        }
+        if !self.steal {
+            return None;
+        }
+
+        // Steal from another worker, [spinlocking](https://en.wikipedia.org/wiki/Spinlock)
+        // until we acquire new items to process or there's nothing left to do.
+        loop {
+            // Find worker with the most remaining items.
+            let available = self
+                .workers
+                .iter()
+                .filter_map(|other| {
+                    let current = other.load();
+                    let (start, end) = unpack(current);
+                    let size = end.saturating_sub(start);
+
+                    (size > 0).then_some((other, current, size))
+                })
+                .max_by_key(|t| t.2);
+
+            if let Some((other, current, size)) = available {
+                // Split the work items into two roughly equal piles.
+                let (start, end) = unpack(current);
+                let middle = start + size.div_ceil(2);
+
+                let next = pack(middle, end);
+                let stolen = pack(start + 1, middle);
+
+                // We could be preempted by another thread stealing or by the owning worker
+                // thread finishing an item, so check indices are still unmodified.
+                if other.compare_exchange(current, next) {
+                    worker.store(stolen);
+                    break Some(&self.items[start]);
+                }
+            } else {
+                // No work remaining.
+                break None;
+            }
+        }
+    }
+}
+
+/// Pins the current thread to the `id`th core that the process is allowed to run on.
+/// Does nothing unless pinning is enabled or if the core doesn't exist.
+#[cfg(all(feature = "pin", target_os = "linux", not(feature = "no-threads")))]
+#[allow(unsafe_code)]
+fn pin(id: usize) {
+    // Supports up to 1024 cores, matching the default size of `cpu_set_t` in glibc.
+    type CpuSet = [u64; 16];
+
+    unsafe extern "C" {
+        fn sched_getaffinity(pid: i32, size: usize, mask: *mut CpuSet) -> i32;
+        fn sched_setaffinity(pid: i32, size: usize, mask: *const CpuSet) -> i32;
+    }
+
+    if !pinned() {
+        return;
+    }
+
+    let size = size_of::<CpuSet>();
+    let mut allowed: CpuSet = [0; 16];
+    // SAFETY: The mask is a valid writable buffer of `size` bytes. A pid of 0 is this thread.
+    if unsafe { sched_getaffinity(0, size, &raw mut allowed) } != 0 {
+        return;
+    }
+
+    let cores: Vec<_> =
+        (0..1024).filter(|&core| allowed[core / 64] & (1 << (core % 64)) != 0).collect();
+    if let Some(&core) = cores.get(id % cores.len().max(1)) {
+        let mut mask: CpuSet = [0; 16];
+        mask[core / 64] = 1 << (core % 64);
+        // SAFETY: The mask is a valid buffer of `size` bytes. A pid of 0 is this thread.
+        unsafe {
+            sched_setaffinity(0, size, &raw const mask);
+        }
+    }
+}
+
+#[cfg(all(not(all(feature = "pin", target_os = "linux")), not(feature = "no-threads")))]
+fn pin(_id: usize) {}
+
+/// Creates a channel that holds at most `capacity` messages, which must be at least one.
+pub fn bounded<T>(capacity: usize) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    let capacity = if cfg!(feature = "no-threads") { usize::MAX } else { capacity.max(1) };
+    let exclusive = Exclusive {
+        queue: VecDeque::with_capacity(capacity.min(1024)),
+        senders: 1,
+        receiver: true,
+    };
+    let shared = Arc::new(Channel {
+        capacity,
+        mutex: Mutex::new(exclusive),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+    });
+    (BoundedSender { shared: Arc::clone(&shared) }, BoundedReceiver { shared })
+}
+
+struct Exclusive<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    receiver: bool,
+}
+
+struct Channel<T> {
+    capacity: usize,
+    mutex: Mutex<Exclusive<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+pub struct BoundedSender<T> {
+    shared: Arc<Channel<T>>,
+}
+
+pub struct BoundedReceiver<T> {
+    shared: Arc<Channel<T>>,
+}
+
+impl<T> BoundedSender<T> {
+    /// Waits until there is room then sends `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if the receiver has hung up.
+    pub fn send(&self, value: T) -> Result<(), T> {
+        let channel = &*self.shared;
+        let mut exclusive = channel.mutex.lock().unwrap();
+
+        while exclusive.receiver && exclusive.queue.len() >= channel.capacity {
+            exclusive = channel.not_full.wait(exclusive).unwrap();
+        }
+        if !exclusive.receiver {
+            return Err(value);
+        }
+
+        exclusive.queue.push_back(value);
+        channel.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.mutex.lock().unwrap().senders += 1;
+        BoundedSender { shared: Arc::clone(&self.shared) }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        let mut exclusive = self.shared.mutex.lock().unwrap();
+        exclusive.senders -= 1;
+        if exclusive.senders == 0 {
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<T> BoundedReceiver<T> {
+    /// Waits for the next value, returning `None` once every sender has hung up and the
+    /// channel is empty.
+    pub fn recv(&self) -> Option<T> {
+        let channel = &*self.shared;
+        let mut exclusive = channel.mutex.lock().unwrap();
+
+        loop {
+            if let Some(value) = exclusive.queue.pop_front() {
+                channel.not_full.notify_one();
+                return Some(value);
+            }
+            if exclusive.senders == 0 {
+                return None;
+            }
+            exclusive = channel.not_empty.wait(exclusive).unwrap();
+        }
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.mutex.lock().unwrap().receiver = false;
+        self.shared.not_full.notify_all();
+    }
+}
+
+impl<T> Iterator for BoundedReceiver<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.recv()
+    }
+}
+
+/// Intentionally force alignment to 128 bytes to make a best effort attempt to place each atomic
+/// on its own cache line. This reduces contention and improves performance for common
+/// CPU caching protocols such as [MESI](https://en.wikipedia.org/wiki/MESI_protocol).
+#[repr(align(128))]
+pub struct CachePadding {
+    atomic: AtomicUsize,
+}
+
+/// Convenience wrapper methods around atomic operations. Both start and end indices are packed
+/// into a single atomic so that we can use the fastest and easiest to reason about `Relaxed`
+/// ordering.
+impl CachePadding {
+    #[inline]
+    fn new(n: usize) -> Self {
+        CachePadding { atomic: AtomicUsize::new(n) }
+    }
+
+    #[inline]
+    fn increment(&self) -> usize {
+        self.atomic.fetch_add(1, Relaxed)
+    }
+
+    #[inline]
+    fn load(&self) -> usize {
+        self.atomic.load(Relaxed)
+    }
+
+    #[inline]
+    fn store(&self, n: usize) {
+        self.atomic.store(n, Relaxed);
+    }
+
+    #[inline]
+    fn compare_exchange(&self, current: usize, new: usize) -> bool {
+        self.atomic.compare_exchange(current, new, Relaxed, Relaxed).is_ok()
+    }
+}
+
+#[inline]
+fn pack(start: usize, end: usize) -> usize {
+    (end << 32) | start
+}
+
+#[inline]
+fn unpack(both: usize) -> (usize, usize) {
+    (both & 0xffffffff, both >> 32)
+}
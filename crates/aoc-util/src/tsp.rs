@@ -0,0 +1,107 @@
+//! Exact solver for small [Travelling Salesman](https://en.wikipedia.org/wiki/Travelling_salesman_problem)
+//! style problems.
+//!
+//! Checking every permutation of `n` locations takes `O(n!)` time. The
+//! [Held-Karp algorithm](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm) instead
+//! builds a table of the best route that visits each subset of locations exactly once and ends
+//! at a specific location. Every entry extends a route over a subset one location smaller, so
+//! the whole table takes `O(2ⁿn²)` time.
+//!
+//! A [`Tour::Cycle`] must return to its starting point. Rotating a cycle doesn't change its
+//! total, so the start is fixed at location `0`. A [`Tour::Path`] can start and end anywhere.
+//! Missing values are represented with `None` so that the same code can search for either the
+//! minimum or maximum of any integer type without needing a sentinel value.
+//!
+//! ```
+//!   # use aoc_util::tsp::*;
+//!
+//!   // Four locations on a line at positions 0, 1, 3 and 6.
+//!   let position = [0_u32, 1, 3, 6];
+//!   let weight = |from: usize, to: usize| position[from].abs_diff(position[to]);
+//!
+//!   assert_eq!(held_karp(4, Tour::Path, Goal::Minimize, weight), 6);
+//!   assert_eq!(held_karp(4, Tour::Path, Goal::Maximize, weight), 14);
+//!   assert_eq!(held_karp(4, Tour::Cycle, Goal::Minimize, weight), 12);
+//!   assert_eq!(held_karp(4, Tour::Cycle, Goal::Maximize, weight), 16);
+//! ```
+use crate::integer::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tour {
+    Cycle,
+    Path,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Goal {
+    Minimize,
+    Maximize,
+}
+
+impl Goal {
+    #[inline]
+    fn better<T: Integer<T>>(self, current: Option<T>, candidate: T) -> Option<T> {
+        match (self, current) {
+            (_, None) => Some(candidate),
+            (Goal::Minimize, Some(value)) if candidate < value => Some(candidate),
+            (Goal::Maximize, Some(value)) if candidate > value => Some(candidate),
+            _ => current,
+        }
+    }
+}
+
+/// Best total weight of a route that visits all `size` locations exactly once. `weight` returns
+/// the cost of travelling directly from one location to another.
+///
+/// # Panics
+///
+/// If `size` is zero.
+pub fn held_karp<T, F>(size: usize, tour: Tour, goal: Goal, weight: F) -> T
+where
+    T: Integer<T>,
+    F: Fn(usize, usize) -> T,
+{
+    assert!(size > 0, "At least one location is required");
+
+    let weights: Vec<_> = (0..size * size).map(|i| weight(i / size, i % size)).collect();
+    let all = (1 << size) - 1;
+    let mut best = vec![None; (1 << size) * size];
+
+    match tour {
+        Tour::Cycle => best[size] = Some(T::ZERO),
+        Tour::Path => {
+            for start in 0..size {
+                best[(1 << start) * size + start] = Some(T::ZERO);
+            }
+        }
+    }
+
+    // Subsets only grow, so increasing numerical order visits each subset after all of its
+    // own subsets.
+    for mask in 1..all {
+        for last in 0..size {
+            let Some(total) = best[mask * size + last] else { continue };
+
+            for next in 0..size {
+                if mask & (1 << next) == 0 {
+                    let index = (mask | (1 << next)) * size + next;
+                    best[index] = goal.better(best[index], total + weights[last * size + next]);
+                }
+            }
+        }
+    }
+
+    let mut result = None;
+
+    for last in 0..size {
+        if let Some(total) = best[all * size + last] {
+            let total = match tour {
+                Tour::Cycle => total + weights[last * size],
+                Tour::Path => total,
+            };
+            result = goal.better(result, total);
+        }
+    }
+
+    result.unwrap()
+}
@@ -6,10 +6,10 @@
 //! Sorting the dimensions in ascending order makes calculating the smallest side or smallest
 //! perimeter straightforward.
 //!
-//! [`iter_unsigned`]: crate::util::parse
-//! [`chunk`]: crate::util::iter
-use crate::util::iter::*;
-use crate::util::parse::*;
+//! [`iter_unsigned`]: aoc_util::parse
+//! [`chunk`]: aoc_util::iter
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Gift = [u32; 3];
 
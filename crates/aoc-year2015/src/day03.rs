@@ -0,0 +1,53 @@
+//! # Perfectly Spherical Houses in a Vacuum
+//!
+//! We store Santa's path in a [`BitGrid`] that deduplicates visited points. The path is walked
+//! twice, first to find the bounding box, which is small enough that a single bit per house is
+//! much faster than hashing. For part two we alternate between Santa and the robot, tracking two
+//! points simultaneously and reusing the same deduplicating logic as part one.
+//!
+//! [`BitGrid`]: aoc_util::bitset
+use aoc_util::bitset::*;
+use aoc_util::point::*;
+
+pub fn parse(input: &str) -> Vec<Point> {
+    input.trim().bytes().map(Point::from).collect()
+}
+
+pub fn part1(input: &[Point]) -> usize {
+    deliver(input, |_| true)
+}
+
+pub fn part2(input: &[Point]) -> usize {
+    deliver(input, |i| i % 2 == 0)
+}
+
+fn deliver(input: &[Point], predicate: fn(usize) -> bool) -> usize {
+    let visits = || {
+        let mut santa = ORIGIN;
+        let mut robot = ORIGIN;
+
+        input.iter().enumerate().map(move |(index, &point)| {
+            if predicate(index) {
+                santa += point;
+                santa
+            } else {
+                robot += point;
+                robot
+            }
+        })
+    };
+
+    // Walk the path once to find the bounding box.
+    let (min, max) = visits().fold((ORIGIN, ORIGIN), |(min, max), p| {
+        (Point::new(min.x.min(p.x), min.y.min(p.y)), Point::new(max.x.max(p.x), max.y.max(p.y)))
+    });
+
+    let mut set = BitGrid::new(min, max);
+    set.insert(ORIGIN);
+
+    for point in visits() {
+        set.insert(point);
+    }
+
+    set.len()
+}
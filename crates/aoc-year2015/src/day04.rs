@@ -15,10 +15,10 @@
 //! However for unit testing, I was able to randomly find a value that takes only 455 iterations,
 //! about 22,000 times faster!
 //!
-//! [`MD5`]: crate::util::md5
+//! [`MD5`]: aoc_util::md5
 //! [`format!`]: std::format
-use crate::util::md5::*;
-use crate::util::thread::*;
+use aoc_util::md5::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 pub struct Shared {
@@ -104,8 +104,7 @@ fn worker(shared: &Shared) {
 #[cfg(feature = "simd")]
 mod simd {
     use super::*;
-    use crate::util::md5::simd::hash;
-    use std::simd::{LaneCount, SupportedLaneCount};
+    use aoc_util::md5::simd::hash;
 
     #[expect(clippy::needless_range_loop)]
     fn check_hash_simd<const N: usize>(
@@ -114,9 +113,7 @@ mod simd {
         start: u32,
         offset: u32,
         shared: &Shared,
-    ) where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) {
         // Format macro is very slow, so update digits directly
         for i in 0..N {
             let n = offset + i as u32;
@@ -60,8 +60,8 @@
 //!
 //! For my input, there was ~100,000 rectangles but only ~20,000 different instructions regions
 //! needed to be computed. This is a 50x reduction from looking at each light individually.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 enum Command {
     On,
@@ -6,8 +6,8 @@
 //!
 //! For part two we pre-seed the value of `b` in the cache with the result from part one then
 //! re-run the same process.
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 type Result = (u16, u16);
 
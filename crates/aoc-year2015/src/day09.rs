@@ -0,0 +1,59 @@
+//! # All in a Single Night
+//!
+//! This is a variant of the classic NP-hard
+//! [Travelling Salesman Problem](https://en.wikipedia.org/wiki/Travelling_salesman_problem).
+//!
+//! There are only 8 locations so we can find the exact answer using the
+//! [Held-Karp](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm) solver from the
+//! [`tsp`] module. Santa doesn't need to return home, so both parts look for a path rather than
+//! a cycle, minimizing the total distance for part one and maximizing it for part two.
+//!
+//! For speed we first convert each location into an index, then store the distances between
+//! every pair of locations in an array for fast lookup.
+//!
+//! [`tsp`]: aoc_util::tsp
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::tsp::*;
+
+type Result = (u32, u32);
+
+pub fn parse(input: &str) -> Result {
+    let tokens: Vec<_> = input.split_ascii_whitespace().chunk::<5>().collect();
+    let mut indices = FastMap::new();
+
+    for [start, _, end, ..] in &tokens {
+        let size = indices.len();
+        indices.entry(start).or_insert(size);
+
+        let size = indices.len();
+        indices.entry(end).or_insert(size);
+    }
+
+    let stride = indices.len();
+    let mut distances = vec![0; stride * stride];
+
+    for [start, _, end, _, distance] in &tokens {
+        let start = indices[start];
+        let end = indices[end];
+        let distance = distance.unsigned();
+
+        distances[stride * start + end] = distance;
+        distances[stride * end + start] = distance;
+    }
+
+    let distance = |from, to| distances[stride * from + to];
+    let shortest = held_karp(stride, Tour::Path, Goal::Minimize, distance);
+    let longest = held_karp(stride, Tour::Path, Goal::Maximize, distance);
+
+    (shortest, longest)
+}
+
+pub fn part1(input: &Result) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &Result) -> u32 {
+    input.1
+}
@@ -12,7 +12,7 @@
 //!
 //! Computing the result is simply multiplying the number of each element by its length. There are
 //! 92 elements total so we can use a fixed size array to store the decay chain information.
-use crate::util::hash::*;
+use aoc_util::hash::*;
 
 const ELEMENTS: &str = "\
 22 -> H -> H
@@ -12,7 +12,7 @@
 //! * The 5 digit sequence cannot start with any letter from `g` to `o` inclusive or it would
 //!   contain an invalid character somewhere in the sequence.
 //!
-//! [previous day]: crate::year2015::day10
+//! [previous day]: crate::day10
 use std::str::from_utf8;
 
 type Password = [u8; 8];
@@ -0,0 +1,69 @@
+//! # JSAbacusFramework.io
+//!
+//! We walk the JSON one token at a time using the shared [`Cursor`] without building a tree
+//! in memory. A stack holds one frame per currently open array or object, containing the running
+//! total of its numbers and whether it's an object with a `"red"` property value. When an array
+//! or object closes its total is added to its parent, unless excluded for being red.
+//!
+//! A string followed by `:` is an object key, so a key named "red" doesn't exclude anything.
+//! "red" inside an array is also allowed. The explicit stack means that deeply nested input can't
+//! overflow the call stack.
+//!
+//! [`Cursor`]: aoc_util::parse::Cursor
+use aoc_util::parse::*;
+
+struct Frame {
+    object: bool,
+    red: bool,
+    total: i32,
+}
+
+pub fn parse(input: &str) -> &str {
+    input
+}
+
+pub fn part1(input: &str) -> i32 {
+    sum_numbers(input, false)
+}
+
+pub fn part2(input: &str) -> i32 {
+    sum_numbers(input, true)
+}
+
+/// Sums every number in a JSON document. If `exclude_red` is true then objects (and all of their
+/// children) that have any property with the value "red" are skipped.
+pub fn sum_numbers(input: &str, exclude_red: bool) -> i32 {
+    let mut cursor = Cursor::new(input);
+    let mut stack = vec![Frame { object: false, red: false, total: 0 }];
+
+    while let Some(byte) = cursor.peek() {
+        match byte {
+            b'[' | b'{' => {
+                cursor.take();
+                stack.push(Frame { object: byte == b'{', red: false, total: 0 });
+            }
+            b']' | b'}' => {
+                cursor.take();
+                let Frame { red, total, .. } = stack.pop().unwrap();
+                if !red {
+                    stack.last_mut().unwrap().total += total;
+                }
+            }
+            b'"' => {
+                cursor.take();
+                let string = cursor.take_until(b'"');
+                let key = cursor.eat(b':');
+                let frame = stack.last_mut().unwrap();
+                frame.red |= exclude_red && frame.object && !key && string == b"red";
+            }
+            b'-' | b'0'..=b'9' => {
+                stack.last_mut().unwrap().total += cursor.signed::<i32>();
+            }
+            _ => {
+                cursor.take();
+            }
+        }
+    }
+
+    stack[0].total
+}
@@ -0,0 +1,61 @@
+//! # Knights of the Dinner Table
+//!
+//! This problem is very similar to [`Day 9`] and we solve it in almost exactly the same way by
+//! computing an adjacency matrix of happiness then using the Held-Karp solver from the [`tsp`]
+//! module.
+//!
+//! The table is round so part one looks for the cycle with the most happiness.
+//!
+//! For part two, inserting yourself between two diners sets the value of their mutual link to
+//! zero. This breaks the cycle into a path, so we look for the path with the most happiness.
+//!
+//! [`Day 9`]: crate::day09
+//! [`tsp`]: aoc_util::tsp
+use aoc_util::hash::*;
+use aoc_util::parse::*;
+use aoc_util::tsp::*;
+
+type Input = (i32, i32);
+
+pub fn parse(input: &str) -> Input {
+    // Assign each diner an index on a first come first served basis.
+    let lines: Vec<Vec<_>> = input.lines().map(|line| line.split([' ', '.']).collect()).collect();
+    let mut indices = FastMap::new();
+
+    for tokens in &lines {
+        let size = indices.len();
+        indices.entry(tokens[0]).or_insert(size);
+
+        let size = indices.len();
+        indices.entry(tokens[10]).or_insert(size);
+    }
+
+    // Calculate the happiness values. Note that the values are not reciprocal a => b != b => a.
+    let stride = indices.len();
+    let mut happiness = vec![0; stride * stride];
+
+    for tokens in &lines {
+        let start = indices[tokens[0]];
+        let end = indices[tokens[10]];
+        let sign = if tokens[2] == "gain" { 1 } else { -1 };
+        let value: i32 = tokens[3].signed();
+
+        // Add the values together to make the mutual link reciprocal
+        happiness[stride * start + end] += sign * value;
+        happiness[stride * end + start] += sign * value;
+    }
+
+    let link = |from, to| happiness[stride * from + to];
+    let part_one = held_karp(stride, Tour::Cycle, Goal::Maximize, link);
+    let part_two = held_karp(stride, Tour::Path, Goal::Maximize, link);
+
+    (part_one, part_two)
+}
+
+pub fn part1(input: &Input) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &Input) -> i32 {
+    input.1
+}
@@ -2,8 +2,8 @@
 //!
 //! In order to make things easier we create a function to calculate the distance travelled by a
 //! reindeer at any arbitrary time.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Reindeer = [u32; 3];
 
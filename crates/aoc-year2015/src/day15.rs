@@ -0,0 +1,170 @@
+//! # Science for Hungry People
+//!
+//! Solves part one and two simultaneously, tracking both the best score and the best score with
+//! exactly 500 calories.
+//!
+//! For up to 4 ingredients we brute force every possible combination. The loop conditions are
+//! calculated so that the ingredients always sum to 100. As an optimization we check before
+//! each level of the recursion to see if any property can never be greater than zero, skipping
+//! large numbers of combinations.
+//!
+//! With more ingredients the number of combinations grows too quickly, so instead we use
+//! [hill climbing](https://en.wikipedia.org/wiki/Hill_climbing). Starting from a random recipe
+//! we repeatedly move a single teaspoon from one ingredient to another, picking the move that
+//! most improves the recipe, until no move helps. The search restarts from a number of different
+//! random recipes to reduce the chance of getting stuck on a local maximum. Each random recipe is
+//! climbed twice, once for each part. For part two recipes are first compared by how close they
+//! are to the calorie target then by score.
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use std::array::from_fn;
+
+pub type Ingredient = [i32; 5];
+type Input = (i32, i32);
+
+const TEASPOONS: i32 = 100;
+const CALORIES: i32 = 500;
+const RESTARTS: usize = 100;
+
+pub fn parse(input: &str) -> Input {
+    let ingredients: Vec<Ingredient> = input.iter_signed().chunk::<5>().collect();
+    best_score(&ingredients, CALORIES)
+}
+
+pub fn part1(input: &Input) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &Input) -> i32 {
+    input.1
+}
+
+/// Finds the highest scoring recipe and the highest scoring recipe with exactly `calories`.
+pub fn best_score(ingredients: &[Ingredient], calories: i32) -> (i32, i32) {
+    if ingredients.len() <= 4 {
+        exhaustive(ingredients, calories, [0; 5], TEASPOONS)
+    } else {
+        hill_climb(ingredients, calories)
+    }
+}
+
+/// Recursively tries every amount of the first ingredient, leaving the remaining teaspoons
+/// for the other ingredients.
+fn exhaustive(
+    ingredients: &[Ingredient],
+    calories: i32,
+    total: Ingredient,
+    left: i32,
+) -> (i32, i32) {
+    let (first, rest) = ingredients.split_first().unwrap();
+
+    if rest.is_empty() {
+        let total: Ingredient = from_fn(|i| total[i] + left * first[i]);
+        let score = score(&total);
+        return (score, if total[4] == calories { score } else { 0 });
+    }
+
+    // Check if any property can never be greater than zero.
+    let check = (0..4).any(|i| {
+        let best = ingredients.iter().map(|ingredient| ingredient[i]).max().unwrap();
+        total[i] + best * left <= 0
+    });
+    if check {
+        return (0, 0);
+    }
+
+    (0..left + 1).fold((0, 0), |(part_one, part_two), amount| {
+        let next: Ingredient = from_fn(|i| total[i] + amount * first[i]);
+        let (one, two) = exhaustive(rest, calories, next, left - amount);
+        (part_one.max(one), part_two.max(two))
+    })
+}
+
+fn hill_climb(ingredients: &[Ingredient], calories: i32) -> (i32, i32) {
+    let n = ingredients.len();
+    let mut seed = 0x2545f4914f6cdd1d_u64;
+    let mut random = || {
+        // Xorshift is a fast and simple pseudorandom number generator.
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    let mut part_one = 0;
+    let mut part_two = 0;
+
+    for _ in 0..RESTARTS {
+        // Start with a random recipe.
+        let mut amounts = vec![0; n];
+        for _ in 0..TEASPOONS {
+            amounts[random() as usize % n] += 1;
+        }
+
+        let (_, score, _) = climb(ingredients, None, amounts.clone());
+        part_one = part_one.max(score);
+
+        let (distance, score, _) = climb(ingredients, Some(calories), amounts);
+        if distance == 0 {
+            part_two = part_two.max(score);
+        }
+    }
+
+    (part_one, part_two)
+}
+
+/// Moves one teaspoon at a time until no move improves the recipe, returning its ranking.
+fn climb(
+    ingredients: &[Ingredient],
+    calories: Option<i32>,
+    mut amounts: Vec<i32>,
+) -> (i32, i32, i32) {
+    // Recipes are ranked by distance from the calorie target (if any) then by score. As any
+    // negative property makes the score zero, ties are broken by how negative the properties are
+    // so that the search can climb out of the flat region.
+    let evaluate = |amounts: &[i32]| {
+        let total: Ingredient = from_fn(|i| {
+            amounts.iter().zip(ingredients).map(|(amount, ingredient)| amount * ingredient[i]).sum()
+        });
+        let distance = calories.map_or(0, |c| (total[4] - c).abs());
+        let deficit: i32 = total.iter().take(4).map(|&n| n.min(0)).sum();
+        (-distance, score(&total), deficit)
+    };
+
+    let n = amounts.len();
+    let mut current = evaluate(&amounts);
+
+    loop {
+        let mut next = None;
+
+        for from in 0..n {
+            if amounts[from] == 0 {
+                continue;
+            }
+
+            for to in (0..n).filter(|&to| to != from) {
+                amounts[from] -= 1;
+                amounts[to] += 1;
+
+                let candidate = evaluate(&amounts);
+                if candidate > current {
+                    current = candidate;
+                    next = Some((from, to));
+                }
+
+                amounts[from] += 1;
+                amounts[to] -= 1;
+            }
+        }
+
+        let Some((from, to)) = next else { break };
+        amounts[from] -= 1;
+        amounts[to] += 1;
+    }
+
+    current
+}
+
+fn score(total: &Ingredient) -> i32 {
+    total.iter().take(4).map(|&n| n.max(0)).product()
+}
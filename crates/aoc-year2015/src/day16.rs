@@ -1,8 +1,8 @@
 //! # Aunt Sue
 //!
 //! Brute force search through each aunt until we find one that matches all the facts.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> &str {
     input
@@ -16,7 +16,7 @@
 //!
 //! As an optimization containers are ordered from largest to smallest so that the
 //! recursive checking can exit as early as possible if we exceed 150 litres.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 use std::collections::BTreeMap;
 
 /// nCr for `n` from 0 to 4 inclusive.
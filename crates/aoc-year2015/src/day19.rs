@@ -4,7 +4,7 @@
 //!
 //! Part two uses the analysis from `askalski` provided on the
 //! [Day 19 solution megathread](https://www.reddit.com/r/adventofcode/comments/3xflz8/day_19_solutions/).
-use crate::util::hash::*;
+use aoc_util::hash::*;
 
 type Input<'a> = (&'a str, Vec<(&'a str, &'a str)>);
 
@@ -37,7 +37,7 @@
 // More explicit syntax fits in with surrounding code better.
 #![allow(clippy::needless_range_loop)]
 
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 const BLOCK: usize = 100_000;
 
@@ -0,0 +1,23 @@
+//! # RPG Simulator 20XX
+//!
+//! The trick to get the outcome of each battle quickly is to divide the hero's health by the
+//! boss's damage and vice-versa then find out how many turns each takes to win. The shop and
+//! fight math are shared with day 22 in the [`rpg`] module.
+//!
+//! [`rpg`]: crate::rpg
+use crate::rpg::*;
+
+type Result = (bool, i32);
+
+pub fn parse(input: &str) -> Vec<Result> {
+    let boss = Stats::parse(input);
+    loadouts().map(|item| (Stats::equip(100, item).defeats(boss), item.cost)).collect()
+}
+
+pub fn part1(input: &[Result]) -> i32 {
+    input.iter().filter(|(win, _)| *win).map(|&(_, cost)| cost).min().unwrap()
+}
+
+pub fn part2(input: &[Result]) -> i32 {
+    input.iter().filter(|(win, _)| !win).map(|&(_, cost)| cost).max().unwrap()
+}
@@ -7,10 +7,9 @@
 //! The key to optimizing is to cache previously seen states. As we receive states in strictly
 //! increasing order of mana spent if we see a state again then it cannot possibly be optimal
 //! and we can discard.
-use crate::util::hash::*;
-use crate::util::heap::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+use crate::rpg::*;
+use aoc_util::hash::*;
+use aoc_util::heap::*;
 
 type Input = [i16; 2];
 
@@ -25,7 +24,8 @@ struct State {
 }
 
 pub fn parse(input: &str) -> Input {
-    input.iter_signed().chunk::<2>().next().unwrap()
+    let Stats { health, damage, .. } = Stats::parse(input);
+    [health as i16, damage as i16]
 }
 
 pub fn part1(input: &Input) -> i16 {
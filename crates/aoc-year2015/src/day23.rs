@@ -5,7 +5,7 @@
 //! for one of two different numbers chosen depending on whether `a` is 0 or 1.
 //!
 //! The code fast enough to emulate directly without needing any understanding of what it's doing.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub enum Op {
     Hlf,
@@ -6,7 +6,7 @@
 //! Sorts the weights in ascending order, then tries combinations of increasing size until a
 //! match in found. This will be the answer since the package count is the smallest and the
 //! quantum entaglement will also be the lowest.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<u64> {
     let mut packages: Vec<_> = input.iter_unsigned().collect();
@@ -28,9 +28,9 @@
 //! The second part is realizing that the description of the code generation is
 //! [modular exponentiation](https://en.wikipedia.org/wiki/Modular_exponentiation). The exponent
 //! of the first code is zero, which is the reason for using a zero based index.
-use crate::util::iter::*;
-use crate::util::math::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
 type Input = [u64; 2];
 
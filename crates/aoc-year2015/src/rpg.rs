@@ -0,0 +1,117 @@
+//! Shared model of the role playing game used in [`day 21`] and [`day 22`].
+//!
+//! The shop sells exactly one weapon, at most one armor and up to two different rings.
+//! [`loadouts`] iterates over every valid combination, combining the stats of each item.
+//!
+//! A fight is decided without simulating every turn. Dividing the defender's health by the
+//! attacker's effective damage (always at least one) gives the number of turns each side needs.
+//! The hero attacks first so wins ties.
+//!
+//! [`day 21`]: crate::day21
+//! [`day 22`]: crate::day22
+use aoc_util::integer::*;
+use aoc_util::parse::*;
+use std::ops::Add;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Item {
+    pub cost: i32,
+    pub damage: i32,
+    pub armor: i32,
+}
+
+impl Item {
+    const fn new(cost: i32, damage: i32, armor: i32) -> Self {
+        Item { cost, damage, armor }
+    }
+}
+
+impl Add for Item {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Item {
+            cost: self.cost + rhs.cost,
+            damage: self.damage + rhs.damage,
+            armor: self.armor + rhs.armor,
+        }
+    }
+}
+
+pub const WEAPONS: [Item; 5] = [
+    Item::new(8, 4, 0),
+    Item::new(10, 5, 0),
+    Item::new(25, 6, 0),
+    Item::new(40, 7, 0),
+    Item::new(74, 8, 0),
+];
+
+pub const ARMOR: [Item; 5] = [
+    Item::new(13, 0, 1),
+    Item::new(31, 0, 2),
+    Item::new(53, 0, 3),
+    Item::new(75, 0, 4),
+    Item::new(102, 0, 5),
+];
+
+pub const RINGS: [Item; 6] = [
+    Item::new(25, 1, 0),
+    Item::new(50, 2, 0),
+    Item::new(100, 3, 0),
+    Item::new(20, 0, 1),
+    Item::new(40, 0, 2),
+    Item::new(80, 0, 3),
+];
+
+/// Health, damage and armor of either the hero or the boss.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub health: i32,
+    pub damage: i32,
+    pub armor: i32,
+}
+
+impl Stats {
+    /// Parses the boss's stats in the order listed in the input. Armor is optional.
+    pub fn parse(input: &str) -> Self {
+        let mut iter = input.iter_signed();
+        let health = iter.next().unwrap();
+        let damage = iter.next().unwrap();
+        let armor = iter.next().unwrap_or(0);
+        Stats { health, damage, armor }
+    }
+
+    /// Hero with the combined stats of some equipment.
+    pub fn equip(health: i32, item: Item) -> Self {
+        Stats { health, damage: item.damage, armor: item.armor }
+    }
+
+    /// Number of turns needed to defeat `defender`.
+    pub fn turns_to_defeat(self, defender: Stats) -> i32 {
+        let damage = (self.damage - defender.armor).max(1);
+        defender.health.ceil_div(damage)
+    }
+
+    /// Does this character win when attacking first?
+    pub fn defeats(self, defender: Stats) -> bool {
+        self.turns_to_defeat(defender) <= defender.turns_to_defeat(self)
+    }
+}
+
+/// Every valid combination of one weapon, zero or one armor and zero to two rings.
+pub fn loadouts() -> impl Iterator<Item = Item> {
+    let mut rings = vec![Item::default()];
+    for (i, &first) in RINGS.iter().enumerate() {
+        rings.push(first);
+        rings.extend(RINGS[i + 1..].iter().map(|&second| first + second));
+    }
+
+    let mut loadouts = Vec::with_capacity(WEAPONS.len() * (ARMOR.len() + 1) * rings.len());
+    for weapon in WEAPONS {
+        for armor in [Item::default()].into_iter().chain(ARMOR) {
+            loadouts.extend(rings.iter().map(|&ring| weapon + armor + ring));
+        }
+    }
+
+    loadouts.into_iter()
+}
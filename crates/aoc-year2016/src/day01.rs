@@ -0,0 +1,73 @@
+//! # No Time for a Taxicab
+//!
+//! The solution is short as it leverages three utility classes, [`geometry`] for segment
+//! intersection, [`parse`] for extracting integers from surrounding text and [`point`] for two
+//! dimensional rotations and translations.
+//!
+//! Part two could walk one block at a time, storing every location in a set. Instead each
+//! instruction is treated as a line segment, excluding its starting point which is shared with
+//! the previous segment. The new segment is intersected with every previous segment. The
+//! first location visited twice is the start of the intersection closest to the start of the
+//! new segment.
+//! There are only a few hundred segments, so checking every pair is fast.
+//!
+//! [`geometry`]: aoc_util::geometry
+//! [`parse`]: aoc_util::parse
+//! [`point`]: aoc_util::point
+use aoc_util::geometry::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
+
+type Pair = (u8, i32);
+
+pub fn parse(input: &str) -> Vec<Pair> {
+    let first = input.bytes().filter(u8::is_ascii_uppercase);
+    let second = input.iter_signed();
+    first.zip(second).collect()
+}
+
+pub fn part1(input: &[Pair]) -> i32 {
+    let mut position = ORIGIN;
+    let mut direction = UP;
+
+    for &(turn, amount) in input {
+        direction =
+            if turn == b'L' { direction.counter_clockwise() } else { direction.clockwise() };
+        position += direction * amount;
+    }
+
+    position.manhattan(ORIGIN)
+}
+
+pub fn part2(input: &[Pair]) -> i32 {
+    let mut position = ORIGIN;
+    let mut direction = UP;
+    let mut segments = Vec::with_capacity(input.len());
+
+    for &(turn, amount) in input {
+        direction =
+            if turn == b'L' { direction.counter_clockwise() } else { direction.clockwise() };
+        if amount == 0 {
+            continue;
+        }
+
+        let end = position + direction * amount;
+        let segment = Segment::new((position + direction).into(), end.into());
+
+        // Of all the overlaps with previous segments, find the one nearest to where we started.
+        let first = segments
+            .iter()
+            .filter_map(|&previous| segment.intersection(previous))
+            .map(|overlap| overlap.start)
+            .min_by_key(|&[x, y]| x.abs_diff(segment.start[0]) + y.abs_diff(segment.start[1]));
+
+        if let Some([x, y]) = first {
+            return (x.abs() + y.abs()) as i32;
+        }
+
+        segments.push(segment);
+        position = end;
+    }
+
+    unreachable!()
+}
@@ -2,10 +2,10 @@
 //!
 //! Relies heavily on the [`point`] and [`grid`] modules.
 //!
-//! [`grid`]: crate::util::grid
-//! [`point`]: crate::util::point
-use crate::util::grid::*;
-use crate::util::point::*;
+//! [`grid`]: aoc_util::grid
+//! [`point`]: aoc_util::point
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 pub fn parse(input: &str) -> Vec<&str> {
     input.lines().collect()
@@ -3,10 +3,10 @@
 //! We rely on the [`iter`] and [`parse`] utility modules to extract integers from surrounding
 //! text then group together in chunks of three.
 //!
-//! [`iter`]: crate::util::iter
-//! [`parse`]: crate::util::parse
-use crate::util::iter::*;
-use crate::util::parse::*;
+//! [`iter`]: aoc_util::iter
+//! [`parse`]: aoc_util::parse
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<u32> {
     input.iter_unsigned().collect()
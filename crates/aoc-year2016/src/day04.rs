@@ -7,7 +7,7 @@
 //! In part two as the [Caesar cipher](https://en.wikipedia.org/wiki/Caesar_cipher) does
 //! not change the length of words, we can also eliminate most candidates with a simple length
 //! check and only decrypt a much smaller number of strings.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub struct Room<'a> {
     name: &'a str,
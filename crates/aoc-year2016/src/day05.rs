@@ -3,9 +3,9 @@
 //! Essentially a repeat of [`Year 2015 Day 4`]. We brute force MD5 hashes as quickly as
 //! possible in parallel in blocks of 1000 at a time.
 //!
-//! [`Year 2015 Day 4`]: crate::year2015::day04
-use crate::util::md5::*;
-use crate::util::thread::*;
+//! [`Year 2015 Day 4`]: ../../aoc_year2015/day04/index.html
+use aoc_util::md5::*;
+use aoc_util::thread::*;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
@@ -115,8 +115,7 @@ fn worker(shared: &Shared) {
 #[cfg(feature = "simd")]
 mod simd {
     use super::*;
-    use crate::util::md5::simd::hash;
-    use std::simd::{LaneCount, SupportedLaneCount};
+    use aoc_util::md5::simd::hash;
 
     #[expect(clippy::needless_range_loop)]
     fn check_hash_simd<const N: usize>(
@@ -125,9 +124,7 @@ mod simd {
         start: u32,
         offset: u32,
         shared: &Shared,
-    ) where
-        LaneCount<N>: SupportedLaneCount,
-    {
+    ) {
         // Format macro is very slow, so update digits directly
         for i in 0..N {
             let n = offset + i as u32;
@@ -1,38 +1,44 @@
 //! # Internet Protocol Version 7
 //!
 //! It's faster to treat the entire input as one big stream, using line breaks to increment
-//! the count if an address is valid.
+//! the count if an address is valid. A window only counts if its outer bytes are letters, so it
+//! can never straddle a bracket or line break. The bracket depth is tracked rather than a single
+//! flag, so that a window after a nested hypernet sequence closes is still inside the outer one.
+//! No segments are copied or allocated.
 //!
 //! For part two there are at most 26 * 26 = 676 possible ABA or BAB sequences so we can use
 //! a fixed size array to keep track of which ones we've seen for the current address so far.
+//!
+//! [`supports_tls`] and [`supports_ssl`] run the same scans over a single address.
 pub fn parse(input: &str) -> &[u8] {
     input.as_bytes()
 }
 
 pub fn part1(input: &[u8]) -> usize {
     let mut count = 0;
-    let mut inside = false;
+    let mut depth = 0;
     let mut positive = false;
     let mut negative = false;
 
     for w in input.windows(4) {
         if w[0].is_ascii_lowercase() {
-            if w[0] == w[3] && w[1] == w[2] && w[0] != w[1] {
-                if inside {
+            if w[0] == w[3] && w[1] == w[2] && w[0] != w[1] && w[1].is_ascii_lowercase() {
+                if depth > 0 {
                     negative = true;
                 } else {
                     positive = true;
                 }
             }
         } else if w[0] == b'[' {
-            inside = true;
+            depth += 1;
         } else if w[0] == b']' {
-            inside = false;
+            depth -= 1;
         } else {
             // Next line
             if positive && !negative {
                 count += 1;
             }
+            depth = 0;
             positive = false;
             negative = false;
         }
@@ -44,18 +50,18 @@ pub fn part1(input: &[u8]) -> usize {
 pub fn part2(input: &[u8]) -> usize {
     let mut count = 0;
     let mut version = 0;
-    let mut inside = false;
+    let mut depth = 0;
     let mut positive = false;
     let mut aba = [usize::MAX; 676];
     let mut bab = [usize::MAX; 676];
 
     for w in input.windows(3) {
         if w[1].is_ascii_lowercase() {
-            if w[0] == w[2] && w[0] != w[1] {
+            if w[0] == w[2] && w[0] != w[1] && w[0].is_ascii_lowercase() {
                 let first = (w[0] - b'a') as usize;
                 let second = (w[1] - b'a') as usize;
 
-                if inside {
+                if depth > 0 {
                     // Reverse the order of letters
                     let index = 26 * second + first;
                     bab[index] = version;
@@ -67,18 +73,29 @@ pub fn part2(input: &[u8]) -> usize {
                 }
             }
         } else if w[1] == b'[' {
-            inside = true;
+            depth += 1;
         } else if w[1] == b']' {
-            inside = false;
+            depth -= 1;
         } else {
             // Next line
             if positive {
                 count += 1;
             }
             version += 1;
+            depth = 0;
             positive = false;
         }
     }
 
     if positive { count + 1 } else { count }
 }
+
+/// An ABBA outside every hypernet sequence and none inside.
+pub fn supports_tls(address: &[u8]) -> bool {
+    part1(address) == 1
+}
+
+/// An ABA outside any hypernet sequence with the corresponding BAB inside one.
+pub fn supports_ssl(address: &[u8]) -> bool {
+    part2(address) == 1
+}
@@ -0,0 +1,97 @@
+//! # Two-Factor Authentication
+//!
+//! The screen is a small dot matrix [`Display`] stored as a [`Grid`] of bytes. Rotating a row
+//! uses [`rotate_right`] on the contiguous row slice. Columns are strided in memory so rotating
+//! a column first gathers the column into a small buffer, rotates the buffer, then scatters it
+//! back.
+//!
+//! The lit pixels of part two spell out capital letters that are read using the shared [`ocr`]
+//! module, falling back to drawing the screen if any letter isn't recognized.
+//!
+//! [`rotate_right`]: slice::rotate_right
+//! [`ocr`]: aoc_util::ocr
+use aoc_util::grid::*;
+use aoc_util::ocr::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
+
+const ON: u8 = b'#';
+const OFF: u8 = b'.';
+
+pub struct Display {
+    pub grid: Grid<u8>,
+}
+
+impl Display {
+    pub fn new(width: i32, height: i32) -> Self {
+        Display { grid: Grid::new(width, height, OFF) }
+    }
+
+    /// Applies a single instruction, for example `rotate column x=1 by 1`.
+    pub fn apply(&mut self, line: &str) {
+        let [a, b] = line.extract_signed::<i32, 2>();
+
+        if line.starts_with("rect") {
+            self.rect(a, b);
+        } else if line.starts_with("rotate row") {
+            self.rotate_row(a, b);
+        } else {
+            self.rotate_column(a, b);
+        }
+    }
+
+    /// Turns on every pixel in the top left `width` x `height` rectangle.
+    pub fn rect(&mut self, width: i32, height: i32) {
+        for y in 0..height {
+            self.grid.row_mut(y)[..width as usize].fill(ON);
+        }
+    }
+
+    /// Shifts every pixel in row `y` right by `amount`, wrapping around the edge.
+    pub fn rotate_row(&mut self, y: i32, amount: i32) {
+        let amount = amount % self.grid.width;
+        self.grid.row_mut(y).rotate_right(amount as usize);
+    }
+
+    /// Shifts every pixel in column `x` down by `amount`, wrapping around the edge.
+    pub fn rotate_column(&mut self, x: i32, amount: i32) {
+        let Grid { width, height, .. } = self.grid;
+        let amount = amount % height;
+        let index = |y: i32| (width * y + x) as usize;
+
+        let mut column: Vec<_> = (0..height).map(|y| self.grid.bytes[index(y)]).collect();
+        column.rotate_right(amount as usize);
+
+        for (y, pixel) in (0..height).zip(column) {
+            self.grid.bytes[index(y)] = pixel;
+        }
+    }
+
+    pub fn lit(&self) -> usize {
+        self.grid.count(|&b| b == ON)
+    }
+
+    /// Decodes the letters shown on the display, or draws the pixels as a multiline string if
+    /// any letter isn't recognized.
+    pub fn read(&self) -> String {
+        let grid = &self.grid;
+        decode(grid.width, |x, y| grid[Point::new(x, y)] == ON)
+            .unwrap_or_else(|| format!("\n{}", grid.render()))
+    }
+}
+
+pub fn parse(input: &str) -> Display {
+    let mut display = Display::new(50, 6);
+    for line in input.lines() {
+        display.apply(line);
+    }
+    display
+}
+
+pub fn part1(input: &Display) -> usize {
+    input.lit()
+}
+
+pub fn part2(input: &Display) -> String {
+    input.read()
+}
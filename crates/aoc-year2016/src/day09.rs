@@ -1,7 +1,7 @@
 //! # Explosives in Cyberspace
 //!
 //! The only difference between part one and two is that we recursively decompress inner sequences.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> &[u8] {
     input.trim().as_bytes()
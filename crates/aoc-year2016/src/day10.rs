@@ -6,9 +6,11 @@
 //! We maintain a [`VecDeque`] of chips and destinations starting with raw inputs.
 //! Once each robot receives 2 chips then its low and high outputs are added to the queue.
 //!
-//! As a minor optimization we only need to store the product of outputs 0, 1 and 2.
-use crate::util::hash::*;
-use crate::util::parse::*;
+//! Each bot is evaluated exactly once, as soon as both of its inputs are known, so the queue
+//! visits the chip-flow graph in topological order. The resulting [`Factory`] records the bot
+//! that compared every pair of chips and the chip that ends up in every output.
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 use std::collections::VecDeque;
 
 type Input = (u32, u32);
@@ -21,15 +23,40 @@ struct Bot<'a> {
     amount: usize,
 }
 
+/// Result of running every chip through the factory.
+pub struct Factory {
+    compared: FastMap<(u32, u32), u32>,
+    outputs: FastMap<u32, u32>,
+}
+
+impl Factory {
+    /// Bot responsible for comparing the chips `low` and `high`.
+    pub fn comparer(&self, low: u32, high: u32) -> Option<u32> {
+        self.compared.get(&(low, high)).copied()
+    }
+
+    /// Chip that ends up in output bin `index`.
+    pub fn output(&self, index: u32) -> Option<u32> {
+        self.outputs.get(&index).copied()
+    }
+}
+
 pub fn parse(input: &str) -> Input {
+    let factory = factory(input);
+    let part_one = factory.comparer(17, 61).unwrap_or(u32::MAX);
+    let part_two = (0..3).filter_map(|index| factory.output(index)).product();
+    (part_one, part_two)
+}
+
+pub fn factory(input: &str) -> Factory {
     let tokens: Vec<_> = input.split_ascii_whitespace().collect();
     let mut tokens = &tokens[..];
 
     let mut todo = VecDeque::new();
     let mut bots = FastMap::new();
 
-    let mut part_one = u32::MAX;
-    let mut part_two = 1;
+    let mut compared = FastMap::new();
+    let mut outputs = FastMap::new();
 
     while !tokens.is_empty() {
         if tokens[0] == "value" {
@@ -62,18 +89,15 @@ pub fn parse(input: &str) -> Input {
 
                     todo.push_back((bot.low, min));
                     todo.push_back((bot.high, max));
-
-                    if min == 17 && max == 61 {
-                        part_one = index;
-                    }
+                    compared.insert((min, max), index);
                 }
             });
-        } else if index <= 2 {
-            part_two *= value;
+        } else {
+            outputs.insert(index, value);
         }
     }
 
-    (part_one, part_two)
+    Factory { compared, outputs }
 }
 
 pub fn part1(input: &Input) -> u32 {
@@ -24,7 +24,7 @@
 //! As a further optimization we assume that there are no more than 15 generators and microchips
 //! and store the total packed into a single byte for each floor. This reduces the size of each
 //! state to only 8 bytes making it quick to copy and hash.
-use crate::util::hash::*;
+use aoc_util::hash::*;
 use std::collections::VecDeque;
 
 // Interestingly it was slightly faster using a `u32` for `elevator` so that the total size of
@@ -33,7 +33,7 @@
 //! plus some constant offset. We can replace the entire code with a single multiplication.
 //! If we had emulated the raw instructions then it would have taken ~10,000,000 iterations to
 //! obtain the answer.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 /// Extract the constant offset from the assembunny code.
 pub fn parse(input: &str) -> u32 {
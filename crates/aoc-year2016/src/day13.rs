@@ -0,0 +1,162 @@
+//! # A Maze of Twisty Little Cubicles
+//!
+//! We first generate the maze dynamically then explore it using a
+//! [BFS](https://en.wikipedia.org/wiki/Breadth-first_search) solving both part one and part
+//! two simultaneously.
+//!
+//! As we start at (1, 1) and the most steps that we are interested in is 50, we can bound
+//! the maze to 2 + 50 = 52 in each dimension, growing the bounds if the target lies further out.
+//! The shortest path to the target could still leave the bounds and come back. Any such path
+//! takes at least two more steps than the cell on the edge where it leaves, so if every edge
+//! cell is at least that far away then the answer is exact. Otherwise the bounds are doubled
+//! and the search repeated.
+//!
+//! The [`is_open`] wall predicate and an alternative [A*](https://en.wikipedia.org/wiki/A*_search_algorithm)
+//! search for the target are exposed so that different favorite numbers and targets can be explored.
+//! A* uses the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) as its
+//! heuristic and treats the maze as unbounded.
+
+// Explicit syntax is cleaner for this case.
+#![allow(clippy::needless_range_loop)]
+
+use aoc_util::hash::*;
+use aoc_util::heap::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
+use std::collections::VecDeque;
+
+type Input = (u32, u32);
+
+pub fn parse(input: &str) -> Input {
+    let favorite = input.unsigned();
+    bfs(favorite, 31, 39)
+}
+
+pub fn part1(input: &Input) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &Input) -> u32 {
+    input.1
+}
+
+/// A location is open if the quadratic form plus the favorite number has an even number of
+/// set bits.
+#[inline]
+pub fn is_open(favorite: usize, x: usize, y: usize) -> bool {
+    let n = (x * x) + (3 * x) + (2 * x * y) + y + (y * y) + favorite;
+    n.count_ones() % 2 == 0
+}
+
+/// Returns the fewest steps from (1, 1) to the target and the number of distinct locations
+/// reachable in at most 50 steps. Like [`astar`] this will not terminate if the target is
+/// unreachable but the start region is infinite.
+pub fn bfs(favorite: usize, target_x: usize, target_y: usize) -> Input {
+    let mut size = 52.max(target_x + 2).max(target_y + 2);
+
+    loop {
+        let (part_one, part_two, edge) = bounded(favorite, target_x, target_y, size);
+
+        match (part_one, edge) {
+            // Unreachable target in a finite region.
+            (None, None) => break (0, part_two),
+            (Some(part_one), edge) if edge.is_none_or(|edge| edge + 2 >= part_one) => {
+                break (part_one, part_two);
+            }
+            _ => size *= 2,
+        }
+    }
+}
+
+/// Searches a `size` by `size` square, returning the fewest steps to the target if reached,
+/// the number of locations within 50 steps and the fewest steps to any open cell on the far
+/// edges, where a shorter path could leave the square.
+fn bounded(
+    favorite: usize,
+    target_x: usize,
+    target_y: usize,
+    size: usize,
+) -> (Option<u32>, u32, Option<u32>) {
+    let mut maze = vec![vec![false; size]; size];
+
+    for x in 0..size {
+        for y in 0..size {
+            maze[x][y] = is_open(favorite, x, y);
+        }
+    }
+
+    let mut part_one = None;
+    let mut part_two = 0;
+    let mut edge = None;
+    let mut todo = VecDeque::new();
+
+    todo.push_back((1, 1, 0));
+    maze[1][1] = false;
+
+    while let Some((x, y, cost)) = todo.pop_front() {
+        if x == target_x && y == target_y {
+            part_one = Some(cost);
+        }
+        if edge.is_none() && (x == size - 1 || y == size - 1) {
+            edge = Some(cost);
+        }
+        if cost <= 50 {
+            part_two += 1;
+        }
+
+        if x > 0 && maze[x - 1][y] {
+            todo.push_back((x - 1, y, cost + 1));
+            maze[x - 1][y] = false;
+        }
+        if y > 0 && maze[x][y - 1] {
+            todo.push_back((x, y - 1, cost + 1));
+            maze[x][y - 1] = false;
+        }
+        if x < size - 1 && maze[x + 1][y] {
+            todo.push_back((x + 1, y, cost + 1));
+            maze[x + 1][y] = false;
+        }
+        if y < size - 1 && maze[x][y + 1] {
+            todo.push_back((x, y + 1, cost + 1));
+            maze[x][y + 1] = false;
+        }
+    }
+
+    (part_one, part_two, edge)
+}
+
+/// Returns the fewest steps from (1, 1) to the target, or `None` if the start is enclosed by
+/// walls. As the maze is unbounded the search will not terminate if the target is unreachable
+/// but the start region is infinite.
+pub fn astar(favorite: usize, target_x: usize, target_y: usize) -> Option<u32> {
+    let start = Point::new(1, 1);
+    let target = Point::new(target_x as i32, target_y as i32);
+    let heuristic = |point: Point| point.manhattan(target) as u32;
+
+    let mut todo = MinHeap::new();
+    let mut cost = FastMap::new();
+
+    todo.push(heuristic(start), start);
+    cost.insert(start, 0);
+
+    while let Some((_, position)) = todo.pop() {
+        let steps = cost[&position];
+
+        if position == target {
+            return Some(steps);
+        }
+
+        for next in ORTHOGONAL.map(|o| position + o) {
+            if next.x >= 0
+                && next.y >= 0
+                && is_open(favorite, next.x as usize, next.y as usize)
+                && cost.get(&next).is_none_or(|&c| steps + 1 < c)
+            {
+                todo.push(steps + 1 + heuristic(next), next);
+                cost.insert(next, steps + 1);
+            }
+        }
+    }
+
+    None
+}
@@ -2,8 +2,8 @@
 //!
 //! Brute force slog through all possible keys, parallelized as much as possible. An optimization
 //! for part two is a quick method to convert `u32` to 8 ASCII digits.
-use crate::util::md5::*;
-use crate::util::thread::*;
+use aoc_util::md5::*;
+use aoc_util::thread::*;
 use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
@@ -8,8 +8,8 @@
 //! the first integer. Then we search for each subsequent integer located at the correct offset of
 //! minutes and multiply the step by the new integer. This preserve the relative offset at each step
 //! in the next search.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Disc = [usize; 2];
 
@@ -12,8 +12,8 @@
 //! The approach from "Waiting: Parking and Condition Variables" in the excellent book
 //! [Rust Atomics and Locks](https://marabos.nl/atomics/) prevent idle threads from busy
 //! looping on the mutex.
-use crate::util::md5::*;
-use crate::util::thread::*;
+use aoc_util::md5::*;
+use aoc_util::thread::*;
 use std::sync::{Condvar, Mutex};
 
 type Input = (String, usize);
@@ -37,6 +37,28 @@ struct Shared {
 }
 
 pub fn parse(input: &str) -> Input {
+    search(input)
+}
+
+pub fn part1(input: &Input) -> &str {
+    &input.0
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.1
+}
+
+/// Shortest path to the vault, for example `DDRRRD` for passcode `ihgpwlah`.
+pub fn shortest_path(passcode: &str) -> String {
+    search(passcode).0
+}
+
+/// Length of the longest path that reaches the vault.
+pub fn longest_path_len(passcode: &str) -> usize {
+    search(passcode).1
+}
+
+fn search(input: &str) -> Input {
     // Initial starting position is the top left corner.
     let input = input.trim().as_bytes();
     let prefix = input.len();
@@ -54,14 +76,6 @@ pub fn parse(input: &str) -> Input {
     (global.min, global.max)
 }
 
-pub fn part1(input: &Input) -> &str {
-    &input.0
-}
-
-pub fn part2(input: &Input) -> usize {
-    input.1
-}
-
 /// Process local work items, stopping every now and then to redistribute items back to global pool.
 /// This prevents threads idling or hotspotting.
 fn worker(shared: &Shared) {
@@ -79,7 +79,7 @@
 //! Now that we have 5 elves our starting elf `a` is one step away from `c` so the answer is 2.
 //!
 //! [`ilog2`]: u32::ilog2
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> u32 {
     input.unsigned()
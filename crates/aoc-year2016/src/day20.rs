@@ -0,0 +1,28 @@
+//! # Firewall Rules
+//!
+//! The blocked ranges are merged into disjoint intervals using our [`merge`] utility function,
+//! which sorts by the *starting* address. The inclusive ranges in the input are converted to
+//! half open intervals. Allowed addresses are then the gaps between intervals.
+//!
+//! [`merge`]: aoc_util::interval::merge
+use aoc_util::interval::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+
+/// Total number of IPv4 addresses.
+const ADDRESSES: u64 = 1 << 32;
+
+pub fn parse(input: &str) -> Vec<Interval<u64>> {
+    merge(input.iter_unsigned().chunk::<2>().map(|[start, end]| Interval::new(start, end + 1)))
+}
+
+pub fn part1(input: &[Interval<u64>]) -> u64 {
+    match input.first() {
+        Some(first) if first.start == 0 => first.end,
+        _ => 0,
+    }
+}
+
+pub fn part2(input: &[Interval<u64>]) -> u64 {
+    ADDRESSES - input.iter().map(|i| i.len()).sum::<u64>()
+}
@@ -2,7 +2,7 @@
 //!
 //! The forward transformations are a straightforward. The trickiest reverse
 //! implementation is the rotation based on the index of the letter.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 #[derive(Clone, Copy)]
 pub enum Op {
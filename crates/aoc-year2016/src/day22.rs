@@ -59,8 +59,8 @@
 //!
 //! To find the minimum number of steps we only need to find the `(x, y)` coordinates of the empty
 //! spot and the width of the wall, then add up the sequence of moves.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub struct Node {
     x: u32,
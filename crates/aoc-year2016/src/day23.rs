@@ -21,8 +21,8 @@
 //! If we had emulated the raw instructions directly then it would have taken billions of
 //! iterations to get the answer.
 //!
-//! [`Day 12`]: crate::year2016::day12
-use crate::util::parse::*;
+//! [`Day 12`]: crate::day12
+use aoc_util::parse::*;
 
 /// Extract the constant offset from the assembunny code.
 pub fn parse(input: &str) -> u32 {
@@ -16,11 +16,11 @@
 //! There are 8 locations, however since we always start at `0` this requires checking only
 //! 7! = 5,040 permutations. We find the answer to both part one and two simultaneously.
 //!
-//! [`permutations`]: crate::util::slice
-//! [`Year 2015 Day 13`]: crate::year2015::day13
-use crate::util::grid::*;
-use crate::util::parse::*;
-use crate::util::slice::*;
+//! [`permutations`]: aoc_util::slice
+//! [`Year 2015 Day 13`]: ../../aoc_year2015/day13/index.html
+use aoc_util::grid::*;
+use aoc_util::parse::*;
+use aoc_util::slice::*;
 use std::collections::VecDeque;
 
 type Input = (u32, u32);
@@ -12,9 +12,9 @@
 //! To obtain the desired pattern we need the next highest binary number that has the
 //! pattern `101010..`.
 //!
-//! [`Day 12`]: crate::year2016::day12
-//! [`Day 23`]: crate::year2016::day23
-use crate::util::parse::*;
+//! [`Day 12`]: crate::day12
+//! [`Day 23`]: crate::day23
+use aoc_util::parse::*;
 
 /// Extract the constant offset from the assembunny code.
 pub fn parse(input: &str) -> u32 {
@@ -2,7 +2,7 @@
 //!
 //! Modern hardware is so good at shuffling memory around that it's faster to rotate the entire
 //! array instead of stepping through elements one at a time with an index modulo array length.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> &[u8] {
     input.trim().as_bytes()
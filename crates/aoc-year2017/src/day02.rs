@@ -3,7 +3,7 @@
 //! Part two is `O(n²)` complexity but at least we can reduce by a factor of two by sorting
 //! each line first, allowing us to only compare each number against those that are greater.
 //! As a minor benefit this makes part one faster too.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Input = Vec<Vec<u32>>;
 
@@ -27,9 +27,9 @@
 //!
 //! We use the [`Point`] utility to move in the spiral direction. Values are stored in a hashmap
 //! defaulting to zero if the value doesn't exist yet.
-use crate::util::hash::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 pub fn parse(input: &str) -> u32 {
     input.unsigned()
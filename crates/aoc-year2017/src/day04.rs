@@ -12,7 +12,7 @@
 //!
 //! Then a [`FastSet`] is used to detect duplicates. Sorting the letters in each word so that
 //! anagrams become the same also works but is slower.
-use crate::util::hash::*;
+use aoc_util::hash::*;
 
 type Input<'a> = Vec<&'a str>;
 
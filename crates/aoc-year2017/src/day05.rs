@@ -15,7 +15,7 @@
 //!
 //! We then precompute all possible combination for blocks of size 16, using this to accelerate
 //! part two.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 const WIDTH: usize = 16;
 const LENGTH: usize = 1 << WIDTH;
@@ -10,8 +10,8 @@
 //! This makes it very fast to find the highest nibble using bitwise logic. To detect the cycle
 //! a [`FastMap`] stores each previously seen memory layout along with the cycle that it first
 //! appeared.
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 type Input = (u32, u32);
 
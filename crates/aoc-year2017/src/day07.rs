@@ -9,8 +9,8 @@
 //! so that we can disambiguate.
 //!
 //! [`Rc`]: std::rc::Rc
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 use std::collections::VecDeque;
 
 #[derive(Clone, Copy, Default)]
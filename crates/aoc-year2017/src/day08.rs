@@ -1,9 +1,9 @@
 //! # I Heard You Like Registers
 //!
 //! Computes both parts in a single pass.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Input = (i32, i32);
 
@@ -6,9 +6,13 @@
 //!
 //! [`rotate_left`]: slice::rotate_left
 //! [`reverse`]: slice::reverse
-use crate::util::parse::*;
+use aoc_util::lookup_table;
+use aoc_util::parse::*;
 use std::fmt::Write as _;
 
+/// Initial list of numbers from 0 to 255 in order.
+const IDENTITY: [u8; 256] = lookup_table!(256, |i| i as u8);
+
 pub fn parse(input: &str) -> &str {
     input
 }
@@ -34,8 +38,8 @@ pub fn part2(input: &str) -> String {
     result
 }
 
-fn hash(lengths: &[usize], rounds: usize) -> Vec<u8> {
-    let mut knot: Vec<_> = (0..=255).collect();
+fn hash(lengths: &[usize], rounds: usize) -> [u8; 256] {
+    let mut knot = IDENTITY;
     let mut position = 0;
     let mut skip = 0;
 
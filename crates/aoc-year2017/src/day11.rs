@@ -0,0 +1,37 @@
+//! # Hex Ed
+//!
+//! Hex grid parsing and navigation uses the shared [`hex`] module with
+//! [Axial Coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-cube)
+//! exactly as described in the excellent [Red Blob Games](https://www.redblobgames.com/) blog.
+//!
+//! As mentioned in the blog, the Manhattan distance to the center has the formula
+//! `(q.abs() + r.abs() + s.abs()) / 2`
+//!
+//! [`hex`]: aoc_util::hex
+use aoc_util::hex::*;
+
+type Input = (i32, i32);
+
+pub fn parse(input: &str) -> Input {
+    let mut position = ORIGIN;
+    let mut part_one = 0;
+    let mut part_two = 0;
+
+    for step in flat(input) {
+        position += step;
+        // Manhattan distance to the center.
+        part_one = position.distance(ORIGIN);
+        // Keep track of furthest distance.
+        part_two = part_two.max(part_one);
+    }
+
+    (part_one, part_two)
+}
+
+pub fn part1(input: &Input) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &Input) -> i32 {
+    input.1
+}
@@ -0,0 +1,29 @@
+//! # Digital Plumber
+//!
+//! This problem is the classic [union-find](https://en.wikipedia.org/wiki/Disjoint-set_data_structure).
+//! Each line lists the neighbors of one program, giving an edge list that is passed to the shared
+//! [`components`] helper. This returns the size of each connected group, ordered so that the
+//! group containing program `0` is first.
+use aoc_util::graph::*;
+use aoc_util::parse::*;
+
+pub fn parse(input: &str) -> Vec<usize> {
+    let lines: Vec<_> = input.lines().collect();
+    let mut edges = Vec::with_capacity(lines.len() * 3);
+
+    // At least the first 6 characters of each line can be skipped as it only contains the index
+    // that we already know.
+    for (index, line) in lines.iter().enumerate() {
+        edges.extend((&line[6..]).iter_unsigned().map(|next| (index, next)));
+    }
+
+    components(lines.len(), edges)
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    input[0]
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    input.len()
+}
@@ -49,10 +49,10 @@
 //!
 //! The lowest remaining value is our answer `10`.
 //!
-//! [`Year 2016 Day 15`]: crate::year2016::day15
-use crate::util::iter::*;
-use crate::util::math::*;
-use crate::util::parse::*;
+//! [`Year 2016 Day 15`]: ../../aoc_year2016/day15/index.html
+use aoc_util::iter::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
 type Input = Vec<[u32; 2]>;
 
@@ -1,14 +1,21 @@
 //! # Disk Defragmentation
 //!
 //! This problem is a blend of the hashing from [`Day 10`] and the connected clique finding
-//! from [`Day 12`] and reuses the same flood fill approach to count groups.
+//! from [`Day 12`]. Our utility [`Grid`] module labels connected regions, then we count the
+//! regions of used squares.
 //!
-//! [`Day 10`]: crate::year2017::day10
-//! [`Day 12`]: crate::year2017::day12
-use crate::util::thread::*;
+//! [`Day 10`]: crate::day10
+//! [`Day 12`]: crate::day12
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::lookup_table;
+use aoc_util::thread::*;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+/// Initial list of numbers from 0 to 255 in order.
+const IDENTITY: [u8; 256] = lookup_table!(256, |i| i as u8);
+
 /// Atomics can be safely shared between threads.
 pub struct Shared {
     prefix: String,
@@ -39,19 +46,17 @@ pub fn part1(input: &[u8]) -> u32 {
     input.iter().map(|&n| n as u32).sum()
 }
 
-pub fn part2(input: &[u8]) -> u32 {
-    let mut grid: Vec<_> = input.iter().map(|&n| n == 1).collect();
-    let mut groups = 0;
+pub fn part2(input: &[u8]) -> usize {
+    let grid = Grid { width: 128, height: 128, bytes: input.to_vec() };
+    let (labels, sizes) = grid.label_regions(|a, b| a == b);
 
-    for start in 0..0x4000 {
-        // DFS from each new group.
-        if grid[start] {
-            groups += 1;
-            dfs(&mut grid, start);
-        }
+    // Free squares also form regions so only count labels of used squares.
+    let mut used = vec![false; sizes.len()];
+    for (&label, &square) in labels.bytes.iter().zip(input) {
+        used[label] |= square == 1;
     }
 
-    groups
+    used.iter().filter(|&&u| u).count()
 }
 
 /// Each worker thread chooses the next available index then computes the hash and patches the
@@ -92,8 +97,8 @@ fn fill_row(prefix: &str, index: usize) -> [u8; 128] {
 }
 
 /// Slightly tweaked version of the code from Day 10 that always performs 64 rounds.
-fn knot_hash(lengths: &[usize]) -> Vec<u8> {
-    let mut knot: Vec<_> = (0..=255).collect();
+fn knot_hash(lengths: &[usize]) -> [u8; 256] {
+    let mut knot = IDENTITY;
     let mut position = 0;
     let mut skip = 0;
 
@@ -111,23 +116,3 @@ fn knot_hash(lengths: &[usize]) -> Vec<u8> {
     knot.rotate_right(position % 256);
     knot
 }
-
-/// Flood fill that explores the connected squares in the grid.
-fn dfs(grid: &mut [bool], index: usize) {
-    grid[index] = false;
-    let x = index % 128;
-    let y = index / 128;
-
-    if x > 0 && grid[index - 1] {
-        dfs(grid, index - 1);
-    }
-    if x < 127 && grid[index + 1] {
-        dfs(grid, index + 1);
-    }
-    if y > 0 && grid[index - 128] {
-        dfs(grid, index - 128);
-    }
-    if y < 127 && grid[index + 128] {
-        dfs(grid, index + 128);
-    }
-}
@@ -6,12 +6,18 @@
 //!
 //! The sequence of numbers are [modular exponentiation](https://en.wikipedia.org/wiki/Modular_exponentiation)
 //! so we can jump to any location in the sequence, without needing to know the previous numbers.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::math::*;
-use crate::util::parse::*;
+//!
+//! When built with the `no-threads` feature blocks are generated on demand by the judge instead.
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
+#[cfg(not(feature = "no-threads"))]
+use aoc_util::thread::threads;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::sync::mpsc::{Receiver, Sender, channel};
+#[cfg(not(feature = "no-threads"))]
+use std::sync::mpsc::{Sender, channel};
+#[cfg(not(feature = "no-threads"))]
 use std::thread;
 
 const PART_ONE: usize = 40_000_000;
@@ -39,16 +45,25 @@ struct Block {
 pub fn parse(input: &str) -> Input {
     let [first, second] = input.iter_unsigned().chunk::<2>().next().unwrap();
     let shared = Shared { first, second, start: AtomicUsize::new(0), done: AtomicBool::new(false) };
-    let (tx, rx) = channel();
 
-    thread::scope(|scope| {
-        // Use all cores except one to generate blocks of numbers for judging.
-        for _ in 0..thread::available_parallelism().unwrap().get() - 1 {
-            scope.spawn(|| sender(&shared, &tx));
-        }
-        // Judge batches serially.
-        receiver(&shared, &rx)
-    })
+    #[cfg(not(feature = "no-threads"))]
+    {
+        let (tx, rx) = channel();
+        let threads = threads();
+
+        thread::scope(|scope| {
+            // Use all cores except one to generate blocks of numbers for judging, but always
+            // at least one so that single core machines still make progress.
+            for _ in 0..threads.saturating_sub(1).max(1) {
+                scope.spawn(|| sender(&shared, &tx));
+            }
+            // Judge batches serially.
+            receiver(&shared, || rx.recv().unwrap())
+        })
+    }
+
+    #[cfg(feature = "no-threads")]
+    receiver(&shared, || generate(&shared))
 }
 
 pub fn part1(input: &Input) -> u32 {
@@ -59,42 +74,52 @@ pub fn part2(input: &Input) -> u32 {
     input.1
 }
 
+#[cfg(not(feature = "no-threads"))]
 fn sender(shared: &Shared, tx: &Sender<Block>) {
     while !shared.done.load(Ordering::Relaxed) {
-        // Start at any point in the sequence using modular exponentiation.
-        let start = shared.start.fetch_add(BLOCK, Ordering::Relaxed);
-        let mut first = shared.first * 16807.mod_pow(start, 0x7fffffff);
-        let mut second = shared.second * 48271.mod_pow(start, 0x7fffffff);
-
-        // Estimate capacity at one quarter or one eight, plus a little extra for variance.
-        let mut ones = 0;
-        let mut fours = Vec::with_capacity((BLOCK * 30) / 100);
-        let mut eights = Vec::with_capacity((BLOCK * 15) / 100);
-
-        // Check part one pairs immediately while queueing part two pairs.
-        for _ in 0..BLOCK {
-            first = (first * 16807) % 0x7fffffff;
-            second = (second * 48271) % 0x7fffffff;
-
-            let left = first as u16;
-            let right = second as u16;
-
-            if left == right {
-                ones += 1;
-            }
-            if left % 4 == 0 {
-                fours.push(left);
-            }
-            if right % 8 == 0 {
-                eights.push(right);
-            }
-        }
+        let _unused = tx.send(generate(shared));
+    }
+}
+
+/// Generates the next available block.
+fn generate(shared: &Shared) -> Block {
+    // Start at any point in the sequence using modular exponentiation.
+    let start = shared.start.fetch_add(BLOCK, Ordering::Relaxed);
+    let mut first = shared.first * 16807.mod_pow(start, 0x7fffffff);
+    let mut second = shared.second * 48271.mod_pow(start, 0x7fffffff);
+
+    // Estimate capacity at one quarter or one eight, plus a little extra for variance.
+    let mut ones = 0;
+    let mut fours = Vec::with_capacity((BLOCK * 30) / 100);
+    let mut eights = Vec::with_capacity((BLOCK * 15) / 100);
+
+    // Check part one pairs immediately while queueing part two pairs.
+    for _ in 0..BLOCK {
+        first = (first * 16807) % 0x7fffffff;
+        second = (second * 48271) % 0x7fffffff;
 
-        let _unused = tx.send(Block { start, ones, fours, eights });
+        let left = first as u16;
+        let right = second as u16;
+
+        if left == right {
+            ones += 1;
+        }
+        if left % 4 == 0 {
+            fours.push(left);
+        }
+        if right % 8 == 0 {
+            eights.push(right);
+        }
     }
+
+    Block { start, ones, fours, eights }
 }
 
-fn receiver(shared: &Shared, rx: &Receiver<Block>) -> (u32, u32) {
+/// Judges blocks in order, where `next` returns blocks in any order.
+fn receiver<F>(shared: &Shared, mut next: F) -> (u32, u32)
+where
+    F: FnMut() -> Block,
+{
     let mut remaining = PART_TWO;
     let mut part_two = 0;
 
@@ -113,7 +138,7 @@ fn receiver(shared: &Shared, rx: &Receiver<Block>) -> (u32, u32) {
         // processing at the same time. The `start` field of the block defines the order they
         // must be added to the vec.
         while fours_block >= blocks.len() || eights_block >= blocks.len() {
-            let block = rx.recv().unwrap();
+            let block = next();
             out_of_order.insert(block.start, block);
 
             while let Some(next) = out_of_order.remove(&required) {
@@ -151,7 +176,7 @@ fn receiver(shared: &Shared, rx: &Receiver<Block>) -> (u32, u32) {
 
     // Just in case, make sure we have enough blocks for part one.
     while required < PART_ONE {
-        let block = rx.recv().unwrap();
+        let block = next();
         out_of_order.insert(block.start, block);
 
         while let Some(next) = out_of_order.remove(&required) {
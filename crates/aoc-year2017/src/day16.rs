@@ -9,7 +9,7 @@
 //!
 //! This allows us to compute part two with a similar approach to
 //! [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring).
-use crate::util::parse::*;
+use aoc_util::parse::*;
 use std::array::from_fn;
 
 #[derive(Copy, Clone)]
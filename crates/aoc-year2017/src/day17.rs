@@ -13,7 +13,7 @@
 //! This reduces the number of loops needed to approximately √50000000 = 7071.
 //!
 //! [`div_ceil`]: usize::div_ceil
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> usize {
     input.unsigned()
@@ -53,7 +53,7 @@
 //!     jgz f -16       }
 //!     jgz a -19       // Jump to deadlock section.
 //! ```
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 /// Generate a pseudorandom sequence of 127 numbers, based on a
 /// starting seed different for each input.
@@ -2,10 +2,10 @@
 //!
 //! Uses the utility [`Grid`] to parse the input, then the [`Point`] class to follow the path.
 //!
-//! [`Grid`]: crate::util::grid
-//! [`Point`]: crate::util::point
-use crate::util::grid::*;
-use crate::util::point::*;
+//! [`Grid`]: aoc_util::grid
+//! [`Point`]: aoc_util::point
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 type Input = (String, u32);
 
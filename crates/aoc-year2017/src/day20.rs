@@ -0,0 +1,228 @@
+//! # Particle Swarm
+//!
+//! ## Part One
+//!
+//! The particle that remains closest to the origin as time goes to infinity has the lowest
+//! acceleration, measured via its manhattan value. If more than one particle shares the same
+//! lowest acceleration then ties are broken by velocity then by position.
+//!
+//! ## Part Two
+//!
+//! Rather than simulating a fixed number of ticks and hoping that every collision has happened,
+//! we find the exact time that each pair of particles collides. After `t` ticks the position of
+//! a particle along a single axis is:
+//!
+//! `p + v * t + a * t * (t + 1) / 2`
+//!
+//! Doubling then subtracting the positions of two particles gives the quadratic
+//! `Δa * t² + (2 * Δv + Δa) * t + 2 * Δp` with integer coefficients. The particles collide at
+//! the smallest positive integer root that is shared by all three axes.
+//!
+//! Collisions are then processed in order of time. A collision only happens if both particles
+//! still exist at that time, however any number of particles can collide at the same tick.
+//! [`simulate`] is the original brute force approach, kept to cross-check the results.
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Vector {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Vector {
+    fn new(cs: [i32; 3]) -> Self {
+        Vector { x: cs[0], y: cs[1], z: cs[2] }
+    }
+
+    fn manhattan(&self) -> i32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    fn tick(&mut self, other: &Vector) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Particle {
+    id: usize,
+    position: Vector,
+    velocity: Vector,
+    acceleration: Vector,
+}
+
+impl Particle {
+    fn tick(&mut self) {
+        self.velocity.tick(&self.acceleration);
+        self.position.tick(&self.velocity);
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Particle> {
+    input
+        .iter_signed()
+        .chunk::<3>()
+        .chunk::<3>()
+        .enumerate()
+        .map(|(id, cs)| Particle {
+            id,
+            position: Vector::new(cs[0]),
+            velocity: Vector::new(cs[1]),
+            acceleration: Vector::new(cs[2]),
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Particle]) -> usize {
+    let mut candidates = Vec::new();
+    let mut min = i32::MAX;
+
+    // Find particles with the lowest acceleration.
+    for particle in input {
+        let next = particle.acceleration.manhattan();
+
+        if next < min {
+            candidates.clear();
+            min = next;
+        }
+        if next == min {
+            candidates.push(*particle);
+        }
+    }
+
+    // Ensure all acceleration, velocity and position vectors are "aligned", that is the
+    // sign of each component is the same, for example a particle with a negative x acceleration
+    // should also have a negative x velocity and negative x position.
+    for _ in 0..1000 {
+        candidates.iter_mut().for_each(Particle::tick);
+    }
+
+    // Tie break by velocity then by position.
+    candidates.sort_unstable_by(|a, b| {
+        let first = a.velocity.manhattan().cmp(&b.velocity.manhattan());
+        let second = a.position.manhattan().cmp(&b.position.manhattan());
+        first.then(second)
+    });
+
+    candidates[0].id
+}
+
+pub fn part2(input: &[Particle]) -> usize {
+    collide(input).iter().filter(|time| time.is_none()).count()
+}
+
+/// Tick when each particle is destroyed, or `None` if the particle survives forever.
+pub fn collide(particles: &[Particle]) -> Vec<Option<u64>> {
+    let mut events = Vec::new();
+
+    for (i, first) in particles.iter().enumerate() {
+        for (j, second) in particles.iter().enumerate().skip(i + 1) {
+            if let Some(time) = collision(first, second) {
+                events.push((time, i, j));
+            }
+        }
+    }
+
+    events.sort_unstable();
+
+    let mut destroyed = vec![None; particles.len()];
+
+    for (time, i, j) in events {
+        // A particle destroyed at the same tick can still take part in other collisions.
+        let exists = |k: usize| destroyed[k].is_none_or(|t| t == time);
+
+        if exists(i) && exists(j) {
+            destroyed[i] = Some(time);
+            destroyed[j] = Some(time);
+        }
+    }
+
+    destroyed
+}
+
+/// Brute force simulation for a fixed number of ticks, returning the tick when each particle
+/// is destroyed.
+pub fn simulate(particles: &[Particle], ticks: u64) -> Vec<Option<u64>> {
+    let mut particles = particles.to_vec();
+    let mut collisions = FastMap::with_capacity(particles.len());
+    let mut destroyed = vec![None; particles.len()];
+
+    for time in 1..=ticks {
+        for (i, particle) in particles.iter_mut().enumerate() {
+            // Only consider particles that haven't collided in a previous tick.
+            // Multiple particles can collide in the same tick.
+            if destroyed[i].is_none_or(|t| t == time) {
+                particle.tick();
+
+                if let Some(j) = collisions.insert(particle.position, i) {
+                    destroyed[i] = Some(time);
+                    destroyed[j] = Some(time);
+                }
+            }
+        }
+
+        collisions.clear();
+    }
+
+    destroyed
+}
+
+/// Earliest positive tick when two particles are at the same position.
+fn collision(first: &Particle, second: &Particle) -> Option<u64> {
+    let delta = |a: Vector, b: Vector| [a.x - b.x, a.y - b.y, a.z - b.z].map(i64::from);
+    let dp = delta(first.position, second.position);
+    let dv = delta(first.velocity, second.velocity);
+    let da = delta(first.acceleration, second.acceleration);
+
+    // `None` means that every axis so far matches at every tick.
+    let mut candidates: Option<Vec<u64>> = None;
+
+    for axis in 0..3 {
+        if let Some(roots) = roots(da[axis], 2 * dv[axis] + da[axis], 2 * dp[axis]) {
+            let roots = match candidates {
+                Some(previous) => previous.into_iter().filter(|t| roots.contains(t)).collect(),
+                None => roots,
+            };
+            if roots.is_empty() {
+                return None;
+            }
+            candidates = Some(roots);
+        }
+    }
+
+    // Identical particles collide on the very first tick.
+    candidates.map_or(Some(1), |roots| roots.into_iter().min())
+}
+
+/// Positive integer solutions of `a * t² + b * t + c = 0`, or `None` if every `t` is a solution.
+fn roots(a: i64, b: i64, c: i64) -> Option<Vec<u64>> {
+    let mut roots = Vec::with_capacity(2);
+    let mut check = |numerator: i64, denominator: i64| {
+        if numerator % denominator == 0 && numerator / denominator > 0 {
+            roots.push((numerator / denominator) as u64);
+        }
+    };
+
+    if a == 0 {
+        if b == 0 {
+            return (c != 0).then_some(roots);
+        }
+        check(-c, b);
+    } else {
+        let discriminant = b * b - 4 * a * c;
+        if discriminant >= 0 {
+            let root = discriminant.isqrt();
+            if root * root == discriminant {
+                check(-b + root, 2 * a);
+                check(-b - root, 2 * a);
+            }
+        }
+    }
+
+    Some(roots)
+}
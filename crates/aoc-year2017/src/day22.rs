@@ -2,8 +2,8 @@
 //!
 //! Brute force solution using a fixed size grid, relying on the properties of the input to never
 //! exceed the bounds. Some bit manipulation tricks are used to speeds things up slightly.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 pub fn parse(input: &str) -> Grid<u8> {
     Grid::parse(input)
@@ -25,18 +25,14 @@ fn simulate(input: &Grid<u8>, bursts: usize, delta: usize) -> usize {
     // Right, Down, Left, Up
     let offsets = [1, full, 0_usize.wrapping_sub(1), 0_usize.wrapping_sub(full)];
 
-    // Copy input
-    let mut grid = vec![1; full * full];
-    let offset = half - (input.width / 2) as usize;
+    // Copy input, with infected nodes as 3 and clean nodes as 1.
+    let bytes = input.bytes.iter().map(|&b| if b == b'#' { 3 } else { 1 }).collect();
+    let nodes = Grid { width: input.width, height: input.height, bytes };
+    let offset = half as i32 - input.width / 2;
 
-    for x in 0..input.width {
-        for y in 0..input.height {
-            if input[Point::new(x, y)] == b'#' {
-                let index = full * (offset + y as usize) + (offset + x as usize);
-                grid[index] = 3; // Infected
-            }
-        }
-    }
+    let mut grid = Grid::new(full as i32, full as i32, 1);
+    grid.blit(&nodes, Point::new(offset, offset));
+    let mut grid = grid.bytes;
 
     let mut index = full * half + half; // Center
     let mut direction = 3; // Up
@@ -48,8 +48,8 @@
 //! 1,000 numbers in steps of 17. The raw code take `O(n²)` complexity for each number so emulating
 //! this directly would take at least 10⁵.10⁵.10³ = 10¹³ = 10,000,000,000,000 steps.
 //!
-//! [`Day 18`]: crate::year2017::day18
-use crate::util::parse::*;
+//! [`Day 18`]: crate::day18
+use aoc_util::parse::*;
 
 /// We only need the vrey first number from the input.
 pub fn parse(input: &str) -> u32 {
@@ -0,0 +1,191 @@
+//! # Electromagnetic Moat
+//!
+//! Both parts are calculated at the same time by recurively building all possible bridge
+//! combinations. Two optimizations are used to speed things up ten times
+//!
+//! First ports that only appear in two components are merged. For example `2/17` and `17/3`
+//! becomes a single component `2/3` with a weight of 39 and a length of 2. This shaves about 30%
+//! off the time needed.
+//!
+//! The second optimization is far more critical and reduces the time needed by 85%. The
+//! observation is that compenets with two ports the same, for example `7/7` are always optimal
+//! to pick first, as they increase strength and length without changing the port number.
+//!
+//! If we can place such a component then there's no need to consider further components which
+//! reduces the total number of combination to consider.
+//!
+//! The best way to extend a bridge only depends on the current port and the set of components
+//! already used, not the order they were added. Memoizing on this pair with a `u64` bitmask
+//! avoids repeating the search for bridges that use the same components in a different order.
+//! Each branch leaving port zero is independent so they are searched in parallel.
+use aoc_util::bitset::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Component {
+    left: usize,
+    right: usize,
+    weight: usize,
+    length: usize,
+}
+
+struct State {
+    possible: [u64; 64],
+    both: [usize; 64],
+    weight: [usize; 64],
+    length: [usize; 64],
+}
+
+/// Best extension of a bridge, as the highest strength and the longest `(length, strength)`.
+type Best = (usize, (usize, usize));
+
+pub struct Bridges {
+    strongest: usize,
+    longest: (usize, usize),
+}
+
+impl Bridges {
+    /// Strength of the strongest bridge.
+    pub fn strongest(&self) -> usize {
+        self.strongest
+    }
+
+    /// Strength of the longest bridge, choosing the strongest if there is more than one.
+    pub fn longest(&self) -> usize {
+        self.longest.1
+    }
+}
+
+pub fn parse(input: &str) -> Bridges {
+    let mut components: Vec<_> = input
+        .iter_unsigned()
+        .chunk::<2>()
+        .map(|[left, right]| Component { left, right, weight: left + right, length: 1 })
+        .collect();
+
+    // First optimization. If a port value appears in only 2 components (excluding zero)
+    // then fuse the components together.
+    let mut indices = Vec::new();
+
+    for n in 1..64 {
+        for (index, component) in components.iter().enumerate() {
+            if component.left == n || component.right == n {
+                indices.push(index);
+            }
+        }
+
+        if indices.len() == 2 {
+            let second = components.swap_remove(indices[1]);
+            let first = components.swap_remove(indices[0]);
+
+            let left = if first.left == n { first.right } else { first.left };
+            let right = if second.left == n { second.right } else { second.left };
+            let weight = first.weight + second.weight;
+            let length = first.length + second.length;
+
+            components.push(Component { left, right, weight, length });
+        }
+
+        indices.clear();
+    }
+
+    // Second optimization. Sort components with both ports the same before other components,
+    // so that the loop when choosing componentns in `build` function can terminate early.
+    components.sort_unstable_by(|a, b| {
+        (a.left ^ a.right).cmp(&(b.left ^ b.right)).then(a.left.cmp(&b.left))
+    });
+
+    let mut state = State { possible: [0; 64], both: [0; 64], weight: [0; 64], length: [0; 64] };
+
+    for (index, component) in components.iter().enumerate() {
+        let mask = 1 << index;
+        state.possible[component.left] |= mask;
+        state.possible[component.right] |= mask;
+
+        // Bitwise logic trick. `a ^ b ^ a = b` and `a ^ b ^ b = a` so given a single port and
+        // the XOR of both we can work out the other port of a component.
+        state.both[index] = component.left ^ component.right;
+        state.weight[index] = component.weight;
+        state.length[index] = component.length;
+    }
+
+    // Search each branch from port zero in parallel, each worker with its own cache.
+    let first: Vec<_> = state.possible[0].biterator().collect();
+    let strongest = AtomicUsize::new(0);
+    let longest = AtomicUsize::new(0);
+
+    spawn_parallel_iterator(&first, |iter| {
+        let mut cache = FastMap::with_capacity(10_000);
+
+        for &index in iter {
+            let (strength, (length, longest_strength)) = extend(&state, &mut cache, 0, 0, index);
+            strongest.fetch_max(strength, Ordering::Relaxed);
+            // Pack length and strength together so that a single atomic compares both.
+            longest.fetch_max((length << 32) | longest_strength, Ordering::Relaxed);
+        }
+    });
+
+    let longest = longest.into_inner();
+    Bridges { strongest: strongest.into_inner(), longest: (longest >> 32, longest & 0xffffffff) }
+}
+
+pub fn part1(input: &Bridges) -> usize {
+    input.strongest()
+}
+
+pub fn part2(input: &Bridges) -> usize {
+    input.longest()
+}
+
+/// Adds the component at `index` to a bridge ending at `current`, then returns the best bridge
+/// that can be built from there including the new component.
+fn extend(
+    state: &State,
+    cache: &mut FastMap<(usize, u64), Best>,
+    current: usize,
+    used: u64,
+    index: usize,
+) -> Best {
+    let next = current ^ state.both[index];
+    let used = used | (1 << index);
+    let (strength, (length, longest)) = build(state, cache, next, used);
+
+    let weight = state.weight[index];
+    (strength + weight, (length + state.length[index], longest + weight))
+}
+
+/// Best bridge that can be built starting from port `current` using only unused components.
+fn build(
+    state: &State,
+    cache: &mut FastMap<(usize, u64), Best>,
+    current: usize,
+    used: u64,
+) -> Best {
+    if let Some(&best) = cache.get(&(current, used)) {
+        return best;
+    }
+
+    let mut best = (0, (0, 0));
+
+    // Bitset of all unused components that have a matching port.
+    let remaining = state.possible[current] & !used;
+
+    // Extract the index of each component from the bitset.
+    for index in remaining.biterator() {
+        let (strength, longest) = extend(state, cache, current, used, index);
+        best = (best.0.max(strength), best.1.max(longest));
+
+        // Critical optimization. If this is a component with two ports of the same values,
+        // for example 5/5 or 7/7 then it's always optimal to add it to the bridge.
+        // We don't need to consider further options.
+        if state.both[index] == 0 {
+            break;
+        }
+    }
+
+    cache.insert((current, used), best);
+    best
+}
@@ -20,7 +20,7 @@
 //!
 //! In this example the tape then advances four bits to the left, loading the four values of `t`
 //! then the next batch lookup is performed.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 use std::array::from_fn;
 
 pub struct Input {
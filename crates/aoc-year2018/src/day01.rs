@@ -0,0 +1,34 @@
+//! # Chronal Calibration
+//!
+//! The simplest approach to part two is to store previously seen numbers in a `HashSet` then
+//! stop once a duplicate is found. However this approach requires scanning the input of ~1,000
+//! numbers multiple times, around 150 times for my input.
+//!
+//! A much faster approach relies on the fact that each frequency increases by the same
+//! amount (the sum of all deltas) each time the list of numbers is processed. For example:
+//!
+//! ```none
+//!    Deltas: +1, -2, +3, +1 =>
+//!    0    1   -1    2
+//!    3    4    2    5
+//! ```
+//!
+//! Two frequencies that differ by a multiple of the sum will eventually repeat, so grouping
+//! frequencies by their remainder modulo the sum finds every candidate pair with a single pass.
+//! The shared [`first_repeated_sum`] utility function implements this approach.
+//!
+//! [`first_repeated_sum`]: aoc_util::math::first_repeated_sum
+use aoc_util::math::*;
+use aoc_util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    input.iter().sum()
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    first_repeated_sum(input).unwrap()
+}
@@ -1,5 +1,6 @@
 //! # Inventory Management System
-use crate::util::hash::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
 
 pub fn parse(input: &str) -> Vec<&[u8]> {
     input.lines().map(str::as_bytes).collect()
@@ -12,30 +13,12 @@ pub fn part1(input: &[&[u8]]) -> u32 {
     for &id in input {
         // Ids are lowercase ASCII only with cardinality of 26.
         let mut freq = [0; 26];
-        let mut twos = 0;
-        let mut threes = 0;
+        id.iter().tally_into(&mut freq, |&b| (b - b'a') as usize);
 
-        for &b in id {
-            let index = (b - b'a') as usize;
-            let current = freq[index];
-
-            match current {
-                0 => (),
-                1 => twos += 1,
-                2 => {
-                    twos -= 1;
-                    threes += 1;
-                }
-                _ => threes -= 1,
-            }
-
-            freq[index] += 1;
-        }
-
-        if twos > 0 {
+        if freq.contains(&2) {
             total_twos += 1;
         }
-        if threes > 0 {
+        if freq.contains(&3) {
             total_threes += 1;
         }
     }
@@ -2,15 +2,14 @@
 //!
 //! Brute force approach using bitmasks for efficiency. Assumes that no claim is wider than 65
 //! inches.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Input = (u32, usize);
 
 pub fn parse(input: &str) -> Input {
     let claims: Vec<_> = input
-        .iter_unsigned::<usize>()
-        .chunk::<5>()
+        .lines()
+        .map(|line| line.extract_unsigned::<usize, 5>())
         .map(|[_, x1, y1, width, height]| {
             let start = 16 * y1 + (x1 / 64);
             let end = start + 16 * height;
@@ -1,6 +1,6 @@
 //! # Repose Record
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 type Input = FastMap<usize, [u32; 60]>;
 
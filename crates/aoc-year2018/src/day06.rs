@@ -9,9 +9,9 @@
 //! top to bottom (minimum y coordinate to maximum y coordinate) computing the area a slice at a
 //! time. There are 50 coordinates so the complexity of this approach is much lower at
 //! approximately `O(300 * 50) = O(15000)`.
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 pub struct Input {
     min_y: i32,
@@ -44,7 +44,7 @@ pub fn parse(input: &str) -> Input {
 /// against the head of a stack. This quickly eliminates coordinates that are further away at all
 /// points. Interestingly this approach is very similar to the previous [`Day 5`].
 ///
-/// [`Day 5`]: crate::year2018::day05
+/// [`Day 5`]: crate::day05
 pub fn part1(input: &Input) -> i32 {
     let mut points = input.points.clone();
     let mut area = vec![0; points.len()];
@@ -2,7 +2,7 @@
 //!
 //! Part one is a [topological sort](https://en.wikipedia.org/wiki/Topological_sorting)
 //! of the steps based on the dependencies between them.
-use crate::util::hash::*;
+use aoc_util::hash::*;
 use std::collections::BTreeMap;
 
 type Input = FastMap<u8, Step>;
@@ -2,7 +2,7 @@
 //!
 //! Recursive solution computing both parts at the same time, sharing a single mutable iterator.
 //! A shared stack is used to store the scores for child nodes temporarily.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Input = (usize, usize);
 
@@ -72,8 +72,8 @@
 //! player's score advances `23 - 7 = 16` marbles. This means we only need to generate about
 //!  `16/37` or `44%` of the total blocks to solve the game deterministcally. This saves both
 //! processing time and memory storage proportionally.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Input = [usize; 2];
 
@@ -1,14 +1,14 @@
 //! # The Stars Align
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 type Input = (String, i32);
 
 pub fn parse(input: &str) -> Input {
     let (mut points, velocity): (Vec<_>, Vec<_>) = input
-        .iter_signed::<i32>()
-        .chunk::<4>()
+        .lines()
+        .map(|line| line.extract_signed::<i32, 4>())
         .map(|[x, y, dx, dy]| (Point::new(x, y), Point::new(dx, dy)))
         .unzip();
 
@@ -34,19 +34,8 @@ pub fn parse(input: &str) -> Input {
         time += 1;
     }
 
-    // Move top left corner of points to origin.
-    adjust(&mut points);
-
     // Convert points to human readable string.
-    let mut grid = ['.'; 620];
-    points.iter().for_each(|p| grid[(62 * p.y + p.x) as usize] = '#');
-
-    let mut message = grid
-        .chunks_exact(62)
-        .map(|chunk| chunk.iter().collect())
-        .collect::<Vec<String>>()
-        .join("\n");
-    message.insert(0, '\n');
+    let message = format!("\n{}", Grid::from_points(&points).render());
 
     (message, time)
 }
@@ -59,31 +48,9 @@ pub fn part2(input: &Input) -> i32 {
     input.1
 }
 
-fn bounding_box(points: &[Point]) -> (i32, i32, i32, i32) {
-    let mut min_x = i32::MAX;
-    let mut max_x = i32::MIN;
-    let mut min_y = i32::MAX;
-    let mut max_y = i32::MIN;
-
-    for p in points {
-        min_x = min_x.min(p.x);
-        max_x = max_x.max(p.x);
-        min_y = min_y.min(p.y);
-        max_y = max_y.max(p.y);
-    }
-
-    (min_x, max_x, min_y, max_y)
-}
-
 fn size(points: &[Point]) -> i32 {
-    let (min_x, max_x, min_y, max_y) = bounding_box(points);
-    (max_x - min_x + 1) * (max_y - min_y + 1)
-}
-
-fn adjust(points: &mut [Point]) {
-    let (min_x, _, min_y, _) = bounding_box(points);
-    let top_left = Point::new(min_x, min_y);
-    points.iter_mut().for_each(|p| *p -= top_left);
+    let (min, max) = bounding_box(points).unwrap();
+    (max.x - min.x + 1) * (max.y - min.y + 1)
 }
 
 fn tick(points: &mut [Point], velocity: &[Point], time: i32) {
@@ -0,0 +1,206 @@
+//! # Mine Cart Madness
+//!
+//! Simulates the mine carts for both parts. When checking the grid we only care about `\`, `/`
+//! and `+` characters, all other characters can be ignored. The movement order is important to
+//! resolve collisions or near misses correctly, so carts are kept in a `vec` sorted by `y` and
+//! then by `x`. Carts only move one square each tick so the order barely changes, making an
+//! insertion sort very cheap.
+//!
+//! A grid of booleans the same size as the tracks is an occupancy index, so detecting a
+//! collision is a single lookup without any allocation. Crashed carts are marked inactive during
+//! the tick, then removed once the tick completes.
+//!
+//! [`Mine::simulate_with`] calls an observer after every tick, for example to draw each step
+//! using [`Mine::render`].
+use aoc_util::grid::*;
+use aoc_util::point::*;
+
+#[derive(Clone)]
+pub struct Mine {
+    grid: Grid<u8>,
+    carts: Vec<Cart>,
+    occupied: Grid<bool>,
+}
+
+#[derive(Clone, Copy)]
+struct Cart {
+    position: Point,
+    direction: Point,
+    turns: u32,
+    active: bool,
+}
+
+impl Cart {
+    fn new(position: Point, direction: Point) -> Cart {
+        Cart { position, direction, turns: 0, active: true }
+    }
+
+    fn tick(&mut self, grid: &Grid<u8>) {
+        self.position += self.direction;
+
+        match grid[self.position] {
+            b'\\' => {
+                self.direction = match self.direction {
+                    UP => LEFT,
+                    DOWN => RIGHT,
+                    LEFT => UP,
+                    RIGHT => DOWN,
+                    _ => unreachable!(),
+                }
+            }
+            b'/' => {
+                self.direction = match self.direction {
+                    UP => RIGHT,
+                    DOWN => LEFT,
+                    LEFT => DOWN,
+                    RIGHT => UP,
+                    _ => unreachable!(),
+                }
+            }
+            b'+' => {
+                self.direction = match self.turns {
+                    0 => self.direction.counter_clockwise(),
+                    1 => self.direction,
+                    2 => self.direction.clockwise(),
+                    _ => unreachable!(),
+                };
+                self.turns = (self.turns + 1) % 3;
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Mine {
+    /// Runs until there's at most one cart left or if `until_first_crash` is true then stops at
+    /// the first collision, returning its location.
+    pub fn simulate(&mut self, until_first_crash: bool) -> Option<Point> {
+        self.simulate_with(until_first_crash, |_| ())
+    }
+
+    /// Same as [`simulate`](Mine::simulate) but calls `observe` after every complete tick.
+    pub fn simulate_with<F>(&mut self, until_first_crash: bool, mut observe: F) -> Option<Point>
+    where
+        F: FnMut(&Mine),
+    {
+        while self.carts.len() > 1 {
+            let crash = self.tick(until_first_crash);
+            if crash.is_some() {
+                return crash;
+            }
+            observe(self);
+        }
+
+        None
+    }
+
+    /// Position of the only remaining cart.
+    pub fn last_cart(&self) -> Option<Point> {
+        (self.carts.len() == 1).then(|| self.carts[0].position)
+    }
+
+    /// Draws the tracks with each cart pointing in its direction of travel.
+    pub fn render(&self) -> String {
+        let mut grid = self.grid.clone();
+
+        for cart in &self.carts {
+            grid[cart.position] = match cart.direction {
+                UP => b'^',
+                DOWN => b'v',
+                LEFT => b'<',
+                RIGHT => b'>',
+                _ => unreachable!(),
+            };
+        }
+
+        grid.render()
+    }
+
+    fn tick(&mut self, until_first_crash: bool) -> Option<Point> {
+        self.sort();
+
+        for i in 0..self.carts.len() {
+            // Crashed carts may not have been removed yet.
+            if self.carts[i].active {
+                // Follow tracks to next position.
+                self.occupied[self.carts[i].position] = false;
+                self.carts[i].tick(&self.grid);
+                let next = self.carts[i].position;
+
+                if !self.occupied[next] {
+                    self.occupied[next] = true;
+                    continue;
+                }
+                if until_first_crash {
+                    return Some(next);
+                }
+
+                // Mark both carts as crashed.
+                for cart in self.carts.iter_mut().filter(|c| c.position == next) {
+                    cart.active = false;
+                }
+                self.occupied[next] = false;
+            }
+        }
+
+        // Removed crashed carts to speed up future ticks.
+        self.carts.retain(|c| c.active);
+        None
+    }
+
+    /// Turn order is important. Insertion sort is linear when the carts are almost sorted.
+    fn sort(&mut self) {
+        let width = self.grid.width;
+        let key = |cart: &Cart| width * cart.position.y + cart.position.x;
+
+        for i in 1..self.carts.len() {
+            let mut j = i;
+            while j > 0 && key(&self.carts[j - 1]) > key(&self.carts[j]) {
+                self.carts.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Mine {
+    let mut grid = Grid::parse(input);
+    let width = grid.width;
+    let mut carts = Vec::new();
+
+    for (i, b) in grid.bytes.iter_mut().enumerate() {
+        let (direction, track) = match b {
+            b'^' => (UP, b'|'),
+            b'v' => (DOWN, b'|'),
+            b'<' => (LEFT, b'-'),
+            b'>' => (RIGHT, b'-'),
+            _ => continue,
+        };
+
+        // Carts start on straight track, so remove them from the grid.
+        *b = track;
+        let x = i as i32 % width;
+        let y = i as i32 / width;
+        carts.push(Cart::new(Point::new(x, y), direction));
+    }
+
+    let mut occupied = grid.same_size_with(false);
+    for cart in &carts {
+        occupied[cart.position] = true;
+    }
+
+    Mine { grid, carts, occupied }
+}
+
+pub fn part1(input: &Mine) -> String {
+    let mut mine = input.clone();
+    let crash = mine.simulate(true).unwrap();
+    format!("{},{}", crash.x, crash.y)
+}
+
+pub fn part2(input: &Mine) -> String {
+    let mut mine = input.clone();
+    mine.simulate(false);
+    let last = mine.last_cart().unwrap();
+    format!("{},{}", last.x, last.y)
+}
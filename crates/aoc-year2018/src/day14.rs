@@ -10,31 +10,54 @@
 //! Tricks used to speed things up:
 //! * Separate writer and reader threads to generate recipes and check them in parallel.
 //! * Vector processing of recipes using techniques similar to SIMD.
-use crate::util::parse::*;
+//!
+//! Batches are sent over a [`bounded`] channel, so the writer pauses if it gets too far ahead of
+//! the reader.
+//!
+//! [`bounded`]: aoc_util::thread::bounded
+//!
+//! When built with the `no-threads` feature the writer fills the recipe buffer first, then the
+//! reader checks all the batches afterwards.
+use aoc_util::parse::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{Receiver, Sender, channel};
+#[cfg(not(feature = "no-threads"))]
 use std::thread;
 
 type Input = (String, usize);
 
+/// Most iterations of the hot loop in each batch sent to the reader thread.
+const BATCH_SIZE: usize = 10_000;
+/// Most batches waiting for the reader thread before the writer thread pauses.
+const CAPACITY: usize = 64;
+
 /// Pre-calculate the first 23 recipes.
 const PREFIX: [u8; 23] = [3, 7, 1, 0, 1, 0, 1, 2, 4, 5, 1, 5, 8, 9, 1, 6, 7, 7, 9, 2, 5, 1, 0];
 
 pub fn parse(input: &str) -> Input {
-    // Send batches of recipes from the writer to the reader for checking.
-    let (tx, rx) = channel();
-    // Thread safe flag to let writer know when to stop.
-    let done = AtomicBool::new(false);
     // Store recipes in fixed size vec prefilled with ones. Part two result is around 20 million
     // so size should be sufficient for most inputs.
     let mut recipes = vec![1; 25_000_000];
+    // Send batches of recipes from the writer to the reader for checking.
+    let (tx, rx) = bounded(CAPACITY);
+    // Thread safe flag to let writer know when to stop.
+    let done = AtomicBool::new(false);
 
-    thread::scope(|scope| {
-        // Start writer thread to produce new recipes.
-        scope.spawn(|| writer(tx, &done, recipes.as_mut_slice()));
-        // Reader thread checks recipes for the answers, returning when both parts are found.
-        scope.spawn(|| reader(rx, &done, input)).join().unwrap()
-    })
+    #[cfg(not(feature = "no-threads"))]
+    {
+        thread::scope(|scope| {
+            // Start writer thread to produce new recipes.
+            scope.spawn(|| writer(tx, &done, recipes.as_mut_slice()));
+            // Reader thread checks recipes for the answers, returning when both parts are found.
+            scope.spawn(|| reader(rx, &done, input)).join().unwrap()
+        })
+    }
+
+    #[cfg(feature = "no-threads")]
+    {
+        writer(tx, &done, recipes.as_mut_slice());
+        reader(rx, &done, input)
+    }
 }
 
 pub fn part1(input: &Input) -> &str {
@@ -47,7 +70,7 @@ pub fn part2(input: &Input) -> usize {
 
 /// Receives batches of recipes from the writer thread, then scans them byte by byte searching
 /// for the part two pattern. For simplicity the pattern is always assumed to by six digits.
-fn reader(rx: Receiver<&[u8]>, done: &AtomicBool, input: &str) -> (String, usize) {
+fn reader(rx: BoundedReceiver<&[u8]>, done: &AtomicBool, input: &str) -> (String, usize) {
     let part_one_target = input.unsigned::<usize>() + 10;
     let part_two_target = u32::from_str_radix(input.trim(), 16).unwrap();
 
@@ -125,7 +148,7 @@ fn reader(rx: Receiver<&[u8]>, done: &AtomicBool, input: &str) -> (String, usize
 /// Interestingly on an Apple M2 Max this "poor man's SIMD" has the same performance as using
 /// the portable SIMD API. This is probably due to the fact that the serial loops that write new
 /// recipes take the majority of the time.
-fn writer<'a>(tx: Sender<&'a [u8]>, done: &AtomicBool, mut recipes: &'a mut [u8]) {
+fn writer<'a>(tx: BoundedSender<&'a [u8]>, done: &AtomicBool, mut recipes: &'a mut [u8]) {
     // The first 23 recipes have already been generated
     // so the elves start at position 0 and 8 respectively.
     let mut elf1 = 0;
@@ -142,7 +165,11 @@ fn writer<'a>(tx: Sender<&'a [u8]>, done: &AtomicBool, mut recipes: &'a mut [u8]
     let mut write = 0;
     let mut snack: Vec<u8> = vec![0; 5_000_000];
 
-    while !done.load(Ordering::Relaxed) {
+    // Without threads the reader can't signal the writer, so stop before running out of space
+    // in either `recipes` or `snack`, leaving room for one more batch.
+    let sequential = cfg!(feature = "no-threads");
+
+    while !done.load(Ordering::Relaxed) && (!sequential || recipes.len() >= 2_500_000) {
         // Cold loop to handle start and end transitions.
         while elf1 < 23 || elf2 < 23 || write - index1.max(index2) <= 16 {
             // After the first 23 recipes both elves converge on the same set of ingredients.
@@ -192,10 +219,10 @@ fn writer<'a>(tx: Sender<&'a [u8]>, done: &AtomicBool, mut recipes: &'a mut [u8]
             }
         }
 
-        // Hot loop to handle the majority of recipes in the middle. Process at most 10,000 recipes
-        // at a time in order to produce batches between 160,000 and 320,000 bytes in size.
-        // This size is roughly tuned in order to maximize reader thread throughput.
-        let batch_size = 10_000.min((write - index1.max(index2) - 1) / 16);
+        // Hot loop to handle the majority of recipes in the middle. Process at most `BATCH_SIZE`
+        // iterations at a time in order to produce batches between 160,000 and 320,000 bytes in
+        // size. This size is roughly tuned in order to maximize reader thread throughput.
+        let batch_size = BATCH_SIZE.min((write - index1.max(index2) - 1) / 16);
 
         for _ in 0..batch_size {
             // Snacks can be processed sequentially.
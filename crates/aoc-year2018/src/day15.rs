@@ -76,9 +76,9 @@
 //! ```
 //!
 //! Choosing the first intersection in reading order the Elf correctly moves left.
-use crate::util::grid::*;
-use crate::util::point::*;
-use crate::util::thread::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::mpsc::{Sender, channel};
 
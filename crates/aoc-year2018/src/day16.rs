@@ -23,8 +23,8 @@
 //! possibilities. This causes a domino effect, continuing until all unknowns are resolved.
 //!
 //! [`count_ones`]: u32::count_ones
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub struct Input {
     samples: Vec<(usize, u32)>,
@@ -9,10 +9,10 @@
 //!
 //! This problem is similar to [Year 2022 Day 14].
 //!
-//! [Year 2022 Day 14]: crate::year2022::day14
-use crate::util::iter::*;
-use crate::util::parse::*;
+//! [Year 2022 Day 14]: ../../aoc_year2022/day14/index.html
 use Kind::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Kind {
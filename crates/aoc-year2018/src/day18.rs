@@ -0,0 +1,206 @@
+//! # Settlers of The North Pole
+//!
+//! This problem is a cellular automaton similar to the well known
+//! [Game of Life](https://en.wikipedia.org/wiki/Conway%27s_Game_of_Life). To solve part two
+//! we look for a [cycle](https://en.wikipedia.org/wiki/Cycle_detection) then
+//! extrapolate forward a billion generations.
+//!
+//! To efficiently compute the next generation the state is
+//! [bit-sliced](https://en.wikipedia.org/wiki/Bit_slicing) into two separate planes, one for trees
+//! and one for lumberyards. Each row of 50 acres fits into a single `u64` with the acre at
+//! column `x` stored in bit `x`. For example, writing the lowest bit first:
+//!
+//! ```none
+//!                Trees       Lumberyards
+//!     .#.#...| => 00000001    01010000
+//! ```
+//!
+//! The 8 neighbors of every acre in a row are the rows above, below and itself shifted left
+//! and right by one bit. Adding these 8 masks with bitwise
+//! [half adders](https://en.wikipedia.org/wiki/Adder_(electronics)#Half_adder) gives a bit-sliced
+//! count of neighbors for all 50 acres at once. We only need to know if there is at least one
+//! or at least three neighbors, so the count saturates at four.
+//!
+//! Bitwise logic then computes the next generation a whole row at a time. Part two uses the
+//! shared [`Cycle`] utility to find the repeating states then extrapolate.
+//!
+//! A SIMD variant computes 8 rows at a time. The `compare` feature builds both variants side by
+//! side so they can be checked against each other.
+use aoc_util::cycle::*;
+
+/// Padding on the top and bottom, plus extra rows so the SIMD version can process 8 at a time.
+const SIZE: usize = 58;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Area {
+    trees: [u64; SIZE],
+    lumberyards: [u64; SIZE],
+    height: usize,
+    mask: u64,
+}
+
+/// Rows are stored starting at index 1 so that the rows above and below always exist.
+pub fn parse(input: &str) -> Area {
+    let mut trees = [0; SIZE];
+    let mut lumberyards = [0; SIZE];
+    let mut height = 0;
+    let mut width = 0;
+
+    for (y, line) in input.lines().map(str::as_bytes).enumerate() {
+        for (x, byte) in line.iter().enumerate() {
+            match byte {
+                b'|' => trees[y + 1] |= 1 << x,
+                b'#' => lumberyards[y + 1] |= 1 << x,
+                _ => (),
+            }
+        }
+        height = y + 1;
+        width = line.len();
+    }
+
+    Area { trees, lumberyards, height, mask: (1 << width) - 1 }
+}
+
+/// Compute 10 generations.
+pub fn part1(input: &Area) -> u32 {
+    let area = (0..10).fold(input.clone(), |area, _| step(&area));
+    resource_value(&area)
+}
+
+/// Compute generations until a cycle is detected.
+pub fn part2(input: &Area) -> u32 {
+    let cycle = Cycle::find(input.clone(), step);
+    resource_value(cycle.nth(1_000_000_000))
+}
+
+#[cfg(not(feature = "simd"))]
+fn step(area: &Area) -> Area {
+    scalar::step(area)
+}
+
+#[cfg(feature = "simd")]
+fn step(area: &Area) -> Area {
+    simd::step(area)
+}
+
+/// Each tree or lumberyard is represented by a single bit.
+fn resource_value(area: &Area) -> u32 {
+    let trees: u32 = area.trees.iter().map(|n| n.count_ones()).sum();
+    let lumberyards: u32 = area.lumberyards.iter().map(|n| n.count_ones()).sum();
+    trees * lumberyards
+}
+
+#[cfg(any(not(feature = "simd"), feature = "compare"))]
+pub mod scalar {
+    use super::*;
+
+    pub fn step(area: &Area) -> Area {
+        let Area { trees, lumberyards, height, mask } = *area;
+        let mut next = Area { trees: [0; SIZE], lumberyards: [0; SIZE], height, mask };
+
+        for y in 1..=height {
+            let tree = trees[y];
+            let lumberyard = lumberyards[y];
+            let open = !(tree | lumberyard) & mask;
+
+            let (tree_one, tree_three) = count(trees[y - 1], tree, trees[y + 1]);
+            let (lumberyard_one, lumberyard_three) =
+                count(lumberyards[y - 1], lumberyard, lumberyards[y + 1]);
+
+            // Open acres with three or more adjacent trees become trees.
+            // Trees with three or more adjacent lumberyards become lumberyards.
+            next.trees[y] = (open & tree_three) | (tree & !lumberyard_three);
+            // Lumberyards remain only if adjacent to at least one lumberyard and one tree.
+            next.lumberyards[y] =
+                (tree & lumberyard_three) | (lumberyard & lumberyard_one & tree_one);
+        }
+
+        next
+    }
+
+    /// Returns masks of acres with at least one and at least three neighbors.
+    #[inline]
+    fn count(above: u64, row: u64, below: u64) -> (u64, u64) {
+        let neighbors =
+            [above << 1, above, above >> 1, row << 1, row >> 1, below << 1, below, below >> 1];
+        let (mut ones, mut twos, mut fours) = (0, 0, 0);
+
+        for n in neighbors {
+            let carry = ones & n;
+            ones ^= n;
+            fours |= twos & carry;
+            twos ^= carry;
+        }
+
+        (ones | twos | fours, (ones & twos) | fours)
+    }
+}
+
+#[cfg(feature = "simd")]
+pub mod simd {
+    use super::*;
+    use std::simd::Simd;
+
+    type Vector = Simd<u64, 8>;
+
+    /// Same as the scalar version but computes 8 rows at once. Rows past the bottom edge may
+    /// grow trees so are cleared afterwards.
+    pub fn step(area: &Area) -> Area {
+        let Area { trees, lumberyards, height, mask } = *area;
+        let mut next = Area { trees: [0; SIZE], lumberyards: [0; SIZE], height, mask };
+        let mask = Simd::splat(mask);
+
+        for y in (1..=height).step_by(8) {
+            let load = |rows: &[u64]| {
+                let above = Vector::from_slice(&rows[y - 1..]);
+                let row = Vector::from_slice(&rows[y..]);
+                let below = Vector::from_slice(&rows[y + 1..]);
+                (above, row, below)
+            };
+
+            let (tree_above, tree, tree_below) = load(&trees);
+            let (lumberyard_above, lumberyard, lumberyard_below) = load(&lumberyards);
+            let open = !(tree | lumberyard) & mask;
+
+            let (tree_one, tree_three) = count(tree_above, tree, tree_below);
+            let (lumberyard_one, lumberyard_three) =
+                count(lumberyard_above, lumberyard, lumberyard_below);
+
+            let next_tree = (open & tree_three) | (tree & !lumberyard_three);
+            let next_lumberyard =
+                (tree & lumberyard_three) | (lumberyard & lumberyard_one & tree_one);
+
+            next_tree.copy_to_slice(&mut next.trees[y..]);
+            next_lumberyard.copy_to_slice(&mut next.lumberyards[y..]);
+        }
+
+        next.trees[height + 1..].fill(0);
+        next.lumberyards[height + 1..].fill(0);
+        next
+    }
+
+    #[inline]
+    fn count(above: Vector, row: Vector, below: Vector) -> (Vector, Vector) {
+        let one = Simd::splat(1);
+        let neighbors = [
+            above << one,
+            above,
+            above >> one,
+            row << one,
+            row >> one,
+            below << one,
+            below,
+            below >> one,
+        ];
+        let (mut ones, mut twos, mut fours) = (Simd::splat(0), Simd::splat(0), Simd::splat(0));
+
+        for n in neighbors {
+            let carry = ones & n;
+            ones ^= n;
+            fours |= twos & carry;
+            twos ^= carry;
+        }
+
+        (ones | twos | fours, (ones & twos) | fours)
+    }
+}
@@ -0,0 +1,121 @@
+//! # A Regular Map
+//!
+//! The regex is expanded in a single pass by a stack machine that tracks the *set* of rooms
+//! that the current path could have reached. This handles any input, including branches that
+//! don't return to the room where they started.
+//!
+//! * Directions move every room in the set one step, recording the door passed through.
+//! * `(` pushes the current set as the start of each option and an empty set of end rooms.
+//! * `|` adds the current set to the end rooms then restarts from the saved start.
+//! * `)` continues from the union of every option's end rooms.
+//!
+//! An empty option such as `(NEWS|)` is just another option that ends where it started.
+//!
+//! The doors form a graph with one node per room. A
+//! [BFS](https://en.wikipedia.org/wiki/Breadth-first_search) from the starting room then finds
+//! the fewest doors needed to reach every room.
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::point::*;
+use std::collections::VecDeque;
+
+/// Fewest doors needed to reach each room from the start. Cells that are not rooms are `u32::MAX`.
+type Input = Grid<u32>;
+
+pub fn parse(input: &str) -> Input {
+    let doors = explore(input);
+    distances(&doors)
+}
+
+pub fn part1(input: &Input) -> u32 {
+    input.bytes.iter().copied().filter(|&d| d != u32::MAX).max().unwrap()
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.count(|d| (1000..u32::MAX).contains(d))
+}
+
+/// Expands the regex, returning a bitmask of the doors leading out of every visited room.
+fn explore(input: &str) -> FastMap<Point, u8> {
+    let mut doors = FastMap::with_capacity(10_000);
+    let mut current = vec![ORIGIN];
+    let mut stack: Vec<(Vec<Point>, Vec<Point>)> = Vec::with_capacity(500);
+
+    doors.insert(ORIGIN, 0);
+
+    for b in input.bytes() {
+        match b {
+            b'(' => stack.push((current.clone(), Vec::new())),
+            b'|' => {
+                let (start, ends) = stack.last_mut().unwrap();
+                ends.append(&mut current);
+                current.clone_from(start);
+            }
+            b')' => {
+                let (_, mut ends) = stack.pop().unwrap();
+                ends.append(&mut current);
+                ends.sort_unstable_by_key(|p| (p.y, p.x));
+                ends.dedup();
+                current = ends;
+            }
+            b'N' | b'S' | b'W' | b'E' => {
+                let index = door(b);
+                let direction = ORTHOGONAL[index];
+
+                for room in &mut current {
+                    *doors.entry(*room).or_insert(0) |= 1 << index;
+                    *room += direction;
+                    *doors.entry(*room).or_insert(0) |= 1 << (index ^ 1);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    doors
+}
+
+/// Index into [`ORTHOGONAL`] for each direction, ordered so that opposite directions differ
+/// only in the lowest bit.
+fn door(b: u8) -> usize {
+    match b {
+        b'N' => 0,
+        b'S' => 1,
+        b'W' => 2,
+        b'E' => 3,
+        _ => unreachable!(),
+    }
+}
+
+fn distances(doors: &FastMap<Point, u8>) -> Grid<u32> {
+    let min_x = doors.keys().map(|p| p.x).min().unwrap();
+    let max_x = doors.keys().map(|p| p.x).max().unwrap();
+    let min_y = doors.keys().map(|p| p.y).min().unwrap();
+    let max_y = doors.keys().map(|p| p.y).max().unwrap();
+
+    let offset = Point::new(min_x, min_y);
+    let mut grid = Grid::new(max_x - min_x + 1, max_y - min_y + 1, 0);
+    let mut distance = grid.same_size_with(u32::MAX);
+    let mut todo = VecDeque::new();
+
+    for (&room, &mask) in doors {
+        grid[room - offset] = mask;
+    }
+
+    let start = ORIGIN - offset;
+    distance[start] = 0;
+    todo.push_back(start);
+
+    while let Some(room) = todo.pop_front() {
+        for (index, direction) in ORTHOGONAL.into_iter().enumerate() {
+            let next = room + direction;
+
+            if grid[room] & (1 << index) != 0 && distance[next] == u32::MAX {
+                distance[next] = distance[room] + 1;
+                todo.push_back(next);
+            }
+        }
+    }
+
+    distance
+}
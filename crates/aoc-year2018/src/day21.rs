@@ -58,9 +58,9 @@
 //!
 //! The cycle starts with `4` and ends with `2`, so the answer is `2`.
 //!
-//! [`Day 19`]: crate::year2018::day19
-use crate::util::hash::*;
-use crate::util::parse::*;
+//! [`Day 19`]: crate::day19
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> u64 {
     input.iter_unsigned().nth(22).unwrap()
@@ -23,10 +23,10 @@
 //! requiring 8 buckets total.
 //!
 //! [`BinaryHeap`]: std::collections::BinaryHeap
-use crate::util::grid::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::iter::repeat_with;
 
 /// The index of each tool is that tool that *cannot* be used in that region, for example
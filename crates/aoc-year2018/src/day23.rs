@@ -15,10 +15,11 @@
 //! * There are no cubes that are closer.
 //! * The coordinates cannot be refined any further.
 //!
-//! [`MinHeap`]: crate::util::heap
-use crate::util::heap::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+//! [`MinHeap`]: aoc_util::heap
+use aoc_util::heap::*;
+use aoc_util::integer::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub struct Nanobot {
     x: i32,
@@ -32,8 +33,8 @@ impl Nanobot {
         Nanobot { x, y, z, r }
     }
 
-    fn manhattan(&self, other: &Nanobot) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    fn manhattan(&self, other: &Nanobot) -> u32 {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
     }
 }
 
@@ -106,7 +107,7 @@ pub fn parse(input: &str) -> Vec<Nanobot> {
 
 pub fn part1(input: &[Nanobot]) -> usize {
     let strongest = input.iter().max_by_key(|nb| nb.r).unwrap();
-    input.iter().filter(|nb| strongest.manhattan(nb) <= strongest.r).count()
+    input.iter().filter(|nb| strongest.manhattan(nb) <= strongest.r as u32).count()
 }
 
 pub fn part2(input: &[Nanobot]) -> i32 {
@@ -3,13 +3,19 @@
 //! Similar to [`Day 15`] we implement the rules precisely, paying attention to edge cases.
 //!
 //! In particular during part two, it's possible for a fight to end in a draw, if both armies
-//! become too weak to destroy any further units. As each fight is independent, we find the
-//! minimum boost value with a multithreaded parallel search.
+//! become too weak to destroy any further units. A round where no units are killed is detected
+//! as a stalemate and the fight stops immediately.
 //!
-//! [`Day 15`]: crate::year2018::day15
-use crate::util::hash::*;
-use crate::util::parse::*;
-use crate::util::thread::*;
+//! Ties during target selection are impossible as every group has a unique initiative, so each
+//! fight is deterministic. However the outcome is not monotonic in the boost. A slightly higher
+//! boost can change which groups are targeted and turn a win into a draw, so a binary search
+//! could skip over the lowest winning boost. Instead we try every boost in increasing order,
+//! spreading the fights over multiple threads as each one is independent.
+//!
+//! [`Day 15`]: crate::day15
+use aoc_util::hash::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::mpsc::{Sender, channel};
 
@@ -18,13 +24,28 @@ pub struct Input {
     infection: Vec<Group>,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum Kind {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
     Immune,
     Infection,
     Draw,
 }
 
+/// Result of a single fight. `units` is the number of units remaining in the winning army.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub winner: Kind,
+    pub units: i32,
+}
+
+/// Snapshot passed to the hook after each round of a fight.
+pub struct Round {
+    pub turn: u32,
+    pub killed: i32,
+    pub immune: i32,
+    pub infection: i32,
+}
+
 #[derive(Clone, Copy)]
 struct Group {
     units: i32,
@@ -68,13 +89,6 @@ impl Group {
     }
 }
 
-/// Shared between threads for part two.
-struct Shared {
-    done: AtomicBool,
-    boost: AtomicI32,
-    tx: Sender<(i32, i32)>,
-}
-
 pub fn parse<'a>(input: &'a str) -> Input {
     // Use a bitmask to store each possible attack type.
     let mut elements = FastMap::new();
@@ -90,8 +104,7 @@ pub fn parse<'a>(input: &'a str) -> Input {
 }
 
 pub fn part1(input: &Input) -> i32 {
-    let (_, units) = fight(input, 0);
-    units
+    fight(input, 0).units
 }
 
 pub fn part2(input: &Input) -> i32 {
@@ -107,24 +120,40 @@ pub fn part2(input: &Input) -> i32 {
     rx.iter().min_by_key(|&(boost, _)| boost).map(|(_, units)| units).unwrap()
 }
 
+struct Shared {
+    done: AtomicBool,
+    boost: AtomicI32,
+    tx: Sender<(i32, i32)>,
+}
+
 fn worker(input: &Input, shared: &Shared) {
     while !shared.done.load(Ordering::Relaxed) {
         // Get the next attack boost, incrementing it atomically for the next fight.
         let boost = shared.boost.fetch_add(1, Ordering::Relaxed);
 
         // If the reindeer wins then set the score and signal all threads to stop.
-        // Use a channel to queue all potential scores as another thread may already have sent a
-        // different value.
-        let (kind, units) = fight(input, boost);
+        // Every lower boost has already been claimed by a thread that will finish its fight,
+        // so use a channel to queue all potential scores then pick the lowest boost.
+        let Outcome { winner, units } = fight(input, boost);
 
-        if kind == Kind::Immune {
+        if winner == Kind::Immune {
             shared.done.store(true, Ordering::Relaxed);
             let _unused = shared.tx.send((boost, units));
         }
     }
 }
 
-fn fight(input: &Input, boost: i32) -> (Kind, i32) {
+/// Fights to the end with the immune system's damage boosted by `boost`.
+pub fn fight(input: &Input, boost: i32) -> Outcome {
+    fight_with_hook(input, boost, |_| ())
+}
+
+/// Same as [`fight`] but calls `hook` after every round, for example to log or visualize
+/// the progress of each army.
+pub fn fight_with_hook<F>(input: &Input, boost: i32, mut hook: F) -> Outcome
+where
+    F: FnMut(&Round),
+{
     let mut immune = input.immune.clone();
     let mut infection = input.infection.clone();
     let mut attacks = vec![None; immune.len() + infection.len()];
@@ -178,20 +207,25 @@ fn fight(input: &Input, boost: i32) -> (Kind, i32) {
             }
         }
 
+        // Remove destroyed groups.
+        immune.retain(|group| group.units > 0);
+        infection.retain(|group| group.units > 0);
+
+        let units = |army: &[Group]| army.iter().map(|group| group.units).sum();
+        let round = Round { turn, killed, immune: units(&immune), infection: units(&infection) };
+        hook(&round);
+
         // It's possible to deadlock if groups become too weak to do any more damage.
         if killed == 0 {
-            return (Kind::Draw, 0);
+            return Outcome { winner: Kind::Draw, units: 0 };
         }
 
         // Check for winner.
-        immune.retain(|group| group.units > 0);
-        infection.retain(|group| group.units > 0);
-
         if immune.is_empty() {
-            return (Kind::Infection, infection.iter().map(|group| group.units).sum());
+            return Outcome { winner: Kind::Infection, units: round.infection };
         }
         if infection.is_empty() {
-            return (Kind::Immune, immune.iter().map(|group| group.units).sum());
+            return Outcome { winner: Kind::Immune, units: round.immune };
         }
     }
 
@@ -0,0 +1,73 @@
+//! # Four-Dimensional Adventure
+//!
+//! This problem is the classic [union find](https://en.wikipedia.org/wiki/Disjoint-set_data_structure).
+//! Two points are joined by an edge when they are within range of each other, then the shared
+//! [`components`] helper finds the size of each constellation.
+//!
+//! Checking every pair of points is `O(n²)` but each point can be checked independently, so we
+//! split the work between multiple threads, each collecting edges locally before adding them to
+//! a shared list.
+use aoc_util::graph::*;
+use aoc_util::integer::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
+use std::sync::Mutex;
+
+#[derive(Clone, Copy)]
+pub struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+    w: i32,
+}
+
+/// Simple 4D point implementation with Manhattan distance.
+impl Point {
+    fn from([x, y, z, w]: [i32; 4]) -> Self {
+        Point { x, y, z, w }
+    }
+
+    fn mahattan(&self, other: Self) -> u32 {
+        self.x.distance(other.x)
+            + self.y.distance(other.y)
+            + self.z.distance(other.z)
+            + self.w.distance(other.w)
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Point> {
+    input.iter_signed::<i32>().chunk::<4>().map(Point::from).collect()
+}
+
+pub fn part1(input: &[Point]) -> usize {
+    constellations(input).len()
+}
+
+pub fn part2(_input: &[Point]) -> &'static str {
+    "n/a"
+}
+
+/// Size of each constellation, ordered by the earliest point that it contains.
+pub fn constellations(input: &[Point]) -> Vec<usize> {
+    let items: Vec<_> = (0..input.len()).collect();
+    let edges = Mutex::new(Vec::new());
+
+    // Use as many cores as possible to parallelize the pairwise distance checks.
+    spawn_parallel_iterator(&items, |iter| worker(input, &edges, iter));
+    components(input.len(), edges.into_inner().unwrap())
+}
+
+fn worker(input: &[Point], edges: &Mutex<Vec<(usize, usize)>>, iter: ParIter<'_, usize>) {
+    let mut local = Vec::new();
+
+    for &i in iter {
+        for j in (i + 1)..input.len() {
+            if input[i].mahattan(input[j]) <= 3 {
+                local.push((i, j));
+            }
+        }
+    }
+
+    edges.lock().unwrap().extend(local);
+}
@@ -2,11 +2,11 @@
 //!
 //! The title of the problem is a reference to the
 //! [real life equation](https://en.wikipedia.org/wiki/Tsiolkovsky_rocket_equation).
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 /// The [`iter_unsigned`] utility method extracts and parses numbers from surrounding text.
 ///
-/// [`iter_unsigned`]: crate::util::parse
+/// [`iter_unsigned`]: aoc_util::parse
 pub fn parse(input: &str) -> Vec<u32> {
     input.iter_unsigned().collect()
 }
@@ -9,7 +9,8 @@
 //! As the equation is monotonically increasing in both noun and verb, we can efficiently solve
 //! part two by binary searching in two dimensions, instead of a slow brute force check of all
 //! possible 10,000 combinations.
-use crate::util::parse::*;
+use aoc_util::integer::*;
+use aoc_util::parse::*;
 use std::cmp::Ordering::*;
 
 type Input = [i32; 3];
@@ -58,8 +59,8 @@ fn search(input: &Input, x1: i32, x2: i32, y1: i32, y2: i32) -> Option<i32> {
         return None;
     }
 
-    let x = (x1 + x2) / 2;
-    let y = (y1 + y2) / 2;
+    let x = x1.mid(x2);
+    let y = y1.mid(y2);
     let [a, b, c] = input;
     let result = a * x + b * y + c;
 
@@ -16,8 +16,8 @@
 //! from the starting point of each lines.
 //!
 //! [`range`]: BTreeMap::range
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::collections::BTreeMap;
 
 type Input = (i32, i32);
@@ -3,8 +3,8 @@
 //! We speed things up by only checking numbers that have digits in non-decreasing order for pairs.
 //! These numbers become rapidly less dense as the password value increases and there
 //! are only 3003 total of these numbers with 6 digits.
-use crate::util::parse::*;
-use crate::util::slice::*;
+use aoc_util::parse::*;
+use aoc_util::slice::*;
 
 pub fn parse(input: &str) -> Vec<u32> {
     input.iter_unsigned().collect()
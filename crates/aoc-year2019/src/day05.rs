@@ -1,6 +1,6 @@
 //! # Sunny with a Chance of Asteroids
 use super::intcode::*;
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<i64> {
     input.iter_signed().collect()
@@ -7,7 +7,7 @@
 //!
 //! This is twice as fast as using a [`FastMap`] to lookup the indices.
 //!
-//! [`FastMap`]: crate::util::hash
+//! [`FastMap`]: aoc_util::hash
 
 /// Convert 3 character object names to contiguous indices for faster lookup.
 pub fn parse(input: &str) -> Vec<usize> {
@@ -3,10 +3,10 @@
 //! Brute force solution for both parts using the utility [`permutations`] method to test each of
 //! the possible 5! or 120 permutations of the phase settings.
 //!
-//! [`permutations`]: crate::util::slice
+//! [`permutations`]: aoc_util::slice
 use super::intcode::*;
-use crate::util::parse::*;
-use crate::util::slice::*;
+use aoc_util::parse::*;
+use aoc_util::slice::*;
 use std::array::from_fn;
 
 pub fn parse(input: &str) -> Vec<i64> {
@@ -2,7 +2,7 @@
 //!
 //! This problem is essentially a unit test for the full intcode computer.
 use super::intcode::*;
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<i64> {
     input.iter_signed().collect()
@@ -46,8 +46,8 @@
 //! sorting a second time in this order.
 //!
 //! [`atan2`]: f64::atan2
-use crate::util::math::*;
-use crate::util::point::*;
+use aoc_util::math::*;
+use aoc_util::point::*;
 use std::cmp::Ordering;
 
 type Input = (i32, i32);
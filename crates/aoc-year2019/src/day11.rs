@@ -0,0 +1,115 @@
+//! # Space Police
+//!
+//! This problem is a variant of [Langton's Ant](https://en.wikipedia.org/wiki/Langton%27s_ant).
+//!
+//! The robot's path isn't known in advance, so the hull is a [`Grid`] that starts small then
+//! doubles in size around its contents whenever the robot walks off an edge. This is faster than
+//! a hash map as each step is a single array lookup and only a handful of resizes are needed.
+//!
+//! Part two paints capital letters that are read using the shared [`ocr`] module, falling back
+//! to drawing the panels if any letter isn't recognized.
+//!
+//! [`ocr`]: aoc_util::ocr
+use super::intcode::*;
+use aoc_util::grid::*;
+use aoc_util::ocr::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
+
+const WHITE: u8 = 1;
+const PAINTED: u8 = 2;
+
+/// Grid that grows as needed. Cells store the current color and whether the panel has ever
+/// been painted. `offset` is the grid location of the robot's starting panel.
+struct Hull {
+    grid: Grid<u8>,
+    offset: Point,
+}
+
+impl Hull {
+    fn new() -> Self {
+        Hull { grid: Grid::new(16, 16, 0), offset: Point::new(8, 8) }
+    }
+
+    /// Returns the grid location of a panel, growing the grid first if necessary.
+    fn locate(&mut self, position: Point) -> Point {
+        let mut point = position + self.offset;
+
+        while !self.grid.contains(point) {
+            let Grid { width, height, .. } = self.grid;
+            let margin = Point::new(width / 2, height / 2);
+            let mut bigger = Grid::new(2 * width, 2 * height, 0);
+
+            bigger.blit(&self.grid, margin);
+            self.grid = bigger;
+            self.offset += margin;
+            point += margin;
+        }
+
+        point
+    }
+}
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> usize {
+    paint(input, 0).count(|&cell| cell & PAINTED != 0)
+}
+
+pub fn part2(input: &[i64]) -> String {
+    let hull = paint(input, WHITE);
+    let white: Vec<_> = hull.find_iter(|&cell| cell & WHITE != 0).collect();
+
+    // Bounding box of the white panels.
+    let left = white.iter().map(|p| p.x).min().unwrap();
+    let right = white.iter().map(|p| p.x).max().unwrap();
+    let top = white.iter().map(|p| p.y).min().unwrap();
+    let bottom = white.iter().map(|p| p.y).max().unwrap();
+
+    let is_white = |x, y| {
+        let point = Point::new(left + x, top + y);
+        hull.contains(point) && hull[point] & WHITE != 0
+    };
+
+    decode(right - left + 1, is_white).unwrap_or_else(|| {
+        // Draw the panels as a multiline string instead.
+        let mut result = String::new();
+
+        for y in 0..=bottom - top {
+            result.push('\n');
+            result.extend((0..=right - left).map(|x| if is_white(x, y) { '#' } else { '.' }));
+        }
+
+        result
+    })
+}
+
+fn paint(input: &[i64], initial: u8) -> Grid<u8> {
+    let mut computer = Computer::new(input);
+    let mut hull = Hull::new();
+    let mut position = ORIGIN;
+    let mut direction = UP;
+
+    let start = hull.locate(position);
+    hull.grid[start] = initial;
+
+    loop {
+        let point = hull.locate(position);
+        computer.input(i64::from(hull.grid[point] & WHITE));
+
+        let State::Output(color) = computer.run() else {
+            break;
+        };
+        hull.grid[point] = PAINTED | if color == 0 { 0 } else { WHITE };
+
+        let State::Output(turn) = computer.run() else {
+            break;
+        };
+        direction = if turn == 0 { direction.counter_clockwise() } else { direction.clockwise() };
+        position += direction;
+    }
+
+    hull.grid
+}
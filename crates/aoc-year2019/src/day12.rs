@@ -14,8 +14,8 @@
 //! The [`signum`] function comes in handy when updating the velocity.
 //!
 //! [`signum`]: i32::signum
-use crate::util::math::*;
-use crate::util::parse::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
 type Axis = [i32; 8];
 type Input = [Axis; 3];
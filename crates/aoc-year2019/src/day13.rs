@@ -8,7 +8,7 @@
 //!
 //! [`signum`]: i64::signum
 use super::intcode::*;
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<i64> {
     input.iter_signed().collect()
@@ -100,7 +100,7 @@ pub fn part2(input: &[i64]) -> i64 {
 
 #[cfg(feature = "frivolity")]
 fn draw(tiles: &[i64], stride: i64, score: i64, blocks: i64) {
-    use crate::util::ansi::*;
+    use aoc_util::ansi::*;
     use std::fmt::Write as _;
     use std::thread::sleep;
     use std::time::Duration;
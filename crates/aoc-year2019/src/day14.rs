@@ -2,9 +2,9 @@
 //!
 //! Sorting the reactions in [topological order](https://en.wikipedia.org/wiki/Topological_sorting)
 //! from `FUEL` at the start to `ORE` at the end, allows us to process each reaction only once.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 use std::cmp::Ordering;
 use std::iter::repeat_with;
 
@@ -10,9 +10,9 @@
 //! [wall follower](https://en.wikipedia.org/wiki/Maze-solving_algorithm#Wall_follower)
 //! algorithm to eventually trace our way through the entire maze back to the starting point.
 use super::intcode::*;
-use crate::util::hash::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 
 type Input = (FastSet<Point>, Point);
@@ -67,6 +67,9 @@
 //! coefficients modulo some prime number. If we compute the coefficients modulo 2 and modulo 5
 //! then we can use the [Chinese remainder theorem] to find the result modulo 10.
 //!
+//! The `simd` feature accumulates all 8 result digits in a single vector. Enabling `compare` as
+//! well keeps the scalar version so that tests can check that both give the same answer.
+//!
 //! [prefix sum]: https://en.wikipedia.org/wiki/Prefix_sum
 //! [upper triangular matrix]: https://en.wikipedia.org/wiki/Triangular_matrix
 //! [triangular number]: https://en.wikipedia.org/wiki/Triangular_number
@@ -75,8 +78,8 @@
 //! [grows rather large]: https://oeis.org/A017763/b017763.txt
 //! [Lucas's theorem]: https://en.wikipedia.org/wiki/Lucas%27s_theorem
 //! [Chinese remainder theorem]: https://en.wikipedia.org/wiki/Chinese_remainder_theorem
-use crate::util::parse::*;
-use crate::util::slice::*;
+use aoc_util::parse::*;
+use aoc_util::slice::*;
 
 /// Lookup table for first five rows of
 /// [Pascal's triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle).
@@ -153,43 +156,61 @@ pub fn part2(input: &[u8]) -> usize {
 
 #[cfg(not(feature = "simd"))]
 fn compute(digits: &[usize], size: usize, start: usize, upper: usize) -> usize {
-    let mut coefficients = [0; 8];
-    let mut result = [0; 8];
+    scalar::compute(digits, size, start, upper)
+}
+
+#[cfg(feature = "simd")]
+fn compute(digits: &[usize], size: usize, start: usize, upper: usize) -> usize {
+    simd::compute(digits, size, start, upper)
+}
 
-    for (k, index) in (start..upper).enumerate() {
-        coefficients.rotate_right(1);
-        coefficients[0] = binomial_mod_10(k + 99, k);
+#[cfg(any(not(feature = "simd"), feature = "compare"))]
+pub mod scalar {
+    use super::*;
 
-        let next = digits[index % size];
-        result.iter_mut().zip(coefficients).for_each(|(r, c)| *r += next * c);
-    }
+    pub fn compute(digits: &[usize], size: usize, start: usize, upper: usize) -> usize {
+        let mut coefficients = [0; 8];
+        let mut result = [0; 8];
+
+        for (k, index) in (start..upper).enumerate() {
+            coefficients.rotate_right(1);
+            coefficients[0] = binomial_mod_10(k + 99, k);
 
-    result.iter_mut().for_each(|r| *r %= 10);
-    result.fold_decimal()
+            let next = digits[index % size];
+            result.iter_mut().zip(coefficients).for_each(|(r, c)| *r += next * c);
+        }
+
+        result.iter_mut().for_each(|r| *r %= 10);
+        result.fold_decimal()
+    }
 }
 
 #[cfg(feature = "simd")]
-fn compute(digits: &[usize], size: usize, start: usize, upper: usize) -> usize {
+pub mod simd {
+    use super::*;
     use std::simd::Mask;
+    use std::simd::Select as _;
     use std::simd::Simd;
 
-    let mask: Mask<i32, 8> = Mask::from_bitmask(1);
-    let tens: Simd<u32, 8> = Simd::splat(10);
+    pub fn compute(digits: &[usize], size: usize, start: usize, upper: usize) -> usize {
+        let mask: Mask<i32, 8> = Mask::from_bitmask(1);
+        let tens: Simd<u32, 8> = Simd::splat(10);
 
-    let mut coefficients: Simd<u32, 8> = Simd::splat(0);
-    let mut result: Simd<u32, 8> = Simd::splat(0);
+        let mut coefficients: Simd<u32, 8> = Simd::splat(0);
+        let mut result: Simd<u32, 8> = Simd::splat(0);
 
-    for (k, index) in (start..upper).enumerate() {
-        coefficients = mask.select(
-            Simd::splat(binomial_mod_10(k + 99, k) as u32),
-            coefficients.rotate_elements_right::<1>(),
-        );
+        for (k, index) in (start..upper).enumerate() {
+            coefficients = mask.select(
+                Simd::splat(binomial_mod_10(k + 99, k) as u32),
+                coefficients.rotate_elements_right::<1>(),
+            );
 
-        let next = Simd::splat(digits[index % size] as u32);
-        result += next * coefficients;
-    }
+            let next = Simd::splat(digits[index % size] as u32);
+            result += next * coefficients;
+        }
 
-    (result % tens).to_array().fold_decimal() as usize
+        (result % tens).to_array().fold_decimal() as usize
+    }
 }
 
 /// Computes C(n, k) % 2
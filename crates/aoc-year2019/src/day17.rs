@@ -15,9 +15,9 @@
 //! Without loss of any generality the first pattern anchored at the start is always `A`,
 //! the next `B` and the last `C`.
 use super::intcode::*;
-use crate::util::hash::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::fmt::Write as _;
 use std::ops::ControlFlow;
 
@@ -227,7 +227,7 @@ fn visit(mut computer: Computer) -> i64 {
 /// Non essential but fun. Animates the robot traversing the scaffold.
 #[cfg(feature = "frivolity")]
 fn visit(mut computer: Computer) -> i64 {
-    use crate::util::ansi::*;
+    use aoc_util::ansi::*;
     use std::thread::sleep;
     use std::time::Duration;
 
@@ -50,10 +50,11 @@
 #![allow(clippy::needless_range_loop)]
 #![allow(clippy::unnecessary_lazy_evaluations)]
 
-use crate::util::bitset::*;
-use crate::util::grid::*;
-use crate::util::hash::*;
-use crate::util::heap::*;
+use aoc_util::bitset::*;
+use aoc_util::capacity::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::heap::*;
 use std::collections::VecDeque;
 
 /// `position` and `remaining` are both bitfields. For example a robot at key `d` that needs
@@ -184,8 +185,11 @@ fn parse_maze(width: i32, bytes: &[u8]) -> Maze {
 }
 
 fn explore(width: i32, bytes: &[u8]) -> u32 {
-    let mut todo = MinHeap::with_capacity(5_000);
-    let mut cache = FastMap::with_capacity(5_000);
+    // The number of states grows with the number of keys to collect.
+    let keys = bytes.iter().filter(|b| b.is_ascii_lowercase()).count();
+    let capacity = estimate(keys, 200);
+    let mut todo = MinHeap::with_capacity(capacity);
+    let mut cache = FastMap::with_capacity(capacity);
 
     let Maze { initial, maze } = parse_maze(width, bytes);
     todo.push(0, initial);
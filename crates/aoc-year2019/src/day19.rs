@@ -0,0 +1,125 @@
+//! # Tractor Beam
+//!
+//! Finds the approximate boundary of the upper and lower edges of the beam expressed as a slope.
+//! We then skip the relatively expensive intcode test if the x and y coordinates lie outside.
+//!
+//! The left and right edges of the beam only ever move to the right as the row increases, so
+//! for part two we track the left edge of the bottom row of the square incrementally. Each row
+//! needs only a few intcode tests rather than checking every point in the area.
+//!
+//! The intcode computer is created once then cloned for each test, skipping the cost of
+//! converting the program each time.
+use super::intcode::*;
+use aoc_util::parse::*;
+
+pub struct Input {
+    computer: Computer,
+    lower: i64,
+    upper: i64,
+}
+
+pub fn parse(input: &str) -> Input {
+    let code: Vec<_> = input.iter_signed().collect();
+    let computer = Computer::new(&code);
+    let mut input = Input { computer, lower: 0, upper: 0 };
+
+    // Find slope of lower and upper edges, rounding down to prevent false negatives.
+    while !test(&input, input.lower + 1, 50) {
+        input.lower += 1;
+    }
+    while !test(&input, 50, input.upper + 1) {
+        input.upper += 1;
+    }
+
+    input
+}
+
+pub fn part1(input: &Input) -> i64 {
+    // Handle origin specially
+    let mut result = test(input, 0, 0) as i64;
+
+    // The beam is continuous so we only need to find the left and right edges.
+    for y in 0..50 {
+        let mut left = i64::MAX;
+        let mut right = i64::MIN;
+
+        for x in 0..50 {
+            if precheck(input, x, y) && test(input, x, y) {
+                left = x;
+                break;
+            }
+        }
+        for x in (0..50).rev() {
+            if precheck(input, x, y) && test(input, x, y) {
+                right = x;
+                break;
+            }
+        }
+        if left <= right {
+            result += right - left + 1;
+        }
+    }
+
+    result
+}
+
+pub fn part2(input: &Input) -> i64 {
+    let mut left = 0;
+
+    // The square fits when the top right corner is inside the beam, given the bottom left corner
+    // is on the left edge of the beam.
+    for y in 99.. {
+        if let Some(x) = left_edge(input, left, y) {
+            left = x;
+
+            if test(input, left + 99, y - 99) {
+                return 10000 * left + y - 99;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// Number of points affected by the beam in a single row.
+pub fn beam_width(input: &Input, row: i64) -> i64 {
+    let Some(left) = left_edge(input, 0, row) else {
+        return 0;
+    };
+
+    let mut right = left;
+    while test(input, right + 1, row) {
+        right += 1;
+    }
+
+    right - left + 1
+}
+
+/// Finds the left edge of the beam starting the search from `x`. Returns `None` if the row is
+/// empty.
+fn left_edge(input: &Input, mut x: i64, y: i64) -> Option<i64> {
+    // Once past the upper slope there can be no more points in this row.
+    while 50 * y > input.upper * x {
+        if precheck(input, x, y) && test(input, x, y) {
+            return Some(x);
+        }
+        x += 1;
+    }
+
+    None
+}
+
+/// Quick check with some false positives but no false negatives.
+fn precheck(input: &Input, x: i64, y: i64) -> bool {
+    50 * y > input.upper * x && 50 * x > input.lower * y
+}
+
+/// Definitive but slower check.
+fn test(input: &Input, x: i64, y: i64) -> bool {
+    let mut computer = input.computer.clone();
+    computer.input(x);
+    computer.input(y);
+
+    let State::Output(result) = computer.run() else { unreachable!() };
+    result == 1
+}
@@ -10,11 +10,11 @@
 //! memoize previously seen values. We optimize part two further by not recursing deeper than the
 //! number of portals as this would mean a redundant trip to an already visited portal.
 //!
-//! [`Day 18`]: crate::year2019::day18
+//! [`Day 18`]: crate::day18
 //! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
-use crate::util::grid::*;
-use crate::util::hash::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 
 type Key = ((u8, u8), Kind);
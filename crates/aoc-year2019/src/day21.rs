@@ -20,7 +20,7 @@
 //! We add two rules, either `H` needs to be ground so that we double jump immediately or `E`
 //! needs to be ground, so that we can wait and not jump too early.
 use super::intcode::*;
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 const SLOW: &str = "\
 OR A J
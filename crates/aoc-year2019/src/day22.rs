@@ -17,7 +17,7 @@
 //! The next required insight is that each of the shuffle operation is a *linear congruence*
 //! of the form:
 //!
-//! `Xₙ₊₁ = (aXₙ + c) mod m`
+//! `Xₙ₊₁ = (aXₙ + b) mod m`
 //!
 //! For example "deal into new stack" which reverses the deck can be represented as:
 //!
@@ -56,7 +56,7 @@
 //!
 //! Congruences can be composed:
 //!
-//! `Xₙ₊₁ = a₂ * (a₁Xₙ + c₁) + c₂) mod m = (a₁a₂Xₙ + a₂c₁ + c₂) mod m`
+//! `Xₙ₊₁ = a₂ * (a₁Xₙ + b₁) + b₂) mod m = (a₁a₂Xₙ + a₂b₁ + b₂) mod m`
 //!
 //! so we could combine the previous "cut 3" and deal with increment 3" as:
 //!
@@ -70,68 +70,102 @@
 //! Part two is trickier. To find the card that ends up at index 2020 we need to find an inverse
 //! congruence such that when we apply a composition to the shuffle we get the identity congruence:
 //!
-//! `(a₁a₂Xₙ + a₂c₁ + c₂) mod m = (Xₙ + 0) mod m`
+//! `(a₁a₂Xₙ + a₂b₁ + b₂) mod m = (Xₙ + 0) mod m`
 //!
 //! This implies that `a₁a₂ mod m = 1` which is the definition of the modular inverse`a₂ = a₁⁻¹`.
 //!
-//! The constant term `(a₂c₁ + c₂) mod m = 0` implies `c₂ = m - a₂c₁`.
+//! The constant term `(a₂b₁ + b₂) mod m = 0` implies `b₂ = m - a₂b₁`.
 //!
 //! ## Exponentiation
 //!
 //! To find of inverse of 101741582076661 shuffles we need to raise to our inverse to the same
 //! power. Let's look at the first few powers
-//! * ax + c
-//! * a * (ax + c) + c = a²x + ac + c
-//! * a * (a²x + ac + c) = a³x + a²c + ac + c
-//! * a * (a³x + a²c + ac + c) = a⁴x + a³c + a²c + ac + c
+//! * ax + b
+//! * a * (ax + b) + b = a²x + ab + b
+//! * a * (a²x + ab + b) = a³x + a²b + ab + b
+//! * a * (a³x + a²b + ab + b) = a⁴x + a³b + a²b + ab + b
 //!
 //! We notice that the constant terms are the sum of a [geometric series](https://en.wikipedia.org/wiki/Geometric_series)
 //! which is given by the closed-form formula:
 //!
-//! `cₙ = c(1 - aⁿ)/(1 - a)`
+//! `bₙ = b(1 - aⁿ)/(1 - a)`
 //!
 //! Multiplying both sides by -1 and remembering that modular division is the multiplicative
 //! inverse yields:
 //!
-//! `cₙ = c(aⁿ - 1)((a - 1)⁻¹) mod m`
+//! `bₙ = b(aⁿ - 1)((a - 1)⁻¹) mod m`
 //!
 //! We can then raise a congruence to any power, using only one modular exponentiation and
 //! one modular inverse, allowing us to solve part two efficiently.
 //!
-//! `Xₙ₊₁ = (aⁿXₙ + c(aⁿ - 1)((a - 1)⁻¹)) mod m`
-use crate::util::math::*;
-use crate::util::parse::*;
+//! `Xₙ₊₁ = (aⁿXₙ + b(aⁿ - 1)((a - 1)⁻¹)) mod m`
+//!
+//! ## Queries
+//!
+//! An [`Affine`] shuffle maps the position of a card before shuffling to its position after.
+//! [`position_of`] answers "where does card `c` end up?" directly, while [`card_at`] answers
+//! "which card ends up at position `p`?" by applying the inverse.
+//!
+//! [`position_of`]: Affine::position_of
+//! [`card_at`]: Affine::card_at
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
-struct Technique {
-    a: i128,
-    c: i128,
-    m: i128,
+/// Linear congruence `(aX + b) mod m` mapping a card's position before a shuffle to its
+/// position after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Affine {
+    pub a: i128,
+    pub b: i128,
+    pub m: i128,
 }
 
-impl Technique {
-    fn compose(&self, other: &Technique) -> Technique {
+impl Affine {
+    /// Shuffle that leaves every card in place.
+    pub fn identity(m: i128) -> Affine {
+        Affine { a: 1, b: 0, m }
+    }
+
+    /// Applies `self` then `other`.
+    #[must_use]
+    pub fn compose(&self, other: &Affine) -> Affine {
         let m = self.m;
         let a = (self.a * other.a) % m;
-        let c = (self.c * other.a + other.c) % m;
-        Technique { a, c, m }
+        let b = (self.b * other.a + other.b) % m;
+        Affine { a, b, m }
     }
 
-    fn inverse(&self) -> Technique {
+    /// Undoes the shuffle. The deck size must be coprime with `a`.
+    #[must_use]
+    pub fn invert(&self) -> Affine {
         let m = self.m;
         let a = self.a.mod_inv(m).unwrap();
-        let c = m - (a * self.c) % m;
-        Technique { a, c, m }
+        let b = (m - (a * self.b) % m) % m;
+        Affine { a, b, m }
     }
 
-    fn power(&self, e: i128) -> Technique {
+    /// Repeats the shuffle `e` times. The deck size must be prime.
+    #[must_use]
+    pub fn pow(&self, e: i128) -> Affine {
         let m = self.m;
         let a = self.a.mod_pow(e, m);
-        let c = (((a - 1) * (self.a - 1).mod_inv(m).unwrap() % m) * self.c) % m;
-        Technique { a, c, m }
+        // The geometric series has no closed form when a is one.
+        let b = if self.a == 1 {
+            (self.b * (e % m)) % m
+        } else {
+            (((a - 1) * (self.a - 1).mod_inv(m).unwrap() % m) * self.b) % m
+        };
+        Affine { a, b, m }
+    }
+
+    /// Where does `card` end up after shuffling?
+    pub fn position_of(&self, card: i128) -> i128 {
+        (self.a * card + self.b) % self.m
     }
 
-    fn shuffle(&self, index: i128) -> i128 {
-        (self.a * index + self.c) % self.m
+    /// Which card ends up at `position` after shuffling?
+    pub fn card_at(&self, position: i128) -> i128 {
+        self.invert().position_of(position)
     }
 }
 
@@ -140,33 +174,33 @@ pub fn parse(input: &str) -> &str {
 }
 
 pub fn part1(input: &str) -> i128 {
-    deck(input, 10007).shuffle(2019)
+    deck(input, 10007).position_of(2019)
 }
 
 pub fn part2(input: &str) -> i128 {
-    deck(input, 119315717514047).inverse().power(101741582076661).shuffle(2020)
+    deck(input, 119315717514047).pow(101741582076661).card_at(2020)
 }
 
-fn deck(input: &str, m: i128) -> Technique {
+/// Combines every technique in the input into a single shuffle for a deck of `m` cards.
+pub fn deck(input: &str, m: i128) -> Affine {
     input
         .lines()
         .map(|line| {
             let tokens: Vec<_> = line.split_ascii_whitespace().collect();
             match tokens[..] {
-                [_, "into", _, _] => Technique { a: m - 1, c: m - 1, m },
+                [_, "into", _, _] => Affine { a: m - 1, b: m - 1, m },
                 [_, "with", _, n] => {
                     let n: i128 = n.signed();
                     let a = (m + n % m) % m;
-                    Technique { a, c: 0, m }
+                    Affine { a, b: 0, m }
                 }
                 ["cut", n] => {
                     let n: i128 = n.signed();
-                    let c = (m - n % m) % m;
-                    Technique { a: 1, c, m }
+                    let b = (m - n % m) % m;
+                    Affine { a: 1, b, m }
                 }
                 _ => unreachable!(),
             }
         })
-        .reduce(|a, b| a.compose(&b))
-        .unwrap()
+        .fold(Affine::identity(m), |a, b| a.compose(&b))
 }
@@ -0,0 +1,284 @@
+//! # Category Six
+//!
+//! Solves both part one and two simultaneously. A nice benefit of our intcode computer is that it
+//! returns [`State::Input`] when the input queue is empty, making it easy to detect an
+//! idle network.
+//!
+//! Each computer runs on its own thread with a channel as its input queue. Every packet is sent
+//! over a second channel to a central router, which forwards it to the inbox of its destination
+//! or holds on to it as the NAT. A computer that asks for input twice in a row with an empty inbox
+//! is idle.
+//!
+//! Idleness is tracked by a single atomic counter of busy computers plus packets not yet read.
+//! A packet is counted by its sender before it is sent. When an idle computer reads a packet,
+//! the packet's count passes to the computer, so the counter never drops to zero while a packet
+//! is still on its way. The router spin waits until the counter is zero, at which point the
+//! network is truly idle and the NAT can wake up computer 0.
+//!
+//! When built with the `no-threads` feature computers are run one at a time in a fixed round
+//! robin order instead. The network is then idle once every computer has asked for input during
+//! a round *and* no packets were sent, otherwise a packet could still be waiting in the queue of
+//! a computer that was checked earlier in the same round.
+//!
+//! [`network`] accepts a callback that receives every packet, including those sent to and from
+//! the NAT at address 255, which is handy to trace the traffic.
+use super::intcode::*;
+use aoc_util::parse::*;
+#[cfg(not(feature = "no-threads"))]
+use std::iter::repeat_with;
+#[cfg(not(feature = "no-threads"))]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering::SeqCst};
+#[cfg(not(feature = "no-threads"))]
+use std::sync::mpsc::{Receiver, Sender, channel};
+#[cfg(not(feature = "no-threads"))]
+use std::thread;
+
+type Input = (i64, i64);
+
+/// Address of the NAT.
+pub const NAT: i64 = 255;
+
+/// A single packet with the addresses of its sender and receiver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub from: i64,
+    pub to: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+pub fn parse(input: &str) -> Input {
+    let code: Vec<_> = input.iter_signed().collect();
+    network(&code, |_| ())
+}
+
+pub fn part1(input: &Input) -> i64 {
+    input.0
+}
+
+pub fn part2(input: &Input) -> i64 {
+    input.1
+}
+
+/// State shared between the router and every computer.
+#[cfg(not(feature = "no-threads"))]
+struct Shared {
+    /// Computers that aren't idle plus packets that haven't been read yet.
+    busy: AtomicUsize,
+    done: AtomicBool,
+}
+
+/// Runs 50 computers until the NAT delivers the same `y` value twice in a row, returning the
+/// first `y` value sent to the NAT and the repeated value.
+#[cfg(not(feature = "no-threads"))]
+pub fn network<F>(code: &[i64], mut trace: F) -> Input
+where
+    F: FnMut(Packet),
+{
+    // Every computer starts out busy.
+    let shared = Shared { busy: AtomicUsize::new(50), done: AtomicBool::new(false) };
+    let (outbox, router) = channel();
+    let (inboxes, receivers): (Vec<_>, Vec<_>) = repeat_with(channel).take(50).unzip();
+
+    thread::scope(|scope| {
+        for (address, inbox) in receivers.into_iter().enumerate() {
+            let outbox = outbox.clone();
+            let shared = &shared;
+            scope.spawn(move || computer(code, address, &inbox, &outbox, shared));
+        }
+        route(&shared, &router, &inboxes, &mut trace)
+    })
+}
+
+/// Runs a single computer until the router is done.
+#[cfg(not(feature = "no-threads"))]
+fn computer(
+    code: &[i64],
+    address: usize,
+    inbox: &Receiver<(i64, i64)>,
+    outbox: &Sender<Packet>,
+    shared: &Shared,
+) {
+    let mut computer = Computer::new(code);
+    computer.input(address as i64);
+
+    let mut sent = Vec::new();
+    let mut polls = 0;
+
+    while !shared.done.load(SeqCst) {
+        match computer.run() {
+            State::Output(value) => {
+                // An idle computer that sends a packet is busy again.
+                if polls >= 2 {
+                    shared.busy.fetch_add(1, SeqCst);
+                }
+                polls = 0;
+
+                // Loop until we have accumulated a full packet of 3 values.
+                sent.push(value);
+                let [to, x, y] = sent[..] else {
+                    continue;
+                };
+                sent.clear();
+
+                // Count the packet before sending so that the network can't look idle meanwhile.
+                shared.busy.fetch_add(1, SeqCst);
+                let _unused = outbox.send(Packet { from: address as i64, to, x, y });
+            }
+            State::Input => {
+                if let Ok((x, y)) = inbox.try_recv() {
+                    // An idle computer takes over the count of the packet, otherwise the packet
+                    // is done with.
+                    if polls < 2 {
+                        shared.busy.fetch_sub(1, SeqCst);
+                    }
+                    polls = 0;
+                    computer.input(x);
+                    computer.input(y);
+                } else {
+                    polls += 1;
+                    if polls == 2 {
+                        shared.busy.fetch_sub(1, SeqCst);
+                    }
+                    if polls >= 2 {
+                        // Give other computers a turn on machines with fewer cores than threads.
+                        thread::yield_now();
+                    }
+                    computer.input(-1);
+                }
+            }
+            State::Halted => unreachable!(),
+        }
+    }
+}
+
+/// Forwards packets to their destination until the NAT delivers the same `y` value twice in a
+/// row, then tells every computer to stop.
+#[cfg(not(feature = "no-threads"))]
+fn route<F>(
+    shared: &Shared,
+    router: &Receiver<Packet>,
+    inboxes: &[Sender<(i64, i64)>],
+    trace: &mut F,
+) -> Input
+where
+    F: FnMut(Packet),
+{
+    let mut nat_x = 0;
+    let mut nat_y = 0;
+    let mut first_y = None;
+    let mut idle_y = None;
+
+    loop {
+        if let Ok(packet) = router.try_recv() {
+            let Packet { to, x, y, .. } = packet;
+            trace(packet);
+
+            if to == NAT {
+                // Handle part one.
+                if first_y.is_none() {
+                    first_y = Some(y);
+                }
+                nat_x = x;
+                nat_y = y;
+                shared.busy.fetch_sub(1, SeqCst);
+            } else {
+                let _unused = inboxes[to as usize].send((x, y));
+            }
+        } else if shared.busy.load(SeqCst) == 0 {
+            if idle_y == Some(nat_y) {
+                break;
+            }
+            idle_y = Some(nat_y);
+            trace(Packet { from: NAT, to: 0, x: nat_x, y: nat_y });
+
+            shared.busy.fetch_add(1, SeqCst);
+            let _unused = inboxes[0].send((nat_x, nat_y));
+        } else {
+            thread::yield_now();
+        }
+    }
+
+    shared.done.store(true, SeqCst);
+    (first_y.unwrap(), idle_y.unwrap())
+}
+
+/// Runs 50 computers until the NAT delivers the same `y` value twice in a row, returning the
+/// first `y` value sent to the NAT and the repeated value.
+#[cfg(feature = "no-threads")]
+pub fn network<F>(code: &[i64], mut trace: F) -> Input
+where
+    F: FnMut(Packet),
+{
+    let mut network: Vec<_> = (0..50)
+        .map(|address| {
+            let mut computer = Computer::new(code);
+            computer.input(address);
+            computer
+        })
+        .collect();
+
+    let mut sent = Vec::new();
+    let mut nat_x = 0;
+    let mut nat_y = 0;
+    let mut first_y = None;
+    let mut idle_y = None;
+
+    loop {
+        let mut index = 0;
+        let mut empty = 0;
+        let mut packets = 0;
+
+        while index < 50 {
+            let computer = &mut network[index];
+
+            match computer.run() {
+                State::Output(value) => {
+                    // Loop until we have accumulated a full packet of 3 values.
+                    sent.push(value);
+                    let [address, x, y] = sent[..] else {
+                        continue;
+                    };
+                    sent.clear();
+                    packets += 1;
+                    trace(Packet { from: index as i64, to: address, x, y });
+
+                    if address == NAT {
+                        // Handle part one.
+                        if first_y.is_none() {
+                            first_y = Some(y);
+                        }
+                        nat_x = x;
+                        nat_y = y;
+                    } else {
+                        let destination = &mut network[address as usize];
+                        destination.input(x);
+                        destination.input(y);
+                    }
+                }
+                // Input queue is empty.
+                State::Input => {
+                    empty += 1;
+                    computer.input(-1);
+                }
+                State::Halted => unreachable!(),
+            }
+
+            index += 1;
+        }
+
+        if empty == 50 && packets == 0 {
+            if idle_y == Some(nat_y) {
+                break;
+            }
+            idle_y = Some(nat_y);
+            trace(Packet { from: NAT, to: 0, x: nat_x, y: nat_y });
+
+            let destination = &mut network[0];
+            destination.input(nat_x);
+            destination.input(nat_y);
+        }
+    }
+
+    (first_y.unwrap(), idle_y.unwrap())
+}
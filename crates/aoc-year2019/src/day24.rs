@@ -30,91 +30,55 @@
 //! always zero.
 //!
 //! [`count_ones`]: u32::count_ones
-use crate::util::hash::*;
-
-const LEVEL: [u32; 25] = [
-    0b0000000000000000000100010,
-    0b0000000000000000001000101,
-    0b0000000000000000010001010,
-    0b0000000000000000100010100,
-    0b0000000000000001000001000,
-    0b0000000000000010001000001,
-    0b0000000000000100010100010,
-    0b0000000000001000101000100,
-    0b0000000000010001010001000,
-    0b0000000000100000100010000,
-    0b0000000001000100000100000,
-    0b0000000010001010001000000,
-    0b0000000100010100010000000,
-    0b0000001000101000100000000,
-    0b0000010000010001000000000,
-    0b0000100010000010000000000,
-    0b0001000101000100000000000,
-    0b0010001010001000000000000,
-    0b0100010100010000000000000,
-    0b1000001000100000000000000,
-    0b0001000001000000000000000,
-    0b0010100010000000000000000,
-    0b0101000100000000000000000,
-    0b1010001000000000000000000,
-    0b0100010000000000000000000,
-];
-
-const OUTER: [u32; 25] = [
-    0b0000000000000100010000000,
-    0b0000000000000000010000000,
-    0b0000000000000000010000000,
-    0b0000000000000000010000000,
-    0b0000000000010000010000000,
-    0b0000000000000100000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000010000000000000,
-    0b0000000000000100000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000010000000000000,
-    0b0000000000000100000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000010000000000000,
-    0b0000000100000100000000000,
-    0b0000000100000000000000000,
-    0b0000000100000000000000000,
-    0b0000000100000000000000000,
-    0b0000000100010000000000000,
-];
-
-const INNER: [u32; 25] = [
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000011111,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000100001000010000100001,
-    0b0000000000000000000000000,
-    0b1000010000100001000010000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b1111100000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-    0b0000000000000000000000000,
-];
+use aoc_util::hash::*;
+use aoc_util::lookup_table;
+
+/// Up to 4 neighbors on the same level.
+const LEVEL: [u32; 25] = lookup_table!(25, |i| {
+    let (x, y) = (i % 5, i / 5);
+    let mut mask = 0;
+    if x > 0 {
+        mask |= 1 << (i - 1);
+    }
+    if x < 4 {
+        mask |= 1 << (i + 1);
+    }
+    if y > 0 {
+        mask |= 1 << (i - 5);
+    }
+    if y < 4 {
+        mask |= 1 << (i + 5);
+    }
+    mask
+});
+
+/// Tiles on the outer edge are next to one of the 4 tiles around the center of the outer level.
+const OUTER: [u32; 25] = lookup_table!(25, |i| {
+    let (x, y) = (i % 5, i / 5);
+    let mut mask = 0;
+    if x == 0 {
+        mask |= 1 << 11;
+    }
+    if x == 4 {
+        mask |= 1 << 13;
+    }
+    if y == 0 {
+        mask |= 1 << 7;
+    }
+    if y == 4 {
+        mask |= 1 << 17;
+    }
+    mask
+});
+
+/// The 4 tiles around the center are next to an entire edge of the inner level.
+const INNER: [u32; 25] = lookup_table!(25, |i| match i {
+    7 => 0b0000000000000000000011111,
+    11 => 0b0000100001000010000100001,
+    13 => 0b1000010000100001000010000,
+    17 => 0b1111100000000000000000000,
+    _ => 0,
+});
 
 /// Parse the initial grid, placing the top left bug into the least significant bit of the result.
 pub fn parse(input: &str) -> u32 {
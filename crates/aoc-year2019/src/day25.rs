@@ -15,10 +15,11 @@
 //! layout and items.
 //!
 //! Just for fun this solution can be played interactively on the command line if
-//! "--features frivolity" is enabled.
+//! "--features frivolity" is enabled. The [`map`] function also returns every room discovered
+//! while exploring, together with its items and doors, for visualizing the ship.
 use super::intcode::*;
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 use std::fmt::Write as _;
 
 pub fn parse(input: &str) -> Vec<i64> {
@@ -33,6 +34,32 @@ pub fn part2(_input: &[i64]) -> &'static str {
     "n/a"
 }
 
+/// A room discovered while exploring the ship.
+pub struct Room {
+    pub name: String,
+    /// Every item in the room, including dangerous items that were left behind.
+    pub items: Vec<String>,
+    /// Direction of each door and the index of the room it leads to.
+    pub doors: Vec<(String, usize)>,
+}
+
+/// Explores the ship returning every room found. The first room is the starting point.
+pub fn map(input: &[i64]) -> Vec<Room> {
+    let mut computer = Computer::new(input);
+    let mut explorer = Explorer::default();
+    explore(&mut computer, &mut explorer, None);
+    explorer.rooms
+}
+
+/// State accumulated during the depth first search.
+#[derive(Default)]
+struct Explorer {
+    stack: Vec<String>,
+    path: Vec<String>,
+    inventory: Vec<String>,
+    rooms: Vec<Room>,
+}
+
 // Let a human play the game interactively.
 fn play_manually(input: &[i64]) -> String {
     use std::io::stdin;
@@ -65,7 +92,7 @@ fn play_manually(input: &[i64]) -> String {
 
 // Use ANSI codes to colorize the output to highlight the text.
 fn pretty_print(output: &str) {
-    use crate::util::ansi::*;
+    use aoc_util::ansi::*;
 
     let mut buffer = String::new();
     let mut item = GREEN;
@@ -89,12 +116,11 @@ fn pretty_print(output: &str) {
 
 fn play_automatically(input: &[i64]) -> String {
     let mut computer = Computer::new(input);
-    let mut stack = Vec::new();
-    let mut path = Vec::new();
-    let mut inventory = Vec::new();
+    let mut explorer = Explorer::default();
 
     // DFS through the ship, picking up all 8 safe items, then return to the starting point.
-    explore(&mut computer, &mut stack, &mut path, &mut inventory);
+    explore(&mut computer, &mut explorer, None);
+    let Explorer { mut path, inventory, .. } = explorer;
 
     // Retrace our path back to the Security Checkpoint.
     let last = path.pop().unwrap();
@@ -152,35 +178,42 @@ fn play_automatically(input: &[i64]) -> String {
     output
 }
 
-fn explore(
-    computer: &mut Computer,
-    stack: &mut Vec<String>,
-    path: &mut Vec<String>,
-    inventory: &mut Vec<String>,
-) {
-    let direction = stack.last().map_or("none", |d| d.as_str());
-    let reverse = opposite(direction);
+fn explore(computer: &mut Computer, explorer: &mut Explorer, parent: Option<usize>) {
+    let direction = explorer.stack.last().map_or("none", |d| d.as_str()).to_owned();
+    let reverse = opposite(&direction);
 
     let mut output = String::new();
-    movement_noisy(computer, direction, &mut output);
+    movement_noisy(computer, &direction, &mut output);
+
+    // Record the room, linking it with the room we came from.
+    let index = explorer.rooms.len();
+    let name = output.lines().find_map(|line| line.strip_prefix("== "));
+    let name = name.and_then(|n| n.strip_suffix(" ==")).unwrap_or_default().to_owned();
+    explorer.rooms.push(Room { name, items: Vec::new(), doors: Vec::new() });
+
+    if let Some(parent) = parent {
+        explorer.rooms[parent].doors.push((direction.clone(), index));
+        explorer.rooms[index].doors.push((reverse.to_owned(), parent));
+    }
 
     for line in output.lines() {
         if line.starts_with("== Pressure-Sensitive Floor ==") {
-            path.clone_from(stack);
+            explorer.path.clone_from(&explorer.stack);
             return;
         } else if let Some(suffix) = line.strip_prefix("- ") {
             if opposite(suffix) == "none" {
                 let item = String::from(suffix);
                 if !dangerous(&item) {
                     take_item(computer, &item);
-                    inventory.push(item);
+                    explorer.inventory.push(item.clone());
                 }
+                explorer.rooms[index].items.push(item);
             } else {
                 let direction = String::from(suffix);
                 if direction != reverse {
-                    stack.push(direction);
-                    explore(computer, stack, path, inventory);
-                    stack.pop();
+                    explorer.stack.push(direction);
+                    explore(computer, explorer, Some(index));
+                    explorer.stack.pop();
                 }
             }
         }
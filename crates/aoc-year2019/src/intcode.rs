@@ -9,6 +9,9 @@ pub enum State {
     Halted,
 }
 
+/// Cloning a computer takes a snapshot of its complete state, which is faster than parsing
+/// and converting the program from scratch when the same program is run many times.
+#[derive(Clone)]
 pub struct Computer {
     pc: usize,
     base: usize,
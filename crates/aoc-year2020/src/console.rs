@@ -0,0 +1,160 @@
+//! Handheld game console shared by puzzles that run or repair its boot code.
+//!
+//! The console has a single accumulator and three instructions. A program either halts by
+//! trying to execute an instruction just past the end, or loops forever. As there are no
+//! conditional jumps, the program loops exactly when it tries to execute any instruction a
+//! second time, which we detect with a bitset of visited instructions.
+//!
+//! Corrupted programs are fixed by swapping a single `jmp` for a `nop` or vice-versa.
+//! [`patches`] iterates over every candidate swap for brute force approaches. [`repair`] finds
+//! the fix in `O(n)` by working backwards from the end. An instruction *escapes* if following
+//! the unmodified program from it eventually halts. Walking the original looping path, the fix
+//! is the first swap that jumps to an escaping instruction (or straight off the end).
+//!
+//! The escaping path can't pass through the swapped instruction itself. If it did then the
+//! original instruction would also escape, so the original program would halt, which is a
+//! contradiction.
+//!
+//! ```
+//!   # use aoc_year2020::console::*;
+//!
+//!   let code = parse("nop +0\nacc +1\njmp -2\nacc +3");
+//!   assert_eq!(run(&code), Exit::Loop(1));
+//!   assert_eq!(run(&patch(&code, 2)), Exit::Halt(4));
+//!   assert_eq!(repair(&code), Some((2, 4)));
+//!   assert_eq!(patches(&code).collect::<Vec<_>>(), [0, 2]);
+//! ```
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Acc(i16),
+    Jmp(i16),
+    Nop(i16),
+}
+
+impl Instruction {
+    fn from([a, b]: [&str; 2]) -> Instruction {
+        let amount = b.signed();
+        match a {
+            "acc" => Instruction::Acc(amount),
+            "jmp" => Instruction::Jmp(amount),
+            "nop" => Instruction::Nop(amount),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Swaps `jmp` and `nop`, returning `None` for `acc` which can't be corrupted.
+    pub fn swapped(self) -> Option<Instruction> {
+        match self {
+            Instruction::Acc(_) => None,
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+        }
+    }
+
+    /// Address of the next instruction. Negative addresses wrap around so are also past the end.
+    #[inline]
+    fn next(self, pc: usize) -> usize {
+        match self {
+            Instruction::Jmp(arg) => pc.wrapping_add(arg as usize),
+            _ => pc + 1,
+        }
+    }
+}
+
+/// Value of the accumulator when the program stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exit {
+    Halt(i32),
+    Loop(i32),
+}
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input.split_ascii_whitespace().chunk::<2>().map(Instruction::from).collect()
+}
+
+/// Executes the program from the start until it halts or is about to repeat an instruction.
+pub fn run(code: &[Instruction]) -> Exit {
+    let mut visited = vec![0_u64; code.len().div_ceil(64)];
+    let mut pc = 0;
+    let mut acc = 0;
+
+    loop {
+        if pc >= code.len() {
+            break Exit::Halt(acc);
+        }
+
+        let (index, mask) = (pc / 64, 1 << (pc % 64));
+        if visited[index] & mask != 0 {
+            break Exit::Loop(acc);
+        }
+        visited[index] |= mask;
+
+        if let Instruction::Acc(arg) = code[pc] {
+            acc += arg as i32;
+        }
+        pc = code[pc].next(pc);
+    }
+}
+
+/// Copy of the program with the instruction at `index` swapped.
+pub fn patch(code: &[Instruction], index: usize) -> Vec<Instruction> {
+    let mut patched = code.to_vec();
+    if let Some(swapped) = code[index].swapped() {
+        patched[index] = swapped;
+    }
+    patched
+}
+
+/// Indices of every `jmp` or `nop` instruction that could be swapped.
+pub fn patches(code: &[Instruction]) -> impl Iterator<Item = usize> + '_ {
+    code.iter().enumerate().filter_map(|(index, op)| op.swapped().map(|_| index))
+}
+
+/// Finds the single swap that makes the program halt, returning its index and the final value of
+/// the accumulator.
+pub fn repair(code: &[Instruction]) -> Option<(usize, i32)> {
+    let size = code.len();
+
+    // Reverse every edge, treating all addresses past the end as a single extra vertex.
+    let mut incoming = vec![Vec::new(); size + 1];
+    for (pc, op) in code.iter().enumerate() {
+        incoming[op.next(pc).min(size)].push(pc);
+    }
+
+    let mut escapes = vec![false; size + 1];
+    let mut todo = vec![size];
+    escapes[size] = true;
+
+    while let Some(next) = todo.pop() {
+        for &pc in &incoming[next] {
+            if !escapes[pc] {
+                escapes[pc] = true;
+                todo.push(pc);
+            }
+        }
+    }
+
+    // Follow the original path, which visits each instruction at most once before looping.
+    let mut visited = vec![false; size];
+    let mut pc = 0;
+
+    while pc < size && !visited[pc] {
+        visited[pc] = true;
+
+        if let Some(swapped) = code[pc].swapped() {
+            if escapes[swapped.next(pc).min(size)] {
+                return match run(&patch(code, pc)) {
+                    Exit::Halt(acc) => Some((pc, acc)),
+                    Exit::Loop(_) => None,
+                };
+            }
+        }
+
+        pc = code[pc].next(pc);
+    }
+
+    None
+}
@@ -13,7 +13,7 @@
 //! Part 2 reuses the pair finding logic, finding the third element by stepping through the slice
 //! one by one and adjusting the target total. To reuse the array without reallocating
 //! (which is slow) we use a `round` value instead of `bool`.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<usize> {
     input.lines().map(|line| line.unsigned()).collect()
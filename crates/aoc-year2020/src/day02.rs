@@ -8,9 +8,9 @@
 //! array of size 4.
 //!
 //! [`split`]: slice::split
-//! [`chunk`]: crate::util::iter
-use crate::util::iter::*;
-use crate::util::parse::*;
+//! [`chunk`]: aoc_util::iter
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub struct Rule<'a> {
     start: usize,
@@ -3,9 +3,9 @@
 //! Two dimensional grids of ASCII characters are a common Advent of Code theme,
 //! so we use our utility [`Grid`] class to parse the data.
 //!
-//! [`Grid`]: crate::util::grid
-use crate::util::grid::*;
-use crate::util::point::*;
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 pub fn parse(input: &str) -> Grid<u8> {
     Grid::parse(input)
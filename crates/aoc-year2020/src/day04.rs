@@ -4,7 +4,7 @@
 //! problem. However as the principles of this crate are to avoid external dependencies and
 //! maximize speed we'll instead hand code validation functions for each of the
 //! passport field criteria.
-use crate::util::iter::*;
+use aoc_util::iter::*;
 use std::ops::RangeInclusive;
 
 type Passport<'a> = Vec<[&'a str; 2]>;
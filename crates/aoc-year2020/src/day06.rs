@@ -12,7 +12,7 @@
 //!
 //! Part two is very similar, except that we use a bitwise AND instead.
 //!
-//! [`day 5`]: crate::year2020::day05
+//! [`day 5`]: crate::day05
 //! [`count_ones`]: u32::count_ones
 
 pub fn parse(input: &str) -> Vec<u32> {
@@ -26,8 +26,8 @@
 //!
 //! Part one and part two are very similar. A recursive solution with memoization of previously
 //! seen values computes the result efficiently.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 const FIRST_HASH: [usize; 18] =
     [43, 63, 78, 86, 92, 95, 98, 130, 294, 320, 332, 390, 401, 404, 475, 487, 554, 572];
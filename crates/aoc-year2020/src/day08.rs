@@ -0,0 +1,30 @@
+//! # Handheld Halting
+//!
+//! The boot code runs on the handheld game console implemented in the [`console`] module.
+//!
+//! A brute force implementation that changes every `Jmp` or `Nop` in the input one at at time then
+//! tests the result would have `O(n²)` complexity for part two.
+//!
+//! We can solve part two in `O(n)` complexity instead. First we work backwards from the end of
+//! the program, finding every instruction that would eventually halt if executed. Then we step
+//! through the original looping path. The first `Jmp` or `Nop` that would land on one of these
+//! instructions when swapped is the corrupted instruction.
+//!
+//! [`console`]: crate::console
+use crate::console::{self, *};
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    console::parse(input)
+}
+
+pub fn part1(input: &[Instruction]) -> i32 {
+    match run(input) {
+        Exit::Loop(acc) => acc,
+        Exit::Halt(_) => unreachable!(),
+    }
+}
+
+pub fn part2(input: &[Instruction]) -> i32 {
+    let (_, acc) = repair(input).unwrap();
+    acc
+}
@@ -7,7 +7,7 @@
 //! in the size of the preamble.
 //!
 //! Part two uses a sliding search over a variable size window of the input.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Result = (u64, u64);
 
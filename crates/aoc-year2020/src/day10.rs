@@ -8,7 +8,7 @@
 //! arranged.
 //!
 //! [`windows`]: slice::windows
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<usize> {
     let mut adapters: Vec<_> = input.iter_unsigned().collect();
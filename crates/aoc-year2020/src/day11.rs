@@ -2,12 +2,14 @@
 //!
 //! Cellular automata are hard to speed up due to the need to check all neighbors each iteration.
 //! For both parts we minimize expensive memory allocation by creating only two temporary buffers
-//! then swapping between them each turn, a similar approach to double buffering.
+//! then swapping between them each turn, a similar approach to double buffering. The shared
+//! [`run_until_stable`] driver repeats rounds until no seat changes.
 //!
 //! For part two we can further optimize by precalculating the locations of the nearest visible
 //! seats only once then reusing that information for each step.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
+use aoc_util::stable::*;
 use std::mem::swap;
 
 const FLOOR: u8 = b'.';
@@ -84,12 +86,10 @@ pub fn simulate(input: &Grid<u8>, part_one: bool, limit: u8) -> u32 {
         }
     }
 
-    let mut current = vec![0; (width * height) as usize];
-    let mut next = vec![0; (width * height) as usize];
-    let mut change = true;
+    let buffers = (vec![0; (width * height) as usize], vec![0; (width * height) as usize]);
 
-    while change {
-        change = false;
+    let ((current, _), _) = run_until_stable(buffers, |(current, next)| {
+        let mut changes = 0;
 
         for seat in &seats {
             let index = seat.index as usize;
@@ -101,17 +101,18 @@ pub fn simulate(input: &Grid<u8>, part_one: bool, limit: u8) -> u32 {
 
             if current[index] == 0 && total == 0 {
                 next[index] = 1;
-                change |= true;
+                changes += 1;
             } else if current[index] == 1 && total >= limit {
                 next[index] = 0;
-                change |= true;
+                changes += 1;
             } else {
                 next[index] = current[index];
             }
         }
 
-        swap(&mut current, &mut next);
-    }
+        swap(current, next);
+        changes
+    });
 
     current.iter().map(|&n| n as u32).sum()
 }
@@ -2,9 +2,9 @@
 //!
 //! Our [`Point`] utility class comes in handy for this problem.
 //!
-//! [`Point`]: crate::util::point
-use crate::util::parse::*;
-use crate::util::point::*;
+//! [`Point`]: aoc_util::point
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 type Command = (u8, i32);
 
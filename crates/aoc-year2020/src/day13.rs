@@ -4,11 +4,13 @@
 //! The integers n₁, n₂, ... nₖ map to the bus ids which happen to be prime. This satisfies the
 //! requirement that the integers are [pairwise coprime](https://en.wikipedia.org/wiki/Coprime_integers#Coprimality_in_sets).
 //!
-//! For simplicity we use the "search by sieving" method. We start at zero with a step the size of
-//! the first integer. Then we search for each subsequent integer located at the correct offset of
-//! minutes and multiply the step by the new integer. This preserve the relative offset at each step
-//! in the next search.
-use crate::util::parse::*;
+//! Bus `id` at `offset` minutes needs a timestamp `t` where `t ≡ -offset (mod id)`. The shared
+//! [`crt`] helper combines these congruences one bus at a time. Ids that are not coprime still
+//! work as long as their offsets agree, otherwise there is no timestamp and an error is returned.
+//!
+//! [`crt`]: aoc_util::math::crt
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
 pub struct Input {
     timestamp: usize,
@@ -39,17 +41,19 @@ pub fn part1(input: &Input) -> usize {
 }
 
 pub fn part2(input: &Input) -> usize {
-    let (mut time, mut step) = input.buses[0];
-
-    for (offset, id) in &input.buses[1..] {
-        let remainder = id - offset % id;
-
-        while time % id != remainder {
-            time += step;
-        }
+    earliest_timestamp(&input.buses).unwrap()
+}
 
-        step *= id;
+/// Earliest timestamp where each bus departs at its offset, given `(offset, id)` pairs.
+///
+/// # Errors
+///
+/// Returns an error if bus ids share a factor and their offsets contradict each other.
+pub fn earliest_timestamp(buses: &[(usize, usize)]) -> Result<usize, String> {
+    let congruences = buses.iter().map(|&(offset, id)| (-(offset as i128), id as i128));
+
+    match crt(congruences) {
+        Some((time, _)) => Ok(time as usize),
+        None => Err("No timestamp satisfies every bus offset".to_owned()),
     }
-
-    time
 }
@@ -60,9 +60,9 @@
 //! addresses overlapped with zero others, a few with one and rarely with more than one.
 //! Benchmarking against the brute force solution showed that this approach is ~90x faster.
 //!
-//! [`FastMap`]: crate::util::hash
-use crate::util::hash::*;
-use crate::util::parse::*;
+//! [`FastMap`]: aoc_util::hash
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 #[derive(Copy, Clone)]
 pub enum Instruction {
@@ -9,10 +9,10 @@
 //!
 //! Accessing the map uses the [`Entry`] method as this reduces two key lookups to one.
 //!
-//! [`FastMap`]: crate::util::hash
+//! [`FastMap`]: aoc_util::hash
 //! [`Entry`]: std::collections::hash_map::Entry
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 const THRESHOLD: usize = 1_000_000;
 
@@ -14,8 +14,8 @@
 //! only belong to this column, we eliminate it from other columns. This causes a chain reaction
 //! where a second column will reduce to only one rule, continuing until all columns have been
 //! resolved.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Result = (u32, u64);
 type Ticket = Vec<u32>;
@@ -11,8 +11,8 @@
 //! A `HashSet` is generally a good choice for very sparse infinite grids, however for this
 //! problem we'll pack all dimensions into a single `vec` to achieve a five times increase
 //! in lookup speed.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 /// x and y dimensions are in the plane of the input. Each dimension can expand at most two in each
 /// axis per round (one positive and one negative). Adding padding at the edges to avoid boundary
@@ -37,7 +37,7 @@ pub mod stride {
 
 /// Use our utility [`Grid`] method to parse the input.
 ///
-/// [`Grid`]: crate::util::grid::Grid
+/// [`Grid`]: aoc_util::grid::Grid
 pub fn parse(input: &str) -> Grid<u8> {
     Grid::parse(input)
 }
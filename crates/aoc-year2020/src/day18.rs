@@ -0,0 +1,120 @@
+//! # Operation Order
+//!
+//! Both parts are the same problem with different operator precedence, so we share a single
+//! evaluator parameterized by a precedence table. For part one `+` and `*` have equal precedence
+//! and so are evaluated left to right. For part two `+` binds more tightly than `*`.
+//!
+//! The [`Evaluator`] is a variant of the
+//! [shunting yard algorithm](https://en.wikipedia.org/wiki/Shunting_yard_algorithm) that
+//! computes values immediately instead of producing output in reverse Polish notation. Numbers
+//! are pushed onto a value stack. Before pushing an operator, any operators already on the stack
+//! with greater or equal precedence are applied first, which makes evaluation left associative.
+//! Parentheses act as a barrier on the operator stack. Using explicit stacks instead of recursion
+//! means that deeply nested expressions can't overflow the call stack.
+//!
+//! Tokens are read with the shared [`Cursor`], which skips the whitespace between them.
+//!
+//! [`Cursor`]: aoc_util::parse::Cursor
+use aoc_util::parse::*;
+
+/// Addition and multiplication have the same precedence.
+pub const LEFT_TO_RIGHT: [(u8, u8); 2] = [(b'+', 1), (b'*', 1)];
+/// Addition is evaluated before multiplication.
+pub const ADDITION_FIRST: [(u8, u8); 2] = [(b'+', 2), (b'*', 1)];
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> u64 {
+    let mut evaluator = Evaluator::new(&LEFT_TO_RIGHT);
+    input.iter().map(|line| evaluator.evaluate(line)).sum()
+}
+
+pub fn part2(input: &[&str]) -> u64 {
+    let mut evaluator = Evaluator::new(&ADDITION_FIRST);
+    input.iter().map(|line| evaluator.evaluate(line)).sum()
+}
+
+/// Evaluates a single expression, see [`Evaluator`].
+pub fn evaluate(expression: &str, precedence: &[(u8, u8)]) -> u64 {
+    Evaluator::new(precedence).evaluate(expression)
+}
+
+/// Evaluates expressions of unsigned numbers, `+`, `*` and parentheses. The stacks are reused
+/// between expressions to avoid allocating for every line.
+pub struct Evaluator {
+    /// Level of each operator indexed by its byte. Zero marks a byte that isn't an operator,
+    /// which also makes `(` a barrier as every operator has a higher level.
+    levels: [u8; 256],
+    values: Vec<u64>,
+    operators: Vec<u8>,
+}
+
+impl Evaluator {
+    /// `precedence` maps each operator to its level, where higher levels are applied first.
+    /// Operators with the same level are applied left to right. Levels must be at least one.
+    pub fn new(precedence: &[(u8, u8)]) -> Self {
+        let mut levels = [0; 256];
+        for &(operator, level) in precedence {
+            assert!(level > 0, "Level of {} must be at least one", operator as char);
+            levels[operator as usize] = level;
+        }
+        Evaluator { levels, values: Vec::new(), operators: Vec::new() }
+    }
+
+    pub fn evaluate(&mut self, expression: &str) -> u64 {
+        let Evaluator { levels, values, operators } = self;
+        let mut cursor = Cursor::new(expression);
+
+        values.clear();
+        operators.clear();
+
+        while let Some(byte) = cursor.peek() {
+            match byte {
+                b'(' => {
+                    cursor.take();
+                    operators.push(b'(');
+                }
+                b')' => {
+                    cursor.take();
+                    while let Some(operator) = operators.pop().filter(|&op| op != b'(') {
+                        apply(values, operator);
+                    }
+                }
+                b'0'..=b'9' => values.push(cursor.unsigned()),
+                operator => {
+                    cursor.take();
+                    let level = levels[operator as usize];
+                    assert!(level > 0, "Unknown operator {}", operator as char);
+
+                    while let Some(&top) = operators.last() {
+                        if levels[top as usize] < level {
+                            break;
+                        }
+                        operators.pop();
+                        apply(values, top);
+                    }
+                    operators.push(operator);
+                }
+            }
+        }
+
+        while let Some(operator) = operators.pop() {
+            apply(values, operator);
+        }
+
+        values[0]
+    }
+}
+
+/// Replaces the top two values with the result of `operator`.
+fn apply(values: &mut Vec<u64>, operator: u8) {
+    let right = values.pop().unwrap();
+    let left = values.last_mut().unwrap();
+    if operator == b'+' {
+        *left += right;
+    } else {
+        *left *= right;
+    }
+}
@@ -51,8 +51,8 @@
 //!
 //! Since we don't need to handle the general input case (a common pattern in Advent of Code) we can
 //! implement this rule directly in code.
-use crate::util::parse::*;
 use Rule::*;
+use aoc_util::parse::*;
 
 #[derive(Clone, Copy)]
 pub enum Rule {
@@ -33,7 +33,9 @@
 //!
 //! The complete picture is stored as an array of `u128` values as the tiles form a square 12 wide,
 //! for a total of 12 * 8 = 96 pixels. As we add each tile, we convert its pixels into a `u8` binary
-//! number and left shift to add to the existing pixels.
+//! number and left shift to add to the existing pixels. The size of the square is discovered while
+//! assembling, so any image up to 16 tiles wide (128 pixels) is supported. The [`assemble`]
+//! function exposes the finished picture as a [`Grid`] for other consumers.
 //!
 //! When finding the monsters we make some further assumptions about the input:
 //!
@@ -42,7 +44,11 @@
 //!
 //! For speed the monster bit patterns are rotated and flipped instead of the image, then stored
 //! in hardcoded arrays. The search ends as soon as we find monsters in any orientation.
-use crate::util::parse::*;
+//!
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::array::from_fn;
 
 pub struct Tile {
@@ -156,11 +162,84 @@ pub fn part1(input: &[Tile]) -> u64 {
 }
 
 pub fn part2(input: &[Tile]) -> u32 {
+    let image = assemble_rows(input);
+    let size = image.len();
+
+    // Common search logic
+    let sea: u32 = image.iter().map(|n| n.count_ones()).sum();
+    let find = |monster: &mut [u128], width: usize, height: usize| {
+        let mut rough = sea;
+
+        for _ in 0..(size - width + 1) {
+            for window in image.windows(height) {
+                if monster.iter().enumerate().all(|(i, &n)| n & window[i] == n) {
+                    rough -= 15;
+                }
+            }
+            monster.iter_mut().for_each(|n| *n <<= 1);
+        }
+
+        (rough < sea).then_some(rough)
+    };
+
+    // Transform the monsters instead of the image.
+    // Hardcoded bit patterns for [O, H, V, HV].
+    let mut monsters = [
+        [0b00000000000000000010, 0b10000110000110000111, 0b01001001001001001000],
+        [0b01001001001001001000, 0b10000110000110000111, 0b00000000000000000010],
+        [0b01000000000000000000, 0b11100001100001100001, 0b00010010010010010010],
+        [0b00010010010010010010, 0b11100001100001100001, 0b01000000000000000000],
+    ];
+
+    for monster in &mut monsters {
+        if let Some(rough) = find(monster, 20, 3) {
+            return rough;
+        }
+    }
+
+    // Hardcoded bit patterns [R, RH, RV, RHV].
+    let mut monsters = [
+        [2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2, 3, 2],
+        [2, 3, 2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2],
+        [2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 6, 2],
+        [2, 6, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2],
+    ];
+
+    for monster in &mut monsters {
+        if let Some(rough) = find(monster, 3, 20) {
+            return rough;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Assembles the jigsaw then converts the picture to a grid of `#` and `.` characters,
+/// with tile borders removed.
+pub fn assemble(input: &[Tile]) -> Grid<u8> {
+    let image = assemble_rows(input);
+    let size = image.len() as i32;
+    let mut grid = Grid::new(size, size, b'.');
+
+    for (y, row) in image.iter().enumerate() {
+        for x in 0..size {
+            if row & (1 << (size - 1 - x)) != 0 {
+                grid[Point::new(x, y as i32)] = b'#';
+            }
+        }
+    }
+
+    grid
+}
+
+/// Each row of the picture is stored as bits in a `u128` with the leftmost pixel in the most
+/// significant position.
+fn assemble_rows(input: &[Tile]) -> Vec<u128> {
     // Store mapping of tile edges to tile index in order to allow
     // constant time lookup by edge when assembling the jigsaw.
     let mut edge_to_tile = [[0; 2]; 1024];
     let mut frequency = [0; 1024];
-    let mut placed = [false; 1024];
+    let mut placed = vec![false; input.len()];
 
     for (i, tile) in input.iter().enumerate() {
         for edge in tile.top {
@@ -189,10 +268,12 @@ pub fn part2(input: &[Tile]) -> u32 {
 
     // Assemble the image
     let mut next_top = find_arbitrary_corner();
-    let mut image = [0; 96];
+    let mut image = Vec::new();
     let mut index = 0;
 
     while frequency[next_top] == 2 {
+        image.resize(index + 8, 0);
+
         let tile = find_matching_tile(next_top);
         let permutation = (0..8).position(|i| tile.top[i] == next_top).unwrap();
         tile.transform(&mut image[index..], permutation);
@@ -210,51 +291,5 @@ pub fn part2(input: &[Tile]) -> u32 {
         index += 8;
     }
 
-    // Common search logic
-    let sea: u32 = image.iter().map(|n| n.count_ones()).sum();
-    let find = |monster: &mut [u128], width: usize, height: usize| {
-        let mut rough = sea;
-
-        for _ in 0..(96 - width + 1) {
-            for window in image.windows(height) {
-                if monster.iter().enumerate().all(|(i, &n)| n & window[i] == n) {
-                    rough -= 15;
-                }
-            }
-            monster.iter_mut().for_each(|n| *n <<= 1);
-        }
-
-        (rough < sea).then_some(rough)
-    };
-
-    // Transform the monsters instead of the image.
-    // Hardcoded bit patterns for [O, H, V, HV].
-    let mut monsters = [
-        [0b00000000000000000010, 0b10000110000110000111, 0b01001001001001001000],
-        [0b01001001001001001000, 0b10000110000110000111, 0b00000000000000000010],
-        [0b01000000000000000000, 0b11100001100001100001, 0b00010010010010010010],
-        [0b00010010010010010010, 0b11100001100001100001, 0b01000000000000000000],
-    ];
-
-    for monster in &mut monsters {
-        if let Some(rough) = find(monster, 20, 3) {
-            return rough;
-        }
-    }
-
-    // Hardcoded bit patterns [R, RH, RV, RHV].
-    let mut monsters = [
-        [2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2, 3, 2],
-        [2, 3, 2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2, 2, 4, 0, 0, 4, 2],
-        [2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 6, 2],
-        [2, 6, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1, 2],
-    ];
-
-    for monster in &mut monsters {
-        if let Some(rough) = find(monster, 3, 20) {
-            return rough;
-        }
-    }
-
-    unreachable!()
+    image
 }
@@ -42,9 +42,9 @@
 //! on a `usize` to compute set addition and subtraction. To add to a set use OR `|`,
 //! to remove use AND `&` and to calculate the size use [`count_ones`].
 //!
-//! [`Day 16`]: crate::year2020::day16
+//! [`Day 16`]: crate::day16
 //! [`count_ones`]: u32::count_ones
-use crate::util::hash::*;
+use aoc_util::hash::*;
 use std::collections::BTreeMap;
 
 pub struct Input<'a> {
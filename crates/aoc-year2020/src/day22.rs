@@ -55,8 +55,8 @@
 //! the combined hash of both decks, with a very good probability of no collisions.
 //!
 //! [`VecDeque`]: std::collections::VecDeque
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 type Input = (Deck, Deck);
 type Cache = Vec<FastSet<(usize, usize)>>;
@@ -1,6 +1,6 @@
 //! # Lobby Layout
 //!
-//! Hex grid parsing and navigation uses
+//! Hex grid parsing and navigation uses the shared [`hex`] module with
 //! [Axial Coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-cube)
 //! exactly as described in the excellent [Red Blob Games](https://www.redblobgames.com/) blog.
 //!
@@ -10,45 +10,17 @@
 //! a "pull" model where we check the surroundings neighbors of each tile, to a "push" model
 //! where we update the neighbors of each black tile instead.
 //!
-//! [`day 17`]: crate::year2020::day17
-use crate::util::hash::*;
-use std::array::from_fn;
-
-#[derive(PartialEq, Eq, Hash)]
-pub struct Hex {
-    q: i32,
-    r: i32,
-}
+//! [`day 17`]: crate::day17
+//! [`hex`]: aoc_util::hex
+use aoc_util::hash::*;
+use aoc_util::hex::*;
 
 pub fn parse(input: &str) -> FastSet<Hex> {
     let mut tiles = FastSet::new();
 
     for line in input.lines() {
-        let mut iter = line.bytes();
-        let mut q = 0;
-        let mut r = 0;
-
-        while let Some(b) = iter.next() {
-            match b {
-                b'e' => q += 1,
-                b'w' => q -= 1,
-                b'n' => {
-                    if b'e' == iter.next().unwrap() {
-                        q += 1;
-                    }
-                    r -= 1;
-                }
-                b's' => {
-                    if b'e' != iter.next().unwrap() {
-                        q -= 1;
-                    }
-                    r += 1;
-                }
-                _ => unreachable!(),
-            }
-        }
+        let tile = pointy(line).fold(ORIGIN, |hex, step| hex + step);
 
-        let tile = Hex { q, r };
         if tiles.contains(&tile) {
             tiles.remove(&tile);
         } else {
@@ -81,8 +53,7 @@ pub fn part2(input: &FastSet<Hex>) -> usize {
     // 2 * (100 generations + 1 buffer) + Origin = 203 extra in each dimension
     let width = q2 - q1 + 203;
     let height = r2 - r1 + 203;
-    let neighbors: [i32; 6] = [-1, 1, -width, width, 1 - width, width - 1];
-    let neighbors: [usize; 6] = from_fn(|i| neighbors[i] as usize);
+    let neighbors = NEIGHBORS.map(|hex| (width * hex.r + hex.q) as usize);
 
     let mut active = Vec::with_capacity(5_000);
     let mut candidates = Vec::with_capacity(5_000);
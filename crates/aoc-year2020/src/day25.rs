@@ -8,10 +8,10 @@
 //! The common encryption key is then calculated efficiently by
 //! [modular exponentiation](https://en.wikipedia.org/wiki/Modular_exponentiation) using
 //! [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring).
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::math::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> [u64; 2] {
     input.iter_unsigned().chunk::<2>().next().unwrap()
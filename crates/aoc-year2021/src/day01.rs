@@ -16,7 +16,7 @@
 //! size 4 and compare the first and last elements, without having to sum intermediate elements.
 //!
 //! [`windows`]: slice::windows
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<u32> {
     input.iter_unsigned().collect()
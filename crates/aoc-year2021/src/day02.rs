@@ -5,8 +5,8 @@
 //! [`Sub`] for convenience.
 //!
 //! [`fold`]: Iterator::fold
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 #[derive(Clone, Copy)]
 pub enum Sub {
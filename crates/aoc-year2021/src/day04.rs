@@ -0,0 +1,103 @@
+//! # Giant Squid
+//!
+//! We use a trick to immediately calculate the winning turn and score for each board without
+//! simulating the game.
+//!
+//! First we create a bidirectional map between each number and turn that it's drawn. Since the
+//! numbers are at most 2 digits we can use a fixed size array instead of a `HashMap` for speed.
+//! Numbers that are never drawn map to `usize::MAX`.
+//!
+//! Then for each column and row within a board, map each number to a turn and take the maximum
+//! value. This is the turn that the row or column will win. Then take the *minimum* of
+//! these maximum values. This is the turn that the entire board will win, calculated by
+//! [`win_turn`].
+//!
+//! Filtering the board numbers by turn and a reverse lookup from turn to number gives the
+//! score for each board. Boards are scored in parallel, each worker keeping only the earliest and
+//! latest winner that it has seen, so the answers for part one and part two come from a single
+//! pass without sorting.
+use aoc_util::parse::*;
+use aoc_util::thread::*;
+use std::array::from_fn;
+use std::sync::Mutex;
+
+pub type Board = [usize; 25];
+
+/// Winning turn and score of the first and last boards to win.
+pub struct Input {
+    first: (usize, usize),
+    last: (usize, usize),
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut to_turn = [usize::MAX; 100];
+    let mut from_turn = [0; 100];
+
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+
+    for (i, n) in prefix.iter_unsigned().enumerate() {
+        to_turn[n] = i;
+        from_turn[i] = n;
+    }
+
+    let boards: Vec<Board> = suffix
+        .split("\n\n")
+        .map(|chunk| {
+            let mut iter = chunk.iter_unsigned();
+            from_fn(|_| iter.next().unwrap())
+        })
+        .collect();
+
+    let result = Mutex::new(((usize::MAX, 0), (0, 0)));
+    spawn_parallel_iterator(&boards, |iter| worker(&to_turn, &from_turn, &result, iter));
+
+    let (first, last) = result.into_inner().unwrap();
+    Input { first, last }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.first.1
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.last.1
+}
+
+/// Index of the draw that completes a row or column of the board, or `usize::MAX` if the board
+/// never wins. `to_turn` maps each number to the index when it's drawn.
+pub fn win_turn(board: &Board, to_turn: &[usize; 100]) -> usize {
+    let turns: [usize; 25] = from_fn(|i| to_turn[board[i]]);
+
+    let rows = turns.chunks_exact(5).map(|row| row.iter().max().unwrap());
+    let cols = (0..5).map(|col| turns.iter().skip(col).step_by(5).max().unwrap());
+    *rows.chain(cols).min().unwrap()
+}
+
+type Winners = ((usize, usize), (usize, usize));
+
+fn worker(
+    to_turn: &[usize; 100],
+    from_turn: &[usize; 100],
+    result: &Mutex<Winners>,
+    iter: ParIter<'_, Board>,
+) {
+    let mut first = (usize::MAX, 0);
+    let mut last = (0, 0);
+
+    for board in iter {
+        let turn = win_turn(board, to_turn);
+        if turn == usize::MAX {
+            continue;
+        }
+
+        let unmarked: usize = board.iter().filter(|&&n| to_turn[n] > turn).sum();
+        let score = unmarked * from_turn[turn];
+
+        first = first.min((turn, score));
+        last = last.max((turn, score));
+    }
+
+    let mut result = result.lock().unwrap();
+    result.0 = result.0.min(first);
+    result.1 = result.1.max(last);
+}
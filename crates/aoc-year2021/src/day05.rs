@@ -0,0 +1,140 @@
+//! # Hydrothermal Venture
+//!
+//! When every coordinate is less than 1000 we create an 1 dimensional arrray of 1 million `u8`
+//! elements to store all possible points then increment values for each line. This assumes that
+//! no lines cross more than 255 times. This approach is much faster but less flexible than using
+//! a `HashMap` to store mappings of point to values.
+//!
+//! To avoid the overhead of a nested 2 dimensional array, each point `(x, y)` is mapped to
+//! an index `y * 1000 + x`. For each line direction the index delta is calculated using
+//! the handy [`signum`] function.
+//!
+//! Larger coordinates would need an impractically large grid, so instead we sweep each row
+//! separately. A horizontal line is an interval on a single row, while vertical and diagonal
+//! lines cross each row at a single point. Each row becomes a list of events, `+1` where
+//! coverage starts and `-1` just after it ends, like a
+//! [difference array](https://en.wikipedia.org/wiki/Prefix_sum). Sorting the events by `x` then
+//! keeping a running total finds the length of every stretch covered by two or more lines.
+//! Only rows that contain a line are stored, so the work depends on the length of the vertical
+//! and diagonal lines instead of the area of the bounding box.
+//!
+//! [`signum`]: i32::signum
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+
+/// Line from `x1,y1` to `x2,y2`.
+pub type Vent = [u32; 4];
+
+/// Coordinates must be strictly less than this to use the dense grid.
+const SIZE: u32 = 1000;
+
+pub fn parse(input: &str) -> [usize; 2] {
+    let all: Vec<_> = input.iter_unsigned().chunk::<4>().collect();
+
+    if !fits(&all) {
+        return [count_overlaps(&all, false), count_overlaps(&all, true)];
+    }
+
+    let (orthogonal, diagonal): (Vec<_>, Vec<_>) = all.iter().partition(|line| is_orthogonal(line));
+
+    let mut grid = vec![0_u8; (SIZE * SIZE) as usize];
+    let first = vents(&orthogonal, &mut grid);
+    let second = vents(&diagonal, &mut grid);
+
+    [first, first + second]
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    input[0]
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    input[1]
+}
+
+/// Number of points where at least two lines overlap, choosing the dense grid or sparse row
+/// sweep depending on the range of coordinates.
+pub fn count_overlaps(lines: &[Vent], include_diagonals: bool) -> usize {
+    let lines: Vec<_> =
+        lines.iter().copied().filter(|line| include_diagonals || is_orthogonal(line)).collect();
+
+    if fits(&lines) {
+        let mut grid = vec![0_u8; (SIZE * SIZE) as usize];
+        vents(&lines, &mut grid)
+    } else {
+        sweep(&lines)
+    }
+}
+
+fn is_orthogonal(&[x1, y1, x2, y2]: &Vent) -> bool {
+    x1 == x2 || y1 == y2
+}
+
+fn fits(lines: &[Vent]) -> bool {
+    lines.iter().flatten().all(|&n| n < SIZE)
+}
+
+fn vents(input: &[Vent], grid: &mut [u8]) -> usize {
+    let mut result = 0;
+    let size = SIZE as i32;
+
+    for &[x1, y1, x2, y2] in input {
+        let (x1, y1, x2, y2) = (x1 as i32, y1 as i32, x2 as i32, y2 as i32);
+        let count = (y2 - y1).abs().max((x2 - x1).abs());
+        let delta = (y2 - y1).signum() * size + (x2 - x1).signum();
+        let mut index = y1 * size + x1;
+
+        for _ in 0..=count {
+            if grid[index as usize] == 1 {
+                result += 1;
+            }
+            grid[index as usize] += 1;
+            index += delta;
+        }
+    }
+
+    result
+}
+
+fn sweep(input: &[Vent]) -> usize {
+    let mut rows: FastMap<i64, Vec<(i64, i32)>> = FastMap::new();
+
+    for &[x1, y1, x2, y2] in input {
+        let (x1, y1, x2, y2) = (x1 as i64, y1 as i64, x2 as i64, y2 as i64);
+
+        if y1 == y2 {
+            let events = rows.entry(y1).or_default();
+            events.push((x1.min(x2), 1));
+            events.push((x1.max(x2) + 1, -1));
+        } else {
+            let count = (y2 - y1).abs();
+            let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+
+            for i in 0..=count {
+                let events = rows.entry(y1 + i * dy).or_default();
+                events.push((x1 + i * dx, 1));
+                events.push((x1 + i * dx + 1, -1));
+            }
+        }
+    }
+
+    let mut result = 0;
+
+    for events in rows.values_mut() {
+        events.sort_unstable();
+
+        let mut coverage = 0;
+        let mut previous = 0;
+
+        for &(x, delta) in events.iter() {
+            if coverage >= 2 {
+                result += (x - previous) as usize;
+            }
+            coverage += delta;
+            previous = x;
+        }
+    }
+
+    result
+}
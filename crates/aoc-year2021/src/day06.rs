@@ -6,7 +6,7 @@
 //! Another optimization trick is rather than modifying the array by removing the fish at day 0,
 //! then shifting each fish total down by 1, we can simply increment what we consider the
 //! head of the array modulo 9 to achieve the same effect in place.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Input = [u64; 9];
 
@@ -6,7 +6,7 @@
 //! Part 2 is found by using the [mean](https://en.wikipedia.org/wiki/Mean).
 //! However since this could a floating point value and we are using integers we need to check
 //! 3 values total, the rounded result and one value on either side to ensure the correct answer.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<i32> {
     input.iter_signed().collect()
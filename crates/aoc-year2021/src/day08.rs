@@ -26,8 +26,8 @@
 //!   cardinality of the set is fixed, we can use an array instead of a `HashMap` for speed.
 //! * Add the occurences of each scrambled segment for each digit after the `|` symbol, then
 //!   lookup the total and map directly to the unscrambled digit.
-use crate::util::iter::*;
-use crate::util::slice::*;
+use aoc_util::iter::*;
+use aoc_util::slice::*;
 
 type Input = Vec<[u32; 4]>;
 
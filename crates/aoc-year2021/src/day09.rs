@@ -0,0 +1,52 @@
+//! # Smoke Basin
+//!
+//! Part 2 is the classic [flood fill](https://en.wikipedia.org/wiki/Flood_fill) algorithm with a
+//! twist to return the size of the filled area. Our utility [`Grid`] module labels each connected
+//! region and returns its size. Locations of height 9 form regions too, so these are ignored
+//! before finding the three largest basins.
+//!
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::heap::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<u8>) -> u32 {
+    let mut risk_levels = 0;
+
+    for x in 0..grid.width {
+        for y in 0..grid.height {
+            let point = Point::new(x, y);
+            let cur = grid[point];
+            let low_point = ORTHOGONAL
+                .iter()
+                .map(|&n| point + n)
+                .filter(|&n| grid.contains(n))
+                .all(|n| grid[n] > cur);
+
+            if low_point {
+                risk_levels += 1 + cur.to_decimal() as u32;
+            }
+        }
+    }
+
+    risk_levels
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    let (labels, mut sizes) = input.label_regions(|a, b| (a < b'9') == (b < b'9'));
+
+    // Regions of height 9 are the walls between basins.
+    for (&label, &height) in labels.bytes.iter().zip(&input.bytes) {
+        if height == b'9' {
+            sizes[label] = 0;
+        }
+    }
+
+    let basins: TopK<usize, 3> = sizes.into_iter().collect();
+    basins.as_slice().iter().product()
+}
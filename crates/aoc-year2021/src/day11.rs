@@ -5,7 +5,7 @@
 //! if an octopus has flashed this turn. Each time an octopus flashes it bumps its neighbors
 //! energy levels, which can propagate recursively through the entire grid.
 //!
-//! [`Day 9`]: crate::year2021::day09
+//! [`Day 9`]: crate::day09
 
 /// Pad the 10x10 grid by 1 on either side so that we can avoid boundary checks.
 type Input = [u8; 144];
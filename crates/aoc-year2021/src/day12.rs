@@ -0,0 +1,180 @@
+//! # Passage Pathing
+//!
+//! Our basic approach is a [DFS](https://en.wikipedia.org/wiki/Depth-first_search) through the cave
+//! system, exploring all possible permutations of the paths and finishing whenever we reach
+//! the `end` cave.
+//!
+//! To speed things up, 3 strategies are used:
+//! * Large caves are never directly connected to each other (otherwise there would be an
+//!   infinite number of paths) so we can *compact* the graph by removing them completely.
+//!   Each small cave is instead linked to other small caves by a weighted edge, with the weight
+//!   equal to the number of distinct routes between them.
+//! * [Memoization](https://en.wikipedia.org/wiki/Memoization) (or caching) of the possible paths
+//!   keyed on the current cave, set of visited small caves and whether a small cave has been
+//!   visited twice, to re-use work and save time.
+//! * [Bit Manipulation](https://en.wikipedia.org/wiki/Bit_manipulation) to store the set of
+//!   visited caves compressed into a single `u32`. Inputs have less than 16 small caves so the
+//!   cache is small.
+use aoc_util::bitset::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+
+const START: usize = 0;
+const END: usize = 1;
+
+/// Compacted graph containing only small caves.
+pub struct Input {
+    /// Number of small caves including `start` and `end`.
+    pub size: usize,
+    /// Adjacency matrix where `weights[size * from + to]` is the number of routes between caves.
+    pub weights: Vec<u32>,
+}
+
+struct State {
+    from: usize,
+    visited: u32,
+    twice: bool,
+}
+
+/// Parse the input then compact the graph.
+///
+/// First, each small cave is assigned a unique index, with `0` reserved for the `start` cave and
+/// `1` reserved for the `end` cave. Large caves are stored separately as a bitmask of their
+/// small neighbors. For example the sample input caves are:
+///
+/// | start | end | b | c | d |
+/// | :---: | :-: | - | - | - |
+/// |   0   |  1  | 2 | 3 | 4 |
+///
+/// with large cave `A` connected to `1111`.
+///
+/// Next each pair of small caves that are directly connected gains a weight of one. Then
+/// for each large cave, every pair of its neighbors gains a weight of one, *including* each
+/// neighbor with itself, as it's possible to walk into the large cave and back out again.
+/// The start cave can only be visited once at the beginning, so it is never used as a destination.
+/// For example the sample weights are:
+///
+/// | from  | start | end | b | c | d |
+/// | ----- | :---: | :-: | - | - | - |
+/// | start |   0   |  1  | 2 | 1 | 0 |
+/// | end   |   0   |  1  | 2 | 1 | 0 |
+/// | b     |   0   |  2  | 1 | 1 | 1 |
+/// | c     |   0   |  1  | 1 | 1 | 0 |
+/// | d     |   0   |  0  | 1 | 0 | 0 |
+pub fn parse(input: &str) -> Input {
+    let tokens: Vec<_> =
+        input.split(|c: char| !c.is_ascii_alphabetic()).filter(|s| !s.is_empty()).collect();
+    let is_small = |token: &str| token.starts_with(|c: char| c.is_ascii_lowercase());
+
+    let mut small = FastMap::build([("start", START), ("end", END)]);
+    let mut large = FastMap::new();
+
+    for &token in &tokens {
+        if is_small(token) {
+            if !small.contains_key(token) {
+                small.insert(token, small.len());
+            }
+        } else {
+            large.entry(token).or_insert(0_u32);
+        }
+    }
+
+    let size = small.len();
+    let mut weights = vec![0; size * size];
+
+    for [a, b] in tokens.into_iter().chunk::<2>() {
+        match (small.get(a), small.get(b)) {
+            (Some(&a), Some(&b)) => {
+                weights[size * a + b] += 1;
+                weights[size * b + a] += 1;
+            }
+            (Some(&a), None) => *large.get_mut(b).unwrap() |= 1 << a,
+            (None, Some(&b)) => *large.get_mut(a).unwrap() |= 1 << b,
+            (None, None) => unreachable!(),
+        }
+    }
+
+    for neighbors in large.into_values() {
+        for from in neighbors.biterator() {
+            for to in neighbors.biterator() {
+                weights[size * from + to] += 1;
+            }
+        }
+    }
+
+    // The start cave can't be revisited.
+    for from in 0..size {
+        weights[size * from + START] = 0;
+    }
+
+    Input { size, weights }
+}
+
+/// Explore the cave system visiting all small caves only once.
+pub fn part1(input: &Input) -> u32 {
+    explore(input, false)
+}
+
+/// Explore the cave system visiting a single small cave twice and the other small caves only once.
+pub fn part2(input: &Input) -> u32 {
+    explore(input, true)
+}
+
+/// Convenience method to create initial state.
+fn explore(input: &Input, twice: bool) -> u32 {
+    // Calculate the needed size of the cache as the product of:
+    // * 2 states for boolean "twice".
+    // * n states for the number of small caves including start and end.
+    // * 2^(n-2) states for the possible visited combinations, not including start and end cave.
+    let size = 2 * input.size * (1 << (input.size - 2));
+    let mut cache = vec![0; size];
+
+    let state = State { from: START, visited: 0, twice };
+    paths(input, &state, &mut cache)
+}
+
+/// Core recursive DFS logic.
+///
+/// First we check if we have seen this state before, returning early with the cached result.
+///
+/// Next we follow every weighted edge from our current location. Each path continuing from the
+/// next cave can be reached by `weight` different routes, so is multiplied by that amount.
+/// Paths that reach the `end` cave stop immediately.
+///
+/// The nuance is re-using the same code for both part 1 and part 2. First we check if we can visit
+/// a cave using the rules for part 1. If not, then we also check if the `twice` variable is
+/// still `true`. This variable allows a single second visit to a small cave. The expression
+/// `once && twice` sets this value to `false` whenever we need to use it to visit a small cave.
+fn paths(input: &Input, state: &State, cache: &mut [u32]) -> u32 {
+    let State { from, visited, twice } = *state;
+    let size = input.size;
+
+    // Calculate index by converting "twice" to either 1 or 0, then multiplying "from" by 2
+    // (the cardinality of "twice") and "visited" by "2 * size". The two least significant bits
+    // of "visited" for start and end cave are always 0, so can be shifted away.
+    let index = twice as usize + 2 * from + 2 * size * (visited as usize >> 2);
+    let total = cache[index];
+    if total > 0 {
+        return total;
+    }
+
+    let weights = &input.weights[size * from..size * (from + 1)];
+    let mut total = weights[END];
+
+    for (to, &weight) in weights.iter().enumerate().skip(2) {
+        if weight == 0 {
+            continue;
+        }
+
+        let mask = 1 << to;
+        let once = visited & mask == 0;
+
+        if once || twice {
+            let next = State { from: to, visited: visited | mask, twice: once && twice };
+            total += weight * paths(input, &next, cache);
+        }
+    }
+
+    cache[index] = total;
+    total
+}
@@ -9,10 +9,11 @@
 //! For part 2 we can determine the final size of the paper by taking the *last* x and y
 //! coordinates from the fold instructions. It's then faster and more convenienent to process
 //! each point completely and update the final location, than to step through intermediate folds.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 #[derive(Clone, Copy)]
 pub enum Fold {
@@ -48,11 +49,9 @@ pub fn parse(input: &str) -> Input {
 pub fn part1(input: &Input) -> usize {
     match input.folds[0] {
         Fold::Horizontal(x) => {
-            input.points.iter().map(|&p| fold_horizontal(x, p)).collect::<FastSet<_>>().len()
-        }
-        Fold::Vertical(y) => {
-            input.points.iter().map(|&p| fold_vertical(y, p)).collect::<FastSet<_>>().len()
+            input.points.iter().map(|p| p.fold_x(x)).collect::<FastSet<_>>().len()
         }
+        Fold::Vertical(y) => input.points.iter().map(|p| p.fold_y(y)).collect::<FastSet<_>>().len(),
     }
 }
 
@@ -71,40 +70,20 @@ pub fn part2(input: &Input) -> String {
         }
     }
 
-    let mut grid = vec![false; (width * height) as usize];
+    let mut grid = Grid::new(width, height, b'.');
 
     for point in &input.points {
         let mut point = *point;
 
         for &fold in &input.folds {
             point = match fold {
-                Fold::Horizontal(x) => fold_horizontal(x, point),
-                Fold::Vertical(y) => fold_vertical(y, point),
+                Fold::Horizontal(x) => point.fold_x(x),
+                Fold::Vertical(y) => point.fold_y(y),
             }
         }
 
-        grid[(point.y * width + point.x) as usize] = true;
-    }
-
-    let mut code = String::new();
-    for y in 0..height {
-        code.push('\n');
-        for x in 0..width {
-            let c = if grid[(y * width + x) as usize] { '#' } else { '.' };
-            code.push(c);
-        }
+        grid[point] = b'#';
     }
-    code
-}
-
-/// Fold point at `x` coordinate, doing nothing if the point is to the left of the fold line.
-#[inline]
-fn fold_horizontal(x: i32, p: Point) -> Point {
-    if p.x < x { p } else { Point::new(2 * x - p.x, p.y) }
-}
 
-/// Fold point at `y` coordinate, doing nothing if the point is above the fold line.
-#[inline]
-fn fold_vertical(y: i32, p: Point) -> Point {
-    if p.y < y { p } else { Point::new(p.x, 2 * y - p.y) }
+    format!("\n{}", grid.render())
 }
@@ -6,8 +6,8 @@
 //! Fixed sized arrays are used for speed as we know that the elements are limited to 26 values
 //! and the possible pairs to 26 * 26 values.
 //!
-//! [`Day 6`]: crate::year2021::day06
-use crate::util::iter::*;
+//! [`Day 6`]: crate::day06
+use aoc_util::iter::*;
 
 type Elements = [u64; 26];
 type Pairs = [u64; 26 * 26];
@@ -14,8 +14,14 @@
 //! Once we have processed the current risk level we clear the vec to avoid having to reallocate
 //! memory.
 //!
+//! Part two can either build the 5x larger grid, or compute the risk of any location on the fly
+//! from the original tile, saving the memory needed to materialize the larger grid. Both versions
+//! are exposed so that they can be benchmarked. Building the grid is faster, as the search then
+//! reads each risk directly instead of looking up its tile, so part two uses that version.
+//!
 //! [`BinaryHeap`]: std::collections::BinaryHeap
-use crate::util::parse::*;
+use aoc_util::grid::*;
+use aoc_util::parse::*;
 use std::array::from_fn;
 
 pub struct Square {
@@ -36,35 +42,55 @@ pub fn parse(input: &str) -> Square {
 
 /// Search the regular size grid.
 pub fn part1(input: &Square) -> usize {
-    dijkstra(input)
+    let Square { size, bytes } = input;
+    dijkstra(*size, |i, _, _| bytes[i])
 }
 
-/// Create an expanded grid then search.
 pub fn part2(input: &Square) -> usize {
-    let Square { size, bytes } = input;
+    materialized(input)
+}
 
-    let mut expanded = Square { size: 5 * size, bytes: vec![0; 25 * size * size] };
+/// Create an expanded grid then search.
+pub fn materialized(input: &Square) -> usize {
+    let Square { size, bytes } = input;
+    let expanded = 5 * size;
 
-    for (i, b) in bytes.iter().enumerate() {
-        let x1 = i % size;
-        let y1 = i / size;
-        let base = *b as usize;
+    // Repeat the original tile, then increase risk by the tile's distance from the top left.
+    let tile = Grid { width: *size as i32, height: *size as i32, bytes: bytes.clone() };
+    let mut tiled = tile.tiled(5).bytes;
 
-        for x2 in 0..5 {
-            for y2 in 0..5 {
-                let index = (5 * size) * (y2 * size + y1) + (x2 * size + x1);
-                expanded.bytes[index] = (1 + (base - 1 + x2 + y2) % 9) as u8;
-            }
-        }
+    for (i, b) in tiled.iter_mut().enumerate() {
+        let x = i % expanded / size;
+        let y = i / expanded / size;
+        *b = (1 + (*b as usize - 1 + x + y) % 9) as u8;
     }
 
-    dijkstra(&expanded)
+    dijkstra(expanded, |i, _, _| tiled[i])
+}
+
+/// Search the expanded grid without creating it, computing each risk from the original tile.
+pub fn implicit(input: &Square) -> usize {
+    let Square { size, bytes } = input;
+    let expanded = 5 * size;
+
+    // Look up the position within the tile and the tile's distance from the top left, to avoid a
+    // division for every neighbor. Risk is at most 9 + 8 so wraps around at most once.
+    let inner: Vec<_> = (0..expanded).map(|n| n % size).collect();
+    let tile: Vec<_> = (0..expanded).map(|n| (n / size) as u8).collect();
+
+    dijkstra(expanded, |_, x, y| {
+        let risk = bytes[inner[y] * size + inner[x]] + tile[x] + tile[y];
+        if risk > 9 { risk - 9 } else { risk }
+    })
 }
 
 /// Implementation of [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm)
-/// without using the decrease-key functionality.
-fn dijkstra(square: &Square) -> usize {
-    let Square { size, bytes } = square;
+/// without using the decrease-key functionality. The risk of entering each location is
+/// provided by the `risk_at` function, given both its index and coordinates.
+fn dijkstra<F>(size: usize, risk_at: F) -> usize
+where
+    F: Fn(usize, usize, usize) -> u8,
+{
     let edge = size - 1;
     let end = size * size - 1;
 
@@ -86,8 +112,8 @@ fn dijkstra(square: &Square) -> usize {
                 return risk;
             }
 
-            let mut check = |next: usize| {
-                let next_cost = risk as u16 + bytes[next] as u16;
+            let mut check = |next: usize, x: usize, y: usize| {
+                let next_cost = risk as u16 + risk_at(next, x, y) as u16;
                 if next_cost < cost[next] {
                     todo[(next_cost % 10) as usize].push(next as u32);
                     cost[next] = next_cost;
@@ -97,16 +123,16 @@ fn dijkstra(square: &Square) -> usize {
             let y = current / size;
 
             if x > 0 {
-                check(current - 1);
+                check(current - 1, x - 1, y);
             }
             if x < edge {
-                check(current + 1);
+                check(current + 1, x + 1, y);
             }
             if y > 0 {
-                check(current - size);
+                check(current - size, x, y - 1);
             }
             if y < edge {
-                check(current + size);
+                check(current + size, x, y + 1);
             }
         }
 
@@ -0,0 +1,147 @@
+//! # Trick Shot
+//!
+//! Although this problem is easy to brute force, we can apply some reasoning and simplify both parts.
+//!
+//! ## Part One
+//! Part one can be solved analytically. Movement upwards in the positive y direction is symmetrical.
+//! For example launching a probe at a y-velocity of 5 initially,
+//! would result in a  speed and y-position:
+//!
+//! ```text
+//!     Time:       0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12
+//!     Speed:      5,  4,  3,  2,  1,  0, -1, -2, -3, -4, -5, -6, -7
+//!     Y-Position: 0,  5,  9, 12, 14, 15, 15, 14, 12,  9,  5,  0, -6
+//! ```
+//!
+//! The maximum y velocity is reached when we *just* touch the target area on the way down at the
+//! bottom y-coordinate. For the example above, if the bottom y coordinate was -6 then the maximum
+//! initial upwards velocity is one less, our starting velocity of 5.
+//!
+//! The maximum height is `5 + 4 + 3 + 2 + 1`, which is the sum from 1 to n given by the formula for
+//! triangular numbers [`(n * (n + 1) / 2`](https://en.wikipedia.org/wiki/Triangular_number#Formula).
+//!
+//! ## Part Two
+//! A brute force solution would simulate every possible combination of `x` and `y` velocities.
+//! Instead we treat `x` and `y` independently and compute directly the range of steps `t` that
+//! each velocity spends inside the target area.
+//!
+//! After `t` steps a probe launched with velocity `v` is at position `tv - t(t - 1) / 2`.
+//! Setting this equal to a boundary gives the quadratic equation `t² - (2v + 1)t + 2k = 0`.
+//!
+//! * For `x` the probe moves right until it stops after `v` steps at triangular number
+//!   `v(v + 1) / 2`. The smaller root gives the first step reaching the left edge. If the
+//!   probe stops before the right edge then it stays in range forever.
+//! * For `y` the target is below the start, so the larger root gives the first step below
+//!   the top edge and the last step above the bottom edge.
+//!
+//! The integer square root could be off by one so each root is nudged to the exact step. A pair
+//! of velocities hits the target if their ranges of steps overlap.
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+
+type Input = [i32; 4];
+
+pub fn parse(input: &str) -> Input {
+    input.iter_signed().chunk::<4>().next().unwrap()
+}
+
+pub fn part1(input: &Input) -> i32 {
+    let &[_, _, bottom, _] = input;
+    let n = -(bottom + 1);
+    n * (n + 1) / 2
+}
+
+pub fn part2(input: &Input) -> usize {
+    hits(input).len()
+}
+
+/// Every initial velocity `(dx, dy)` that is inside the target area after some step.
+pub fn hits(input: &Input) -> Vec<(i32, i32)> {
+    let &[left, right, bottom, top] = input;
+    let (left, right, bottom, top) = (left as i64, right as i64, bottom as i64, top as i64);
+
+    let horizontal: Vec<_> = (1..=right)
+        .filter_map(|v| {
+            let stop = v * (v + 1) / 2;
+            if stop < left {
+                return None;
+            }
+
+            let first = first_step(smaller_root(v, left), 0, |t| position(v, t.min(v)) >= left);
+            let last = if stop <= right {
+                i64::MAX
+            } else {
+                let estimate = smaller_root(v, right + 1);
+                first_step(estimate, 0, |t| position(v, t.min(v)) > right) - 1
+            };
+
+            (first <= last).then_some((v, first, last))
+        })
+        .collect();
+
+    let vertical: Vec<_> = (bottom..-bottom)
+        .filter_map(|v| {
+            // Position is strictly decreasing after this step.
+            let start = v.max(0);
+            let first = first_step(larger_root(v, top), start, |t| position(v, t) <= top);
+            let last =
+                first_step(larger_root(v, bottom - 1), start, |t| position(v, t) < bottom) - 1;
+
+            (first <= last).then_some((v, first, last))
+        })
+        .collect();
+
+    let mut result = Vec::new();
+
+    for &(dx, x_first, x_last) in &horizontal {
+        for &(dy, y_first, y_last) in &vertical {
+            if x_first <= y_last && y_first <= x_last {
+                result.push((dx as i32, dy as i32));
+            }
+        }
+    }
+
+    result
+}
+
+/// Position after `t` steps, ignoring drag stopping horizontal movement.
+#[inline]
+fn position(v: i64, t: i64) -> i64 {
+    t * v - t * (t - 1) / 2
+}
+
+/// Approximate smaller root of `t² - (2v + 1)t + 2k = 0`.
+#[inline]
+fn smaller_root(v: i64, k: i64) -> i64 {
+    let b = 2 * v + 1;
+    (b - discriminant(b, k)) / 2
+}
+
+/// Approximate larger root of `t² - (2v + 1)t + 2k = 0`.
+#[inline]
+fn larger_root(v: i64, k: i64) -> i64 {
+    let b = 2 * v + 1;
+    (b + discriminant(b, k)) / 2
+}
+
+#[inline]
+fn discriminant(b: i64, k: i64) -> i64 {
+    (b * b - 8 * k).max(0).isqrt()
+}
+
+/// Nudges an estimated step to the smallest `t >= start` where the monotonic `predicate` is true.
+fn first_step<F>(estimate: i64, start: i64, predicate: F) -> i64
+where
+    F: Fn(i64) -> bool,
+{
+    let mut t = estimate.max(start);
+
+    while t > start && predicate(t - 1) {
+        t -= 1;
+    }
+    while !predicate(t) {
+        t += 1;
+    }
+
+    t
+}
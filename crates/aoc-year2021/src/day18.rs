@@ -27,7 +27,8 @@
 //! The root node is stored at index 0. For a node at index `i` its left child is at index
 //! `2i + 1`, right child at index `2i + 2` and parent at index `i / 2`. As leaf nodes are
 //! always greater than or equal to zero, `-1` is used as a special sentinel value for non-leaf nodes.
-use crate::util::thread::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicI32, Ordering};
 
 type Snailfish = [i32; 63];
@@ -41,27 +42,28 @@ const IN_ORDER: [usize; 30] = [
 
 /// Parse a snailfish number into an implicit binary tree stored in an array.
 ///
-/// Since no number will greater than 9 initially we can consider each character individually.
-/// `[` means moves down a level to parse children, `,` means move from left to right node,
-/// `]` means move up a level to return to parent and a digit from 0-9 creates a leaf node
-/// with that value.
+/// Each pair is parsed recursively, the left child going to index `2i + 1` and the right child
+/// to index `2i + 2`. Regular numbers are leaf nodes.
 pub fn parse(input: &str) -> Vec<Snailfish> {
-    fn helper(bytes: &[u8]) -> Snailfish {
-        let mut tree = [-1; 63];
-        let mut i = 0;
-
-        for &b in bytes {
-            match b {
-                b'[' => i = 2 * i + 1,
-                b',' => i += 1,
-                b']' => i = (i - 1) / 2,
-                b => tree[i] = (b - 48) as i32,
-            }
+    fn helper(cursor: &mut Cursor<'_>, tree: &mut Snailfish, i: usize) {
+        if cursor.eat(b'[') {
+            helper(cursor, tree, 2 * i + 1);
+            cursor.expect(b',');
+            helper(cursor, tree, 2 * i + 2);
+            cursor.expect(b']');
+        } else {
+            tree[i] = cursor.signed();
         }
-
-        tree
     }
-    input.lines().map(|line| helper(line.as_bytes())).collect()
+
+    input
+        .lines()
+        .map(|line| {
+            let mut tree = [-1; 63];
+            helper(&mut Cursor::new(line), &mut tree, 0);
+            tree
+        })
+        .collect()
 }
 
 /// Add all snailfish numbers, reducing to a single magnitude.
@@ -22,9 +22,9 @@
 //! An overlap indicates a potential match, but we need to confirm by checking the beacons against
 //! each other in two steps. First confirming orientation by matching the deltas between
 //! points, then by translating the beacons until 12 overlap.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 use std::ops::{Add, Sub};
 
 /// Stores coordinates in x, y, z order.
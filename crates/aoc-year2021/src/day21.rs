@@ -1,6 +1,6 @@
 //! # Dirac Dice
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Pair = (usize, usize);
 type State = (Pair, Pair);
@@ -26,8 +26,8 @@
 //!
 //! The complexity of this approach depends on how many cubes overlap. In my input most
 //! cubes overlapped with zero others, a few with one and rarely with more than one.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 /// Wraps a cube with on/off information.
 pub struct RebootStep {
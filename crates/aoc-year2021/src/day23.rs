@@ -16,8 +16,9 @@
 //!
 //! Thirdly low level bit manipulation is used to represent the burrow state size compactly
 //! in only 16 bytes for faster copying and hashing.
-use crate::util::hash::*;
-use crate::util::heap::*;
+use aoc_util::capacity::*;
+use aoc_util::hash::*;
+use aoc_util::heap::*;
 use std::array::from_fn;
 use std::hash::*;
 
@@ -157,7 +158,7 @@ pub fn part1(input: &[Vec<usize>]) -> usize {
         [C, C, input[3][7], input[2][7]],
         [D, D, input[3][9], input[2][9]],
     ]);
-    organize(burrow)
+    organize(burrow, 8)
 }
 
 /// Part two adds the middle amphipods as specified in the problem statement.
@@ -168,7 +169,7 @@ pub fn part2(input: &[Vec<usize>]) -> usize {
         [input[3][7], A, B, input[2][7]],
         [input[3][9], C, A, input[2][9]],
     ]);
-    organize(burrow)
+    organize(burrow, 16)
 }
 
 /// A* search over all possible burrow states until we find the lowest cost to organize.
@@ -181,9 +182,12 @@ pub fn part2(input: &[Vec<usize>]) -> usize {
 ///
 /// If no moves to home burrows are possible then the expand phase moves amphipods into the
 /// hallway.
-fn organize(burrow: Burrow) -> usize {
-    let mut todo = MinHeap::with_capacity(20_000);
-    let mut seen = FastMap::with_capacity(20_000);
+///
+/// The number of states explored grows with the number of amphipods that need to move.
+fn organize(burrow: Burrow, amphipods: usize) -> usize {
+    let capacity = estimate(amphipods, 1_250);
+    let mut todo = MinHeap::with_capacity(capacity);
+    let mut seen = FastMap::with_capacity(capacity);
 
     // Initial calculation of the heuristic is expensive but future updates will be cheap.
     todo.push(best_possible(&burrow), burrow);
@@ -81,8 +81,8 @@
 //!     mul y x     | y = (w + 4) * 0       | y = (w + 4) * 1           |
 //!     add z y     | z = z                 | z = (26 * z) * (w + 4)    |
 //! ```
-use crate::util::parse::*;
 use Block::*;
+use aoc_util::parse::*;
 
 /// Blocks are either "push" or "pop".
 enum Block {
@@ -15,6 +15,8 @@
 //!
 //! In the actual implementation `across` and `down` are stored separately so that we know
 //! which cucumbers turn it is to move. We bitwise OR both together to calculate any blockers.
+//! Inputs can be wider than 128 so each row needs two `u128` values. [`step`] advances a single
+//! generation so that the simulation can be tested and benchmarked one step at a time.
 use std::ops::{BitAnd, BitOr, Not};
 
 /// Duct tape two `u128` together to make a 256 bit wide integer.
@@ -33,6 +35,14 @@ impl U256 {
         }
     }
 
+    fn bit(&self, offset: usize) -> bool {
+        if offset < 128 {
+            self.right & (1 << offset) != 0
+        } else {
+            self.left & (1 << (offset - 128)) != 0
+        }
+    }
+
     fn non_zero(&self) -> bool {
         self.left != 0 || self.right != 0
     }
@@ -125,43 +135,73 @@ pub fn parse(input: &str) -> State {
 }
 
 pub fn part1(input: &State) -> usize {
-    let State { width, height, mut across, mut down } = input.clone();
-    let mut changed = true;
-    let mut count = 0;
+    let mut state = input.clone();
+    let mut count = 1;
 
-    while changed {
-        changed = false;
+    loop {
+        let (next, moved) = step(state);
+        if !moved {
+            break count;
+        }
+        state = next;
         count += 1;
+    }
+}
 
-        // Use the bitwise logic described above to process an entire row across at a time.
-        // Direction is reflected due to the parsing so we rotate left instead of right.
-        for i in 0..height {
-            let candidates = across[i].left_roll(width);
-            let moved = candidates & !(across[i] | down[i]);
-            changed |= moved.non_zero();
-            let stay = across[i] & !moved.right_roll(width);
-            across[i] = moved | stay;
-        }
+/// Moves every sea cucumber that can, first the `across` herd then the `down` herd.
+/// Returns the next state and whether any sea cucumber moved.
+pub fn step(mut state: State) -> (State, bool) {
+    let State { width, height, across, down } = &mut state;
+    let (width, height) = (*width, *height);
+    let mut changed = false;
+
+    // Use the bitwise logic described above to process an entire row across at a time.
+    // Direction is reflected due to the parsing so we rotate left instead of right.
+    for i in 0..height {
+        let candidates = across[i].left_roll(width);
+        let moved = candidates & !(across[i] | down[i]);
+        changed |= moved.non_zero();
+        let stay = across[i] & !moved.right_roll(width);
+        across[i] = moved | stay;
+    }
 
-        // Use a similar approach to handle an entire row down at a time.
-        let last_mask = across[0] | down[0];
-        let mut moved = down[height - 1] & !last_mask;
-
-        for i in 0..(height - 1) {
-            changed |= moved.non_zero();
-            let mask = across[i + 1] | down[i + 1];
-            let stay = down[i] & mask;
-            let next_moved = down[i] & !mask;
-            down[i] = moved | stay;
-            moved = next_moved;
-        }
+    // Use a similar approach to handle an entire row down at a time.
+    let last_mask = across[0] | down[0];
+    let mut moved = down[height - 1] & !last_mask;
 
+    for i in 0..(height - 1) {
         changed |= moved.non_zero();
-        let stay = down[height - 1] & last_mask;
-        down[height - 1] = moved | stay;
+        let mask = across[i + 1] | down[i + 1];
+        let stay = down[i] & mask;
+        let next_moved = down[i] & !mask;
+        down[i] = moved | stay;
+        moved = next_moved;
+    }
+
+    changed |= moved.non_zero();
+    let stay = down[height - 1] & last_mask;
+    down[height - 1] = moved | stay;
+
+    (state, changed)
+}
+
+/// Draws the sea cucumbers in the same format as the input.
+pub fn render(state: &State) -> String {
+    let mut result = String::with_capacity((state.width + 1) * state.height);
+
+    for (across, down) in state.across.iter().zip(&state.down) {
+        for offset in 0..state.width {
+            let c = match (across.bit(offset), down.bit(offset)) {
+                (true, _) => '>',
+                (_, true) => 'v',
+                _ => '.',
+            };
+            result.push(c);
+        }
+        result.push('\n');
     }
 
-    count
+    result
 }
 
 pub fn part2(_input: &State) -> &'static str {
@@ -0,0 +1,22 @@
+//! # Calorie Counting
+//! Sums groups of numbers separated by blank lines, keeping only the 3 highest totals with a
+//! [`TopK`] accumulator instead of sorting every elf.
+//!
+//! [`TopK`]: aoc_util::heap::TopK
+use aoc_util::heap::*;
+use aoc_util::parse::*;
+
+/// Parse and group lines.
+pub fn parse(input: &str) -> TopK<u32, 3> {
+    input.split("\n\n").map(|s| s.iter_unsigned::<u32>().sum()).collect()
+}
+
+/// The elf with the most calories is first.
+pub fn part1(input: &TopK<u32, 3>) -> u32 {
+    input.as_slice()[0]
+}
+
+/// Sum the calories of the 3 highest elves.
+pub fn part2(input: &TopK<u32, 3>) -> u32 {
+    input.as_slice().iter().sum()
+}
@@ -23,7 +23,7 @@
 //!   raw ASCII codes and not bother computing offsets until the very end.
 //!
 //! [`trailing_zeroes`]: u128
-use crate::util::iter::*;
+use aoc_util::iter::*;
 
 /// Collect each line into a `vec` of string slices.
 pub fn parse(input: &str) -> Vec<&str> {
@@ -16,8 +16,8 @@
 //! `a <= d && c <= b`
 //!
 //! to check when two ranges do overlap.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Pairs = [u32; 4];
 
@@ -3,8 +3,8 @@
 //! There are 2 main challenges to this problem:
 //! * Parsing the input!
 //! * (Rust Specific): The borrow checker prevents mutating a nested `vec` at 2 indices at once.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Stack = Vec<Vec<char>>;
 type Move = [usize; 3];
@@ -34,7 +34,7 @@
 //! This means that the algorithm is extremely efficient and the data structures are very
 //! straightforward. For example there's no need to store the current path names, or to recursively
 //! update upwards whenever a file is encountered.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 /// Tokenize the input and return a `vec` of directory sizes.
 pub fn parse(input: &str) -> Vec<u32> {
@@ -0,0 +1,145 @@
+//! # Rope Bridge
+//!
+//! This solution relies on the [`Point`] utility class. Two dimensional problems are common in
+//! Advent of Code, so having a decent `Point` (or `Coord` or `Pos`) class in your back pocket
+//! is handy.
+//!
+//! Visited tail positions are stored in a flat `vec` of `bool` sized to the extents of the head's
+//! path, as the tail can never move outside this bounding box.
+//!
+//! Each knot depends on the new position of the knot in front of it, so knots must be updated in
+//! order. The SIMD variant instead vectorizes the `x` and `y` components of each knot, computing
+//! the distance, clamped step and comparison for both axes at once.
+//! Both variants are public and the `compare` feature compiles them together for testing.
+use aoc_util::parse::*;
+use aoc_util::point::*;
+
+type Pair = (Point, i32);
+type Input = ([i32; 4], Vec<Pair>);
+
+/// Converts input lines into a pair of [`Point`] and integer amount, to indicate direction and
+/// magnitude respectively. Then determines the maximum extent of the head so that we can allocate
+/// a two dimensional grid.
+pub fn parse(input: &str) -> Input {
+    let first = input.bytes().filter(u8::is_ascii_alphabetic).map(Point::from);
+    let second = input.iter_signed::<i32>();
+    let pairs = first.zip(second).collect();
+
+    // Determine maximum extents, including the starting position.
+    let mut x1 = 0;
+    let mut y1 = 0;
+    let mut x2 = 0;
+    let mut y2 = 0;
+    let mut point = ORIGIN;
+
+    for &(step, amount) in &pairs {
+        point += step * amount;
+        x1 = x1.min(point.x);
+        y1 = y1.min(point.y);
+        x2 = x2.max(point.x);
+        y2 = y2.max(point.y);
+    }
+
+    ([x1, y1, x2, y2], pairs)
+}
+
+/// Simulate a rope length of 2
+pub fn part1(input: &Input) -> usize {
+    simulate::<2>(input)
+}
+
+/// Simulate a rope length of 10
+pub fn part2(input: &Input) -> usize {
+    simulate::<10>(input)
+}
+
+/// Simulates a rope of arbitrary length, returning the number of distinct tail positions.
+///
+/// The head knot always moves according the instructions from the problem input. Remaining knots
+/// move according to their delta from the head (2nd knot) or the previous knot
+/// (3rd and subsequent knots).
+///
+/// Using const generics for the rope length allows the compiler to optimize the loop, which is
+/// about twice as fast as the same loop over a rope stored in a `vec`.
+pub fn simulate<const N: usize>(input: &Input) -> usize {
+    let ([x1, y1, x2, y2], pairs) = input;
+    let width = x2 - x1 + 1;
+    let height = y2 - y1 + 1;
+    let start = Point::new(-x1, -y1);
+
+    let mut distinct = 0;
+    let mut rope = [start; N];
+    let mut grid = vec![false; (width * height) as usize];
+
+    for &(step, amount) in pairs {
+        for _ in 0..amount {
+            rope[0] += step;
+            for i in 1..N {
+                if !follow(rope[i - 1], &mut rope[i]) {
+                    break;
+                }
+            }
+
+            // Checking every step is faster than only checking when the tail moved, as whether
+            // it moved is hard for the branch predictor to guess.
+            let tail = rope[N - 1];
+            let index = (width * tail.y + tail.x) as usize;
+
+            if !grid[index] {
+                grid[index] = true;
+                distinct += 1;
+            }
+        }
+    }
+
+    // A rope of length one moves away from the start before its first check.
+    let index = (width * start.y + start.x) as usize;
+    distinct + usize::from(!grid[index])
+}
+
+/// Moves a knot one step towards the knot in front if they are not touching, that is the absolute
+/// distance in either x or y axes is greater than 1. Returns `true` if the knot moved.
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn follow(front: Point, knot: &mut Point) -> bool {
+    scalar::follow(front, knot)
+}
+
+#[cfg(feature = "simd")]
+#[inline]
+fn follow(front: Point, knot: &mut Point) -> bool {
+    simd::follow(front, knot)
+}
+
+#[cfg(any(not(feature = "simd"), feature = "compare"))]
+pub mod scalar {
+    use super::*;
+
+    #[inline]
+    pub fn follow(front: Point, knot: &mut Point) -> bool {
+        let apart = (front.x - knot.x).abs() > 1 || (front.y - knot.y).abs() > 1;
+        if apart {
+            *knot += front.signum(*knot);
+        }
+        apart
+    }
+}
+
+#[cfg(feature = "simd")]
+pub mod simd {
+    use super::*;
+    use std::simd::Simd;
+    use std::simd::cmp::SimdPartialOrd as _;
+    use std::simd::num::SimdInt as _;
+
+    #[inline]
+    pub fn follow(front: Point, knot: &mut Point) -> bool {
+        let delta = Simd::from_array([front.x - knot.x, front.y - knot.y]);
+        let apart = delta.abs().simd_gt(Simd::splat(1)).any();
+        if apart {
+            let [dx, dy] = delta.signum().to_array();
+            *knot += Point::new(dx, dy);
+        }
+        apart
+    }
+}
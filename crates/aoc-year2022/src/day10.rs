@@ -1,5 +1,5 @@
 //! # Cathode-Ray Tube
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 /// Tokenizes the input treating both "noop" and "addx" as no-ops to obtain the correct
 /// instruction timing. Produces a `vec` of the absolute values of `x` from cycle 0 to 241.
@@ -41,8 +41,8 @@
 //! many threads, speeding things up in part two.
 //!
 //! [`iter_unsigned`]: ParseOps::iter_unsigned
-use crate::util::parse::*;
-use crate::util::thread::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
 use std::sync::Mutex;
 
 pub struct Monkey {
@@ -0,0 +1,61 @@
+//! # Hill Climbing Algorithm
+//!
+//! Pretty much textbook implementation of a BFS (Breadth First Search). If you're not familar with
+//! BFS, [this blog post is a great introduction](https://www.redblobgames.com/pathfinding/a-star/introduction.html)
+//! to the algorithm, plus some others that come in handy for Advent of Code.
+//!
+//! For Part 2 we could search for all `a` locations and repeatedly start a BFS search from there,
+//! then find the lowest value. However a much faster approach is to search *backwards* from the
+//! end location, reversing the direction of every step so that we can climb down at most one
+//! level at a time. A single search then finds the distance from every location to the end,
+//! answering both parts at once.
+//!
+//! The search is the [`distance_by`] method from the shared [`Grid`] utility. The distances are
+//! kept in the input so that they can also be used for visualization.
+//!
+//! [`distance_by`]: aoc_util::grid::Grid::distance_by
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::point::*;
+
+pub struct Input {
+    /// Heights with `S` replaced by `a` and `E` replaced by `z`.
+    pub grid: Grid<u8>,
+    pub start: Point,
+    pub end: Point,
+    /// Number of steps from each location to the end, or `u32::MAX` if the end can't be reached.
+    pub distance: Grid<u32>,
+}
+
+/// Uses the utility [`Grid`] class to parse a 2D array of ASCII characters.
+///
+/// [`Grid`]: aoc_util::grid
+pub fn parse(input: &str) -> Input {
+    let mut grid = Grid::parse(input);
+    let start = grid.find(b'S').unwrap();
+    let end = grid.find(b'E').unwrap();
+
+    grid[start] = b'a';
+    grid[end] = b'z';
+
+    // Each step in the reverse search must be a valid step in the forward direction.
+    let distance = grid.distance_by([end], |from, to| climbable(to, from));
+    Input { grid, start, end, distance }
+}
+
+/// Find the shortest path from `S` to `E`
+pub fn part1(input: &Input) -> u32 {
+    input.distance[input.start]
+}
+
+/// Find the shortest path from the closest `a` to `E`
+pub fn part2(input: &Input) -> u32 {
+    let Input { grid, distance, .. } = input;
+    grid.find_iter(|&b| b == b'a').map(|point| distance[point]).min().unwrap()
+}
+
+/// We can step down any distance but only climb up one level at a time.
+#[inline]
+fn climbable(from: u8, to: u8) -> bool {
+    to <= from + 1
+}
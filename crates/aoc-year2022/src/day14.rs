@@ -30,7 +30,7 @@
 //! * `Air` Empty blocks, treated as unknown status when checking underneath neighbors.
 //! * `Falling` Grains of sand that will continue to fall continously forever.
 //! * `Stopped` Both original rock walls and any grains of sand that have come to rest.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Kind {
@@ -1,9 +1,9 @@
 //! # Beacon Exclusion Zone
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
-use std::ops::Range;
+use aoc_util::hash::*;
+use aoc_util::interval::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 pub struct Input {
     sensor: Point,
@@ -30,18 +30,21 @@ pub fn part1(input: &[Input]) -> i32 {
 /// A beacon cannot be located with the the radius of a sensor unless it is the closest beacon.
 ///
 /// We first convert each scanner's diamond shaped area into a one dimensional range at the
-/// specified row. By sorting the ranges, we can quickly calculate the total number of distinct
-/// ranges where another beacon cannot exist, only counting overlapping areas once.
+/// specified row. Merging the ranges with our [`merge`] utility function quickly calculates the
+/// total number of distinct positions where another beacon cannot exist, only counting
+/// overlapping areas once.
 ///
 /// Beacons can also not be located at the same position as another beacon so we then also discount
 /// any beacon located exactly on the specified row.
+///
+/// [`merge`]: aoc_util::interval::merge
 pub fn part1_testable(input: &[Input], row: i32) -> i32 {
     // Converts the "diamond" shaped area of each scanner into a one dimensional row.
     // If the scanner's range does not reach the specified row then return `None`.
-    fn build_range(input: &Input, row: i32) -> Option<Range<i32>> {
+    fn build_range(input: &Input, row: i32) -> Option<Interval<i32>> {
         let Input { sensor, manhattan, .. } = input;
         let extra = manhattan - (sensor.y - row).abs();
-        (extra >= 0).then(|| (sensor.x - extra)..(sensor.x + extra))
+        (extra >= 0).then(|| Interval::new(sensor.x - extra, sensor.x + extra + 1))
     }
 
     // Returns the x position off all beacons that are located on the specified row
@@ -51,26 +54,8 @@ pub fn part1_testable(input: &[Input], row: i32) -> i32 {
         (beacon.y == row).then_some(beacon.x)
     }
 
-    // Sort the ranges first
-    let mut ranges: Vec<_> = input.iter().filter_map(|i| build_range(i, row)).collect();
-    ranges.sort_unstable_by_key(|r| r.start);
-
-    let mut total = 0;
-    let mut max = i32::MIN;
-
-    // Compare each range to the next
-    for Range { start, end } in ranges {
-        if start > max {
-            // If there is no overlap with the previous range, then add the entire length.
-            total += end - start + 1;
-            max = end;
-        } else {
-            // If some part of the range overlaps, then only add any extra length.
-            // (it's possible that there is no extra length)
-            total += (end - max).max(0);
-            max = max.max(end);
-        }
-    }
+    let ranges = merge(input.iter().filter_map(|i| build_range(i, row)));
+    let total: i32 = ranges.iter().map(|r| r.len()).sum();
 
     let beacons: FastSet<_> = input.iter().filter_map(|i| build_beacons(i, row)).collect();
     total - (beacons.len() as i32)
@@ -38,9 +38,9 @@
 //! Then we check every possible pair formed by those values, considering only the pairs
 //! where the sets of valves are [disjoint](https://en.wikipedia.org/wiki/Disjoint_sets),
 //! which is when you and the elephant have visited different sets of valves.
-use crate::util::bitset::*;
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::bitset::*;
+use aoc_util::intern::*;
+use aoc_util::parse::*;
 use std::cmp::Ordering;
 
 /// Simplified graph of valves. Valves are stored in descending order of flow so the valve at
@@ -114,7 +114,11 @@ pub fn parse(input: &str) -> Input {
     // Eliminate zero valves. Assumes that zero valves are "tunnels" with each linking 2 other
     // valves. For all non-zero valves follows the tunnels to find the distance to each
     // immediate neighbor.
-    let indices: FastMap<_, _> = valves.iter().enumerate().map(|(i, v)| (v.name, i)).collect();
+    let mut interner = Interner::with_capacity(valves.len());
+    for valve in &valves {
+        interner.get_or_insert(valve.name);
+    }
+    let indices = |name| interner.get(name).unwrap() as usize;
 
     for (from, valve) in valves.iter().enumerate().take(size) {
         // Distance to ourself is zero.
@@ -124,14 +128,14 @@ pub fn parse(input: &str) -> Input {
         for edge in &valve.edges {
             let mut prev = valve.name;
             let mut cur = edge;
-            let mut to = indices[cur];
+            let mut to = indices(cur);
             let mut total = 1;
 
             while to >= size {
                 let next = valves[to].edges.iter().find(|&&e| e != prev).unwrap();
                 prev = cur;
                 cur = next;
-                to = indices[cur];
+                to = indices(cur);
                 total += 1;
             }
 
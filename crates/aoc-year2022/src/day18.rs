@@ -0,0 +1,152 @@
+//! # Boiling Boulders
+//!
+//! The lava droplet is a fixed size so we can store the cubes as a dense 3D bitset. Each `u32`
+//! row holds every `z` coordinate for a single `x` and `y`. Coordinates are offset by one to leave
+//! a layer of empty space around the outside, so that neighbors never need boundary checks.
+//!
+//! Two cubes share a face if they are neighbors along one axis. Along `z` this is a shift of a
+//! row by one bit, along `x` and `y` it's the row at the adjacent index. For each of the six
+//! directions a bitwise AND then a popcount counts a whole row of faces at once.
+//!
+//! Part one counts the faces of lava that touch anything that's not lava. For part two we
+//! [flood fill](https://en.wikipedia.org/wiki/Flood_fill) the outside space with water starting
+//! from a corner, growing the whole volume one step in every direction at a time until nothing
+//! changes. Then we count only the faces of lava that touch water.
+use aoc_util::parse::*;
+
+/// Width of the volume in each dimension, including the empty boundary layer.
+pub const SIZE: usize = 24;
+
+/// Dense set of cubes using a single bit for each position.
+#[derive(Clone)]
+pub struct Volume {
+    rows: Vec<u32>,
+}
+
+impl Volume {
+    /// Every bit within bounds of each row.
+    const MASK: u32 = (1 << SIZE) - 1;
+
+    pub fn new() -> Self {
+        Volume { rows: vec![0; SIZE * SIZE] }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, x: usize, y: usize, z: usize) {
+        self.rows[SIZE * x + y] |= 1 << z;
+    }
+
+    #[inline]
+    pub fn contains(&self, x: usize, y: usize, z: usize) -> bool {
+        self.rows[SIZE * x + y] & (1 << z) != 0
+    }
+
+    /// Number of cubes in the set.
+    pub fn len(&self) -> u32 {
+        self.rows.iter().map(|row| row.count_ones()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.iter().all(|&row| row == 0)
+    }
+
+    /// Every position within bounds that is not in the set.
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        Volume { rows: self.rows.iter().map(|row| !row & Self::MASK).collect() }
+    }
+
+    /// Counts faces of cubes in this set that touch a cube in `other`. Cubes in this set must
+    /// not be on the boundary layer.
+    pub fn touching(&self, other: &Volume) -> u32 {
+        let mut total = 0;
+
+        for x in 1..SIZE - 1 {
+            for y in 1..SIZE - 1 {
+                let index = SIZE * x + y;
+                let row = self.rows[index];
+
+                total += (row & (other.rows[index] << 1)).count_ones()
+                    + (row & (other.rows[index] >> 1)).count_ones()
+                    + (row & other.rows[index - 1]).count_ones()
+                    + (row & other.rows[index + 1]).count_ones()
+                    + (row & other.rows[index - SIZE]).count_ones()
+                    + (row & other.rows[index + SIZE]).count_ones();
+            }
+        }
+
+        total
+    }
+
+    /// Every position reachable from `start` without passing through a cube in this set.
+    #[must_use]
+    pub fn flood_fill(&self, start: (usize, usize, usize)) -> Volume {
+        let mut water = Volume::new();
+        let (x, y, z) = start;
+        water.insert(x, y, z);
+
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for x in 0..SIZE {
+                for y in 0..SIZE {
+                    let index = SIZE * x + y;
+                    let mut next = water.rows[index];
+
+                    next |= next << 1 | next >> 1;
+                    if x > 0 {
+                        next |= water.rows[index - SIZE];
+                    }
+                    if x < SIZE - 1 {
+                        next |= water.rows[index + SIZE];
+                    }
+                    if y > 0 {
+                        next |= water.rows[index - 1];
+                    }
+                    if y < SIZE - 1 {
+                        next |= water.rows[index + 1];
+                    }
+                    next &= !self.rows[index] & Self::MASK;
+
+                    if next != water.rows[index] {
+                        water.rows[index] = next;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        water
+    }
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn parse(input: &str) -> Volume {
+    let mut lava = Volume::new();
+
+    // Leave a 1 layer boundary around the outside for the part two flood fill
+    // and also so that we don't have to use boundary checks when checking neighbors.
+    for line in input.lines() {
+        let [x, y, z] = line.extract_unsigned::<usize, 3>();
+        lava.insert(x + 1, y + 1, z + 1);
+    }
+
+    lava
+}
+
+pub fn part1(input: &Volume) -> u32 {
+    input.touching(&input.complement())
+}
+
+pub fn part2(input: &Volume) -> u32 {
+    // "Paint" the outside of the droplet with water.
+    let water = input.flood_fill((0, 0, 0));
+    input.touching(&water)
+}
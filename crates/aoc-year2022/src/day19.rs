@@ -41,7 +41,7 @@
 //! The final important optimization is that we don't increment minute by minute. Instead once
 //! we decide to buld a robot of a particular type, we "fast forward" in time until there are
 //! enough resources to build that robot. This cuts down on a lot of duplicate intermediate states.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 use std::ops::{Add, Sub};
 
 /// Each robot generates 1 mineral of a particular type.
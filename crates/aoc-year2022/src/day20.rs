@@ -14,7 +14,7 @@
 //! commit history) that used an [order statistic tree](https://en.wikipedia.org/wiki/Order_statistic_tree),
 //! although perhaps adding [balancing rotations](https://en.wikipedia.org/wiki/Tree_rotation)
 //! to the tree would make it faster.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<i64> {
     input.iter_signed().collect()
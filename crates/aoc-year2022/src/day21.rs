@@ -37,8 +37,8 @@
 //! ```
 //!
 //! Once we finally reach the `humn` node the value that we currently have `2` is the answer.
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 #[derive(Clone, Copy)]
 enum Operation {
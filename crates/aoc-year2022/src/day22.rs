@@ -59,10 +59,10 @@
 //! For example say we transition from face `A` to face `B`. Our `k` is (0, 1, 0) which is
 //! equal to minus the new `j`, so we know that we're travelling upwards from the bottom edge.
 //! Then we can use this information to figure out the two dimensional offsets into the new face.
-use crate::util::hash::*;
-use crate::util::math::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::hash::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 use std::ops::Neg;
 
@@ -3,6 +3,7 @@
 //! We represent elves as bits in a integer then use bitwise operations to efficiently figure
 //! out the movement for multiple elves at once.
 use self::Direction::*;
+use aoc_util::stable::*;
 use std::ops::{BitAnd, BitAndAssign, BitOr, Not};
 
 /// The initial grid is 70 x 70. Elves stop moving when no other elf is adjacent so the grid
@@ -132,15 +133,15 @@ pub fn parse(input: &str) -> Input {
 }
 
 pub fn part1(input: &Input) -> u32 {
-    let mut input = *input;
     let mut order = [North, South, West, East];
 
-    for _ in 0..10 {
-        step(&mut input, &mut order);
-    }
+    // A round where no elf moves still rotates the order of directions, so blocked elves can move
+    // again in a later round. The grid only stops changing once no elf has a neighbor.
+    let Stable { state, .. } =
+        run_until_stable_within(*input, 10, |input| step(input, &mut order).1);
 
     // Find the bounding rectangle.
-    let grid = input.grid;
+    let grid = state.grid;
     let elves: u32 = grid.iter().map(U256::count_ones).sum();
     let min_x = grid.iter().filter_map(U256::min_set).min().unwrap();
     let max_x = grid.iter().filter_map(U256::max_set).max().unwrap();
@@ -151,26 +152,20 @@ pub fn part1(input: &Input) -> u32 {
 }
 
 pub fn part2(input: &Input) -> u32 {
-    let mut input = *input;
     let mut order = [North, South, West, East];
-    let mut moved = true;
-    let mut count = 0;
-
-    while moved {
-        moved = step(&mut input, &mut order);
-        count += 1;
-    }
-
-    count
+    let (_, rounds) = run_until_stable(*input, |input| step(input, &mut order).0);
+    rounds as u32
 }
 
-fn step(input: &mut Input, order: &mut [Direction]) -> bool {
+/// Returns the number of elves that moved and the number of elves that had at least one neighbor.
+fn step(input: &mut Input, order: &mut [Direction]) -> (usize, usize) {
     let Input { grid, north, south, west, east } = input;
     // Optimization to avoid processing empty rows.
     let start = grid.iter().position(U256::non_zero).unwrap() - 1;
     let end = grid.iter().rposition(U256::non_zero).unwrap() + 2;
 
-    let mut moved = false;
+    let mut moved = 0;
+    let mut crowded = 0;
 
     let mut prev;
     // Find horizontal neighbors in each row. To make movement calculations easier
@@ -192,6 +187,7 @@ fn step(input: &mut Input, order: &mut [Direction]) -> bool {
         let mut right = vertical.left_shift();
         // Elves need at least 1 neighbor to propose moving.
         let mut remaining = grid[i] & !(up & down & left & right);
+        crowded += remaining.count_ones() as usize;
 
         // Consider each direction one at a time, removing any elves who propose it.
         for direction in &*order {
@@ -243,10 +239,10 @@ fn step(input: &mut Input, order: &mut [Direction]) -> bool {
         // Moving elves.
         let change = north[i] | south[i] | west[i] | east[i];
         grid[i] = same | change;
-        moved |= change.non_zero();
+        moved += change.count_ones() as usize;
     }
 
     // Rotate the order of movement proposals for the next turn.
     order.rotate_left(1);
-    moved
+    (moved, crowded)
 }
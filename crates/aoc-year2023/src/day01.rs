@@ -2,7 +2,7 @@
 //!
 //! The input can contain overlapping digits such as "twone", so we only remove a letter at a time
 //! until the starting or ending digits are found.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 /// Use the index of each digit as its implicit value.
 const DIGITS: [&[u8]; 9] =
@@ -1,6 +1,6 @@
 //! # Cube Conundrum
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub struct Game(u32, u32, u32);
 
@@ -0,0 +1,77 @@
+//! # Gear Ratios
+//!
+//! The [`numbers`] and [`symbols`] methods of the shared [`Grid`] utility extract the part
+//! numbers and symbols, then a single pass over the symbols solves both parts at once.
+//!
+//! To look up which number is next to a symbol, store the index of each number at the location
+//! of every one of its digits, using zero to indicate no value. For example:
+//! `467..114..` => `1110022200`
+//!
+//! [`numbers`]: Grid::numbers
+//! [`symbols`]: Grid::symbols
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::point::*;
+
+type Input = (u32, u32);
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let mut seen: Grid<usize> = grid.same_size_with(0);
+    // Stores each unique part number. The first value is a dummy placeholder.
+    let mut parts = vec![0];
+
+    for span in grid.numbers() {
+        for point in span.points() {
+            seen[point] = parts.len();
+        }
+        parts.push(span.value);
+    }
+
+    let mut counted = vec![false; parts.len()];
+    let mut part_one = 0;
+    let mut part_two = 0;
+
+    for (point, symbol) in grid.symbols() {
+        let mut previous = 0;
+        let mut distinct = 0;
+        let mut subtotal = 1;
+
+        // Rely on the left to right and top to bottom order of DIAGONAL
+        // to detect distinct numbers.
+        for next in DIAGONAL.map(|d| point + d) {
+            let index = seen[next];
+            if index != 0 && index != previous {
+                previous = index;
+                distinct += 1;
+
+                // Gears have exactly two numbers so there's no need to multiply any more.
+                if distinct <= 2 {
+                    subtotal *= parts[index];
+                }
+
+                // Only count each number once when its adjacent to multiple symbols.
+                if !counted[index] {
+                    counted[index] = true;
+                    part_one += parts[index];
+                }
+            }
+        }
+
+        if symbol == b'*' && distinct == 2 {
+            part_two += subtotal;
+        }
+    }
+
+    (part_one, part_two)
+}
+
+/// Sum all part numbers adjacent to a least one symbol.
+pub fn part1(input: &Input) -> u32 {
+    input.0
+}
+
+/// Sum all gears adjacent to exactly two part numbers.
+pub fn part2(input: &Input) -> u32 {
+    input.1
+}
@@ -1,6 +1,6 @@
 //! # If You Give A Seed A Fertilizer
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 pub struct Input {
     seeds: Vec<u64>,
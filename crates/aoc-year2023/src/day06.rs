@@ -19,7 +19,7 @@
 //! [quadratic formula](https://en.wikipedia.org/wiki/Quadratic_formula).
 //!
 //! * `(t ± √(t² - 4d)) / 2`
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<&str> {
     input.lines().collect()
@@ -0,0 +1,137 @@
+//! # Camel Cards
+//!
+//! The type of each hand is computed from the frequency of the cards ordered in descending order.
+//! For example a full house would have 1 card with a frequency of 3 and a second with a
+//! frequency of 2, giving `[3, 2]`. Similarly two pair would be `[2, 2, 1]`. Only the two
+//! highest frequencies are needed to tell every type apart. [`classify`] is a pure function
+//! exposed for testing.
+//!
+//! For part two we add the numbers of jokers to the highest frequency (which could already be
+//! jokers!).
+//!
+//! To rank the hands the type and the card values are packed into the nibbles of a `u32` key:
+//!
+//! * `55222` => `0x455222`
+//! * `32T3K` => `0x1323ad`
+//! * `QQQJA` with jokers => `0x5ccc1e`
+//!
+//! The keys only use 24 bits, so instead of a comparison sort we use an
+//! [LSD radix sort](https://en.wikipedia.org/wiki/Radix_sort) with 3 passes of 8 bits each,
+//! a counting sort on each byte from least to most significant.
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use std::mem::swap;
+
+/// Hand types in ascending order of strength.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+pub struct Hand {
+    cards: [u8; 5],
+    bid: usize,
+}
+
+pub fn parse(input: &str) -> Vec<Hand> {
+    input
+        .lines()
+        .map(|line| {
+            let (prefix, suffix) = line.split_at(5);
+            let cards = prefix.as_bytes().try_into().unwrap();
+            let bid = suffix.unsigned();
+            Hand { cards, bid }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Hand]) -> usize {
+    winnings(input, false)
+}
+
+pub fn part2(input: &[Hand]) -> usize {
+    winnings(input, true)
+}
+
+/// Type of a hand, treating `J` as a wildcard if `jokers` is true.
+pub fn classify(cards: [u8; 5], jokers: bool) -> Kind {
+    let mut freq = [0; 15];
+    cards.map(|b| value(b, jokers)).into_iter().tally_into(&mut freq, |r| r);
+
+    // Find the two highest frequencies, ignoring jokers which have a value of 1.
+    let wild = freq[1];
+    let (mut first, mut second) = (0, 0);
+
+    for &f in &freq[2..] {
+        if f > first {
+            second = first;
+            first = f;
+        } else if f > second {
+            second = f;
+        }
+    }
+
+    match (first + wild, second) {
+        (5, _) => Kind::FiveOfAKind,
+        (4, _) => Kind::FourOfAKind,
+        (3, 2) => Kind::FullHouse,
+        (3, _) => Kind::ThreeOfAKind,
+        (2, 2) => Kind::TwoPair,
+        (2, _) => Kind::OnePair,
+        _ => Kind::HighCard,
+    }
+}
+
+fn value(b: u8, jokers: bool) -> usize {
+    match b {
+        b'A' => 14,
+        b'K' => 13,
+        b'Q' => 12,
+        b'J' if jokers => 1,
+        b'J' => 11,
+        b'T' => 10,
+        _ => b.to_decimal() as usize,
+    }
+}
+
+fn winnings(input: &[Hand], jokers: bool) -> usize {
+    let mut hands: Vec<_> = input
+        .iter()
+        .map(|&Hand { cards, bid }| {
+            // Pack the type then the card values into the nibbles of the key.
+            let kind = classify(cards, jokers) as usize;
+            let key = cards.iter().fold(kind, |key, &b| (key << 4) | value(b, jokers));
+            (key, bid)
+        })
+        .collect();
+
+    radix_sort(&mut hands);
+    hands.iter().enumerate().map(|(i, (_, bid))| (i + 1) * bid).sum()
+}
+
+/// Stable sort by the lowest 24 bits of the key, one byte at a time.
+fn radix_sort(hands: &mut Vec<(usize, usize)>) {
+    let mut buffer = vec![(0, 0); hands.len()];
+
+    for shift in [0, 8, 16] {
+        let mut offsets = [0; 257];
+        for &(key, _) in hands.iter() {
+            offsets[((key >> shift) & 0xff) + 1] += 1;
+        }
+        for i in 1..257 {
+            offsets[i] += offsets[i - 1];
+        }
+        for &hand in hands.iter() {
+            let digit = (hand.0 >> shift) & 0xff;
+            buffer[offsets[digit]] = hand;
+            offsets[digit] += 1;
+        }
+        swap(hands, &mut buffer);
+    }
+}
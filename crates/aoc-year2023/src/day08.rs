@@ -0,0 +1,195 @@
+//! # Haunted Wasteland
+//!
+//! Each ghost follows the same list of directions, so its state is the pair of the current node
+//! and the position within the directions. There are a finite number of states so every ghost
+//! eventually repeats a state then loops forever. Walking each ghost until the first repeat finds
+//! the length of the *prefix* before the loop, the *period* of the loop and every step that lands
+//! on a node ending in `Z`.
+//!
+//! The actual inputs have a very specific structure. Each ghost reaches exactly one node ending in
+//! `Z` and the step when it first arrives is equal to the period. This
+//! [visualization](https://www.reddit.com/r/adventofcode/comments/18did3d/2023_day_8_part_1_my_input_maze_plotted_using/)
+//! shows the special structure. When this holds the answer is simply the
+//! [LCM](https://en.wikipedia.org/wiki/Least_common_multiple) of every period, using the identity:
+//!
+//! `lcm(a, b, c) = lcm(lcm(a, b), c)`
+//!
+//! Otherwise we fall back to a general approach. Steps before every ghost has entered its loop
+//! are checked one by one. After that each ghost is at a `Z` node only when the step is congruent
+//! to one of its loop offsets modulo its period. Every combination of offsets is solved with the
+//! [Chinese Remainder Theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem) and the
+//! smallest solution wins. [`method`] reports which of the two approaches was used.
+//!
+//! Part one is a special case of a single ghost walking from `AAA` to exactly `ZZZ`. If `ZZZ`
+//! can't be reached then the answer is zero.
+use aoc_util::integer::*;
+use aoc_util::intern::*;
+use aoc_util::math::*;
+
+/// Approach used to combine the ghosts for part two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Lcm,
+    Crt,
+}
+
+/// Loop structure of a single ghost. `hits` contains every step in `0..prefix + period`
+/// that lands on a node ending in `Z`, in ascending order.
+#[derive(Clone, Debug)]
+pub struct Ghost {
+    pub prefix: usize,
+    pub period: usize,
+    pub hits: Vec<usize>,
+}
+
+impl Ghost {
+    fn is_hit(&self, step: usize) -> bool {
+        let step = if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.period
+        };
+        self.hits.binary_search(&step).is_ok()
+    }
+
+    fn assumption_holds(&self) -> bool {
+        self.hits == [self.period]
+    }
+}
+
+pub struct Input {
+    part_one: usize,
+    part_two: usize,
+    method: Method,
+}
+
+pub fn parse(input: &str) -> Input {
+    let lines: Vec<_> = input.lines().collect();
+    let directions: Vec<_> = lines[0].bytes().map(|b| usize::from(b == b'R')).collect();
+
+    let mut interner = Interner::with_capacity(lines.len());
+    let mut nodes = vec![[0; 2]; lines.len() - 2];
+
+    for line in &lines[2..] {
+        let node = interner.get_or_insert(&line[0..3]) as usize;
+        let left = interner.get_or_insert(&line[7..10]) as usize;
+        let right = interner.get_or_insert(&line[12..15]) as usize;
+        nodes[node] = [left, right];
+    }
+
+    // There are only `nodes * directions` distinct states, so if `ZZZ` hasn't been reached by
+    // then it never will be.
+    let part_one = match (interner.get("AAA"), interner.get("ZZZ")) {
+        (Some(start), Some(end)) => {
+            let (start, end) = (start as usize, end as usize);
+            let limit = nodes.len() * directions.len();
+            let mut node = start;
+            let mut steps = 0;
+
+            while node != end && steps < limit {
+                node = nodes[node][directions[steps % directions.len()]];
+                steps += 1;
+            }
+
+            if node == end { steps } else { 0 }
+        }
+        _ => 0,
+    };
+
+    let is_end: Vec<_> =
+        (0..nodes.len()).map(|id| interner.name(id as u32).ends_with('Z')).collect();
+    let ghosts: Vec<_> = (0..nodes.len())
+        .filter(|&id| interner.name(id as u32).ends_with('A'))
+        .map(|start| analyze(&nodes, &is_end, &directions, start))
+        .collect();
+    let (part_two, method) = earliest(&ghosts).unwrap_or((0, Method::Crt));
+
+    Input { part_one, part_two, method }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.part_one
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.part_two
+}
+
+/// Which approach solved part two.
+pub fn method(input: &Input) -> Method {
+    input.method
+}
+
+/// Finds the first step when every ghost is on a node ending in `Z` at the same time, using the
+/// LCM fast path when each ghost has the usual structure.
+pub fn earliest(ghosts: &[Ghost]) -> Option<(usize, Method)> {
+    if ghosts.iter().all(Ghost::assumption_holds) {
+        let lcm = ghosts.iter().fold(1, |lcm, ghost| lcm.lcm(ghost.period));
+        return Some((lcm, Method::Lcm));
+    }
+
+    // Check steps before every ghost has entered its loop one at a time.
+    let limit = ghosts.iter().map(|ghost| ghost.prefix).max()?;
+    if let Some(step) = (0..limit).find(|&step| ghosts.iter().all(|ghost| ghost.is_hit(step))) {
+        return Some((step, Method::Crt));
+    }
+
+    // Try every combination of loop offsets, keeping the smallest solution at or after the limit.
+    let mut best = None;
+    let mut combination = Vec::with_capacity(ghosts.len());
+    combine(ghosts, limit, &mut combination, &mut best);
+    best.map(|step| (step, Method::Crt))
+}
+
+fn analyze(nodes: &[[usize; 2]], is_end: &[bool], directions: &[usize], start: usize) -> Ghost {
+    let size = directions.len();
+    let mut seen = vec![usize::MAX; nodes.len() * size];
+    let mut hits = Vec::new();
+    let mut node = start;
+    let mut step = 0;
+
+    loop {
+        let state = node * size + step % size;
+        if seen[state] != usize::MAX {
+            let prefix = seen[state];
+            return Ghost { prefix, period: step - prefix, hits };
+        }
+
+        seen[state] = step;
+        if is_end[node] {
+            hits.push(step);
+        }
+
+        node = nodes[node][directions[step % size]];
+        step += 1;
+    }
+}
+
+fn combine(
+    ghosts: &[Ghost],
+    limit: usize,
+    combination: &mut Vec<(i128, i128)>,
+    best: &mut Option<usize>,
+) {
+    let Some((ghost, remaining)) = ghosts.split_first() else {
+        if let Some((remainder, modulus)) = crt(combination.iter().copied()) {
+            // Lift the solution into the range where every ghost is inside its loop.
+            let limit = limit as i128;
+            let step = if remainder < limit {
+                remainder + (limit - remainder).ceil_div(modulus) * modulus
+            } else {
+                remainder
+            };
+            if let Ok(step) = usize::try_from(step) {
+                *best = Some(best.map_or(step, |best| best.min(step)));
+            }
+        }
+        return;
+    };
+
+    for &hit in ghost.hits.iter().filter(|&&hit| hit >= ghost.prefix) {
+        combination.push((hit as i128, ghost.period as i128));
+        combine(remaining, limit, combination, best);
+        combination.pop();
+    }
+}
@@ -36,25 +36,27 @@
 //!
 //! Let `n` be the number of values and `k` the index of each value. The coefficient for each value
 //! is `(n k)` if `k` is even or `-(n k)` if `k` is odd. For part one we then flip the sign of the
-//! sum when `n` is odd.
-use crate::util::parse::*;
+//! sum when `n` is odd. This is a special case of extrapolating the polynomial through the values
+//! using [Lagrange interpolation](aoc_util::math::lagrange_extrapolate), taking `O(n)` time
+//! per line.
+use aoc_util::math::*;
+use aoc_util::parse::*;
 
 type Input = (i64, i64);
 
 pub fn parse(input: &str) -> Input {
     // Determine how many numbers are on each row. Assume each row has the same amount.
     let (prefix, _) = input.split_once('\n').unwrap();
-    let row = prefix.iter_signed::<i64>().count() as i64;
+    let row = prefix.iter_signed::<i64>().count() as u64;
 
-    // Calculate [Pascal's Triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle)
-    // for the required row, flipping the sign on each second coefficient.
-    let mut coefficient = 1;
-    let mut triangle = vec![1];
-
-    for i in 0..row {
-        coefficient = (coefficient * (i - row)) / (i + 1);
-        triangle.push(coefficient);
-    }
+    // Calculate the required row of [Pascal's Triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle),
+    // flipping the sign on each second coefficient.
+    let triangle: Vec<_> = (0..=row)
+        .map(|k| {
+            let coefficient = binomial(row, k).unwrap() as i64;
+            if k % 2 == 0 { coefficient } else { -coefficient }
+        })
+        .collect();
 
     // Use adjusted binomial coefficients to calculate answers for each row.
     let mut part_one = 0;
@@ -0,0 +1,127 @@
+//! # Pipe Maze
+//!
+//! This solution uses the [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula)
+//! and [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem) helpers from the
+//! [`math`] module.
+//!
+//! Starting at `S` we trace out the path followed by the pipes. Each corner piece
+//! (`7`, `F`, `J`, `L` and finally `S`) is considered a vertex and added to the running total
+//! for the area using the Shoelace formula. Additionally we keep track of the perimeter length.
+//!
+//! As the path is a loop the answer for part one is half the perimeter length.
+//!
+//! The answer for part two is the number of interior points. Rearranging Pick's theorem:
+//!
+//! `A = i + b / 2 - 1 => i = A - b / 2 + 1`
+//!
+//! The slower [`scan_line`] approach that casts a ray along each row, counting the number of
+//! times that it crosses the loop, is kept as an independent cross-check of the area calculation.
+//!
+//! [`math`]: aoc_util::math
+use aoc_util::grid::*;
+use aoc_util::math::*;
+use aoc_util::point::*;
+
+type Input = (i64, i64);
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let corners = trace_loop(&grid);
+
+    let area = polygon_area(corners.iter().copied());
+    let steps = perimeter(corners.iter().copied());
+
+    let part_one = steps / 2;
+    let part_two = interior_points(area, steps);
+    (part_one, part_two)
+}
+
+pub fn part1(input: &Input) -> i64 {
+    input.0
+}
+
+pub fn part2(input: &Input) -> i64 {
+    input.1
+}
+
+/// Follows the pipes starting at `S` returning each vertex of the loop in order.
+/// The starting position is always the first vertex.
+pub fn trace_loop(grid: &Grid<u8>) -> Vec<Point> {
+    // Find the starting position and direction.
+    let start = grid.find(b'S').unwrap();
+    let mut direction = if connects_up(grid, start) { UP } else { DOWN };
+    let mut position = start + direction;
+    let mut corners = vec![start];
+
+    loop {
+        // Follow straight paths.
+        while grid[position] == b'-' || grid[position] == b'|' {
+            position += direction;
+        }
+
+        // Change direction at corner pieces.
+        direction = match grid[position] {
+            b'7' if direction == UP => LEFT,
+            b'F' if direction == UP => RIGHT,
+            b'J' if direction == DOWN => LEFT,
+            b'L' if direction == DOWN => RIGHT,
+            b'J' | b'L' => UP,
+            b'7' | b'F' => DOWN,
+            // We've looped all the way back to the start.
+            _ => break,
+        };
+
+        corners.push(position);
+        position += direction;
+    }
+
+    corners
+}
+
+/// Counts interior tiles by scanning each row from left to right. Every time we cross a loop
+/// tile that connects north (`|`, `L` or `J`) we toggle between outside and inside.
+pub fn scan_line(input: &str) -> i64 {
+    let grid = Grid::parse(input);
+    let corners = trace_loop(&grid);
+    let mut north = grid.same_size_with(false);
+    let mut boundary = grid.same_size_with(false);
+
+    for (i, &from) in corners.iter().enumerate() {
+        let to = corners[(i + 1) % corners.len()];
+        let step = to.signum(from);
+        let mut position = from;
+
+        while position != to {
+            boundary[position] = true;
+            north[position] = matches!(grid[position], b'|' | b'L' | b'J');
+            position += step;
+        }
+    }
+
+    let start = corners[0];
+    north[start] = connects_up(&grid, start);
+
+    let mut interior = 0;
+
+    for y in 0..grid.height {
+        let mut inside = false;
+
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+
+            if boundary[point] {
+                inside ^= north[point];
+            } else if inside {
+                interior += 1;
+            }
+        }
+    }
+
+    interior
+}
+
+/// The start connects upwards if the pipe above leads down into it.
+fn connects_up(grid: &Grid<u8>, start: Point) -> bool {
+    let above = start + UP;
+    grid.contains(above) && matches!(grid[above], b'|' | b'7' | b'F')
+}
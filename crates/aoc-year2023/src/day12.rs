@@ -118,8 +118,8 @@
 //!
 //! This is equivalent to the prefix sum approach described above but a little clearer to
 //! understand however slower to calculate.
-use crate::util::parse::*;
-use crate::util::thread::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 type Spring<'a> = (&'a [u8], Vec<usize>);
@@ -17,8 +17,8 @@
 //! code to be reused.
 //!
 //! [`count_ones`]: u32::count_ones
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 type Input = Vec<(Vec<u32>, Vec<u32>)>;
 
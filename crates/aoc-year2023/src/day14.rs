@@ -64,9 +64,9 @@
 //!    O.#OOO....#..O    [0 3 4 5 13]    [0 0 1 1 1 1 1 1 1 1 2 2 2 2]    [0 5 10]
 //!    O.#OOO....#O..    [0 3 4 5 11]    [0 0 1 1 1 1 1 1 1 1 2 2 2 2]    [0 5 11]
 //! ```
-use crate::util::grid::*;
-use crate::util::hash::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::point::*;
 
 pub struct Input {
     width: i32,
@@ -91,13 +91,7 @@ pub fn parse(input: &str) -> Input {
     let mut grid = Grid::new(inner.width + 2, inner.height + 2, b'#');
 
     // Copy inner grid.
-    for y in 0..inner.width {
-        for x in 0..inner.width {
-            let src = Point::new(x, y);
-            let dst = Point::new(x + 1, y + 1);
-            grid[dst] = inner[src];
-        }
-    }
+    grid.blit(&inner, Point::new(1, 1));
 
     let mut rounded = Vec::new();
     let mut north = grid.same_size_with(0);
@@ -120,7 +114,6 @@ pub fn parse(input: &str) -> Input {
     }
 
     // For each direction, store the next index that a ball will roll to in that direction.
-
     // North
     for x in 0..grid.width {
         for y in 0..grid.height {
@@ -0,0 +1,190 @@
+//! # Lens Library
+//!
+//! Calculates part one and two at the same time as a speed optimization.
+//!
+//! Expanding the hash, each byte is multiplied by 17 once for every byte that follows, so the
+//! hash of `n` bytes is `∑ bₖ * 17ⁿ⁻ᵏ mod 256`. As `17 = 1 + 16` the powers simplify to
+//! `17ᵐ ≡ 1 + 16m mod 256`. With the `simd` feature enabled each step and label is hashed with a
+//! single 16 byte vector, instead of one byte at a time. Multiplying and summing `u8` lanes wraps
+//! around, which is exactly the `% 256` that the algorithm needs. The `compare` feature builds
+//! the `simd` version alongside the scalar [`hash`], so tests can check that both agree.
+//!
+//! Lenses are stored in a single arena with each box a doubly linked list of indices into the
+//! arena. Appending and removing a lens updates only a few indices instead of shifting the
+//! remaining lenses along as a `Vec` per box would, and there's only one allocation.
+use aoc_util::parse::*;
+
+type Input = (usize, usize);
+
+/// Marks the end of a linked list.
+const NONE: usize = usize::MAX;
+
+struct Lens<'a> {
+    label: &'a [u8],
+    focal: usize,
+    prev: usize,
+    next: usize,
+}
+
+struct Boxes<'a> {
+    arena: Vec<Lens<'a>>,
+    head: [usize; 256],
+    tail: [usize; 256],
+}
+
+impl<'a> Boxes<'a> {
+    fn new(capacity: usize) -> Self {
+        Boxes { arena: Vec::with_capacity(capacity), head: [NONE; 256], tail: [NONE; 256] }
+    }
+
+    fn find(&self, slot: usize, label: &[u8]) -> Option<usize> {
+        let mut index = self.head[slot];
+
+        while index != NONE {
+            if self.arena[index].label == label {
+                return Some(index);
+            }
+            index = self.arena[index].next;
+        }
+
+        None
+    }
+
+    fn remove(&mut self, slot: usize, label: &[u8]) {
+        if let Some(index) = self.find(slot, label) {
+            let Lens { prev, next, .. } = self.arena[index];
+
+            if prev == NONE {
+                self.head[slot] = next;
+            } else {
+                self.arena[prev].next = next;
+            }
+            if next == NONE {
+                self.tail[slot] = prev;
+            } else {
+                self.arena[next].prev = prev;
+            }
+        }
+    }
+
+    fn insert(&mut self, slot: usize, label: &'a [u8], focal: usize) {
+        if let Some(index) = self.find(slot, label) {
+            self.arena[index].focal = focal;
+            return;
+        }
+
+        let index = self.arena.len();
+        let prev = self.tail[slot];
+        self.arena.push(Lens { label, focal, prev, next: NONE });
+
+        if prev == NONE {
+            self.head[slot] = index;
+        } else {
+            self.arena[prev].next = index;
+        }
+        self.tail[slot] = index;
+    }
+
+    fn focusing_power(&self) -> usize {
+        let mut total = 0;
+
+        for (slot, &head) in self.head.iter().enumerate() {
+            let mut index = head;
+            let mut position = 1;
+
+            while index != NONE {
+                total += (slot + 1) * position * self.arena[index].focal;
+                index = self.arena[index].next;
+                position += 1;
+            }
+        }
+
+        total
+    }
+}
+
+pub fn parse(input: &str) -> Input {
+    let input = input.trim().as_bytes();
+    let steps: Vec<_> = input.split(|&b| b == b',').collect();
+    let mut part_one = 0;
+    let mut boxes = Boxes::new(steps.len());
+    let mut end = 0;
+
+    for step in steps {
+        // Index in the input just after the step.
+        end += step.len();
+        part_one += hash_in(input, end, step);
+
+        if let Some(label) = step.strip_suffix(b"-") {
+            boxes.remove(hash_in(input, end - 1, label), label);
+        } else {
+            let label = &step[..step.len() - 2];
+            let focal = step[step.len() - 1].to_decimal() as usize;
+            boxes.insert(hash_in(input, end - 2, label), label, focal);
+        }
+
+        // Skip the comma.
+        end += 1;
+    }
+
+    (part_one, boxes.focusing_power())
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.0
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.1
+}
+
+/// Custom hash function.
+#[inline]
+pub fn hash(slice: &[u8]) -> usize {
+    slice.iter().fold(0, |acc, &b| ((acc + b as usize) * 17) & 0xff)
+}
+
+/// Hashes `slice`, which ends just before index `end` of `input`.
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn hash_in(_: &[u8], _: usize, slice: &[u8]) -> usize {
+    hash(slice)
+}
+
+#[cfg(feature = "simd")]
+#[inline]
+fn hash_in(input: &[u8], end: usize, slice: &[u8]) -> usize {
+    simd::hash_in(input, end, slice)
+}
+
+#[cfg(feature = "simd")]
+pub mod simd {
+    use super::hash;
+    use std::simd::Select as _;
+    use std::simd::Simd;
+    use std::simd::cmp::SimdPartialOrd as _;
+    use std::simd::num::SimdUint as _;
+
+    const LANES: usize = 16;
+    const INDEX: Simd<u8, LANES> =
+        Simd::from_array([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    /// `17ᵐ mod 256` for a byte with `m` bytes from its lane to the end of the window.
+    const WEIGHTS: Simd<u8, LANES> =
+        Simd::from_array([1, 241, 225, 209, 193, 177, 161, 145, 129, 113, 97, 81, 65, 49, 33, 17]);
+
+    /// Loads the 16 bytes of the input that end at `slice`, zeroing any before its start.
+    /// Leading zeros don't change the hash, so multiplying each byte by the power of 17 for its
+    /// position then summing the lanes gives the hash. Slices that are longer than 16 bytes or
+    /// too close to the start of the input fall back to the scalar hash.
+    #[inline]
+    pub fn hash_in(input: &[u8], end: usize, slice: &[u8]) -> usize {
+        if slice.len() > LANES || end < LANES {
+            return hash(slice);
+        }
+
+        let window = Simd::from_slice(&input[end - LANES..end]);
+        let skip = Simd::splat((LANES - slice.len()) as u8);
+        let bytes = INDEX.simd_ge(skip).select(window, Simd::splat(0));
+        (bytes * WEIGHTS).reduce_sum() as usize
+    }
+}
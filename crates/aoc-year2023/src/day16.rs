@@ -11,9 +11,9 @@
 //!
 //! For part two each path is independent so we can use multiple threads in parallel to speed
 //! up the search.
-use crate::util::grid::*;
-use crate::util::point::*;
-use crate::util::thread::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
+use aoc_util::thread::*;
 use std::collections::VecDeque;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -250,5 +250,5 @@ fn count(input: &Input, start: Pair) -> usize {
         }
     }
 
-    energized.bytes.iter().filter(|&&b| b).count()
+    energized.count(|&b| b)
 }
@@ -1,16 +1,19 @@
 //! # Lavaduct Lagoon
 //!
 //! Similar approach to [`Day 10`] using the [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula)
-//! and [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem).
+//! and [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem) helpers from the
+//! [`math`] module.
 //!
 //! One nuance is that we want the number of interior *and* boundary points so the final formula is:
 //!
 //! `i + b => A - b / 2 + 1 + b => A + b / 2 + 1`
 //!
-//! [`Day 10`]: crate::year2023::day10
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+//! [`Day 10`]: crate::day10
+//! [`math`]: aoc_util::math
+use aoc_util::iter::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 
 type Move = (Point, i32);
 type Input = (Vec<Move>, Vec<Move>);
@@ -51,23 +54,16 @@ pub fn part2(input: &Input) -> i64 {
 
 /// Find the volume of the lava which is the number of interior and boundary points.
 fn lava(moves: &[Move]) -> i64 {
-    let mut previous;
-    let mut position = ORIGIN;
-    let mut area = 0;
-    let mut perimeter = 0;
+    let vertices = || {
+        moves.iter().scan(ORIGIN, |position, &(direction, amount)| {
+            *position += direction * amount;
+            Some(*position)
+        })
+    };
 
-    for &(direction, amount) in moves {
-        previous = position;
-        position += direction * amount;
-        area += determinant(previous, position);
-        perimeter += amount as i64;
-    }
+    let area = polygon_area(vertices());
+    let perimeter = perimeter(vertices());
 
     // Pick's theorem counting both interior and boundary points.
-    area / 2 + perimeter / 2 + 1
-}
-
-/// Find the determinant of each pair of points casting to `i64` to prevent overflow.
-fn determinant(a: Point, b: Point) -> i64 {
-    (a.x as i64) * (b.y as i64) - (a.y as i64) * (b.x as i64)
+    interior_points(area, perimeter) + perimeter
 }
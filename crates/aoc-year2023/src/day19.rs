@@ -0,0 +1,211 @@
+//! # Aplenty
+//!
+//! Each rule is converted into a half open interval, including the start but excluding the end.
+//! For example:
+//!
+//! * `x > 10` => `10..4001`
+//! * `m < 20` => `1..20`
+//! * `A` => `1..4001`
+//!
+//! For part one if a category is contained in a range, we send the part to the next rule,
+//! stopping when `A` or `R` is reached.
+//!
+//! For part two we perform range splitting similar to [`Day 5`] that converts the category into
+//! 1, 2 or 3 new ranges, then sends those ranges to the respective rule. A [`PartRange`] is a
+//! 4 dimensional hypercube with one [`Interval`] per category.
+//!
+//! The workflows form a tree, so the ranges leaving the first workflow are independent and
+//! processed in parallel.
+//!
+//! [`Day 5`]: crate::day05
+//! [`Interval`]: aoc_util::interval
+use aoc_util::hash::*;
+use aoc_util::interval::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::thread::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Each rule matches a category inside an interval. The last rule of each workflow matches
+/// everything.
+pub struct Rule<'a> {
+    pub category: usize,
+    pub interval: Interval<u32>,
+    pub next: &'a str,
+}
+
+/// The workflows are a graph keyed by name, with edges from each rule to the next workflow.
+pub struct Input<'a> {
+    pub workflows: FastMap<&'a str, Vec<Rule<'a>>>,
+    pub parts: &'a str,
+}
+
+/// Every combination of ratings inside a 4 dimensional hypercube.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartRange {
+    pub x: Interval<u32>,
+    pub m: Interval<u32>,
+    pub a: Interval<u32>,
+    pub s: Interval<u32>,
+}
+
+impl PartRange {
+    /// All ratings from 1 to 4000 inclusive.
+    pub fn full() -> Self {
+        let interval = Interval::new(1, 4001);
+        PartRange { x: interval, m: interval, a: interval, s: interval }
+    }
+
+    /// Number of distinct parts.
+    pub fn combinations(&self) -> u64 {
+        [self.x, self.m, self.a, self.s].iter().map(|i| i.len() as u64).product()
+    }
+
+    fn get(&self, category: usize) -> Interval<u32> {
+        [self.x, self.m, self.a, self.s][category]
+    }
+
+    #[must_use]
+    fn with(mut self, category: usize, interval: Interval<u32>) -> Self {
+        match category {
+            0 => self.x = interval,
+            1 => self.m = interval,
+            2 => self.a = interval,
+            3 => self.s = interval,
+            _ => unreachable!(),
+        }
+        self
+    }
+}
+
+/// Parse each rule from the first half of the input.
+/// Leaves the second half of the input as a `&str` as it's faster to iterate over each chunk of
+/// four numbers than to first collect into a `vec`.
+pub fn parse(input: &str) -> Input<'_> {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let mut workflows = FastMap::with_capacity(1000);
+
+    for line in prefix.lines() {
+        let mut rules = Vec::with_capacity(5);
+        let mut iter = line.split(['{', ':', ',', '}']);
+        let key = iter.next().unwrap();
+
+        for [first, second] in iter.chunk::<2>() {
+            let rule = if second.is_empty() {
+                // The last rule will match everything so pick category 0 arbitrarily.
+                Rule { category: 0, interval: Interval::new(1, 4001), next: first }
+            } else {
+                // Map each category to an index for convenience so that we can store a part
+                // in a fixed size array.
+                let category = match first.as_bytes()[0] {
+                    b'x' => 0,
+                    b'm' => 1,
+                    b'a' => 2,
+                    b's' => 3,
+                    _ => unreachable!(),
+                };
+
+                let value: u32 = (&first[2..]).unsigned();
+                let next = second;
+
+                // Convert each rule into a half open range.
+                match first.as_bytes()[1] {
+                    b'<' => Rule { category, interval: Interval::new(1, value), next },
+                    b'>' => Rule { category, interval: Interval::new(value + 1, 4001), next },
+                    _ => unreachable!(),
+                }
+            };
+
+            rules.push(rule);
+        }
+
+        workflows.insert(key, rules);
+    }
+
+    Input { workflows, parts: suffix }
+}
+
+pub fn part1(input: &Input<'_>) -> u32 {
+    let Input { workflows, parts } = input;
+    let mut result = 0;
+
+    // We only care about the numbers and can ignore all delimeters and whitespace.
+    for part in parts.iter_unsigned::<u32>().chunk::<4>() {
+        let mut key = "in";
+
+        while key.len() > 1 {
+            // Find the first matching rule.
+            for &Rule { category, interval, next } in &workflows[key] {
+                if interval.contains(part[category]) {
+                    key = next;
+                    break;
+                }
+            }
+        }
+
+        if key == "A" {
+            result += part.iter().sum::<u32>();
+        }
+    }
+
+    result
+}
+
+pub fn part2(input: &Input<'_>) -> u64 {
+    // Split the ranges leaving the first workflow, then follow each branch in parallel.
+    let mut branches = Vec::new();
+    split(input, "in", PartRange::full(), |key, range| branches.push((key, range)));
+
+    let total = AtomicU64::new(0);
+
+    spawn_parallel_iterator(&branches, |iter| {
+        let mut result = 0;
+
+        for &(key, range) in iter {
+            evaluate(input, key, range, |accepted| result += accepted.combinations());
+        }
+
+        total.fetch_add(result, Ordering::Relaxed);
+    });
+
+    total.into_inner()
+}
+
+/// Sends every range that reaches `A` starting from the workflow `key` to `accepted`.
+pub fn evaluate<F>(input: &Input<'_>, key: &str, range: PartRange, mut accepted: F)
+where
+    F: FnMut(PartRange),
+{
+    let mut todo = vec![(key, range)];
+
+    while let Some((key, range)) = todo.pop() {
+        match key {
+            "A" => accepted(range),
+            "R" => (),
+            _ => split(input, key, range, |next, range| todo.push((next, range))),
+        }
+    }
+}
+
+/// Splits a range at each rule of a single workflow, sending each non empty piece to the
+/// workflow named by the matching rule.
+pub fn split<'a, F>(input: &Input<'a>, key: &str, mut range: PartRange, mut send: F)
+where
+    F: FnMut(&'a str, PartRange),
+{
+    for &Rule { category, interval, next } in &input.workflows[key] {
+        let (before, inside, after) = range.get(category).split(interval);
+
+        if !inside.is_empty() {
+            send(next, range.with(category, inside));
+        }
+
+        // Ranges before and after the rule continue to the next rule. The rules are single
+        // sided so at most one of these is non empty.
+        let rest = if before.is_empty() { after } else { before };
+        if rest.is_empty() {
+            break;
+        }
+        range = range.with(category, rest);
+    }
+}
@@ -30,8 +30,8 @@
 //! For part one, as long as all numbers are greater than 1000, then the counting pulses follow
 //! a predictable pattern that we can calculate with some bitwise logic.
 //!
-//! [`Day 8`]: crate::year2023::day08
-use crate::util::hash::*;
+//! [`Day 8`]: crate::day08
+use aoc_util::hash::*;
 
 type Input = [u32; 4];
 
@@ -85,8 +85,8 @@
 //! reachable at the edges of the diamond. For some inputs this happens to be the same as the number
 //! of tiles greater than 65 steps from the center by coincidence, however this is not guaranteed so
 //! a second BFS is more reliable solution.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 
 const CENTER: Point = Point::new(65, 65);
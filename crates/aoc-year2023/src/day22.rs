@@ -60,8 +60,8 @@
 //! * `E`'s common ancestor is the floor so its depth is 0.
 //!
 //! In total `0 (A) + 0 (G) + 1 (B) + 1 (F) + 2 (C) + 1 (D) + 0 (E) = 5` bricks will fall.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Input = (usize, usize);
 
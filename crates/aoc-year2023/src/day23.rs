@@ -1,6 +1,6 @@
-use crate::util::grid::*;
-use crate::util::hash::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 
 pub struct Input {
@@ -3,36 +3,14 @@
 //! ## Part One
 //!
 //! We find the intersection for each pair of hailstones by solving a pair of linear simultaneous
-//! equations in 2 unknowns:
+//! equations in 2 unknowns, the times `t` and `u` that the first and second hailstone
+//! respectively are at the intersection point. Ignoring the z axis, each hailstone travels
+//! along a 2D line, so this is the crossing point of two lines, solved exactly with integer
+//! arithmetic by the [`geometry`] utility module.
 //!
-//! * `a` and `g` are the x positions of the pair of hailstones.
-//! * `b` and `h` are the y positions.
-//! * `d` and `j` are the x velocities.
-//! * `e` and `k` are the x velocities.
-//! * Let `t` and `u` be the times that the first and second hailstone respectively are at the
-//!   intersection point.
-//!
-//! Then we can write:
-//!
-//! * `a + dt = g + ju` => `dt - ju = g - a`
-//! * `b + et = h + ku` => `et - ku = h - b`
-//!
-//! In matrix form:
-//!
-//! ```none
-//!     | d  -j ||u| = | g - a |
-//!     | e  -k ||t|   | h - b |
-//! ```
-//!
-//! Solve by finding the inverse of the 2x2 matrix and premultiplying both sides. The inverse is:
-//!
-//! ```none
-//!    ______1______ | -k  j |
-//!    d(-k) - (-j)e | -e  d |
-//! ```
-//!
-//! Then we check that both times are non-negative and that the intersection point is inside the
-//! target area.
+//! The times are fractions with a common positive denominator. Both must be non-negative.
+//! Instead of dividing to find the intersection point, which would round, we multiply the
+//! bounds of the target area by the denominator.
 //!
 //! ## Part Two
 //!
@@ -53,9 +31,12 @@
 //! Given the rock direction we can calculate the times that the two hailstones are intercepted
 //! then use this to determine the original position of the rock, as long as the two times
 //! are different.
-use crate::util::iter::*;
-use crate::util::math::*;
-use crate::util::parse::*;
+//!
+//! [`geometry`]: aoc_util::geometry
+use aoc_util::geometry::*;
+use aoc_util::iter::*;
+use aoc_util::math::*;
+use aoc_util::parse::*;
 use std::ops::RangeInclusive;
 
 const RANGE: RangeInclusive<i64> = 200_000_000_000_000..=400_000_000_000_000;
@@ -110,27 +91,27 @@ pub fn parse(input: &str) -> Vec<[i64; 6]> {
 }
 
 pub fn part1(input: &[[i64; 6]]) -> u32 {
+    let lines: Vec<_> = input.iter().map(|&[a, b, _, c, d, _]| Line::new([a, b], [c, d])).collect();
     let mut result = 0;
 
-    for (index, &[a, b, _, c, d, _]) in input[1..].iter().enumerate() {
-        for &[e, f, _, g, h, _] in &input[..index + 1] {
-            // If the determinant is zero there is no solution possible
-            // which implies the trajectories are parallel.
-            let determinant = d * g - c * h;
-            if determinant == 0 {
+    for (index, &first) in lines[1..].iter().enumerate() {
+        for &second in &lines[..index + 1] {
+            // Parallel trajectories either never meet or are the same line, which doesn't occur.
+            let Some(Crossing { t, u, denominator }) = first.crossing(second) else {
                 continue;
-            }
-
-            // Invert the 2x2 matrix then multiply by the respective columns to find the times.
-            let t = (g * (f - b) - h * (e - a)) / determinant;
-            let u = (c * (f - b) - d * (e - a)) / determinant;
+            };
 
-            // We can pick either the first or second hailstone to find the intersection position.
-            let x = a + t * c;
-            let y = b + t * d;
+            // Each coordinate of the intersection is `origin + t * direction / denominator`.
+            let inside = |axis: usize| {
+                let scaled =
+                    first.origin[axis] as i128 * denominator + t * first.direction[axis] as i128;
+                let low = *RANGE.start() as i128 * denominator;
+                let high = *RANGE.end() as i128 * denominator;
+                (low..=high).contains(&scaled)
+            };
 
             // Both times must be in the future and the position within the specified area.
-            if t >= 0 && u >= 0 && RANGE.contains(&x) && RANGE.contains(&y) {
+            if t >= 0 && u >= 0 && inside(0) && inside(1) {
                 result += 1;
             }
         }
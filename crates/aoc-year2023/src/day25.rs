@@ -46,10 +46,14 @@
 //! further some low level optimizations are used:
 //!
 //! * Numeric node and edge identifiers to allow `vec` to store previously seen values instead
-//!   of `HashMap`.
+//!   of `HashMap`. Each node's name is exactly 3 lowercase ASCII letters so our utility
+//!   [`FixedInterner`] assigns ids using a perfect hash.
 //! * Linked list of path from start to end, stored in a `vec` using indices for simplicity and
 //!   cache locality. This [blog post series](https://rust-unofficial.github.io/too-many-lists/)
 //!   describes the complexity of using actual references.
+//!
+//! [`FixedInterner`]: aoc_util::intern
+use aoc_util::intern::*;
 use std::collections::VecDeque;
 
 /// Store the graph as an [adjacency list](https://en.wikipedia.org/wiki/Adjacency_list).
@@ -76,15 +80,24 @@ impl Input {
 /// As both node and edge indices are contigous this allows us to use a vec to store previously
 /// seen values which is must faster than using a `HashMap`.
 pub fn parse(input: &str) -> Input {
-    let mut lookup = vec![usize::MAX; 26 * 26 * 26];
+    let mut interner = FixedInterner::<3>::new();
     let mut neighbours = Vec::with_capacity(2_000);
 
+    // First time seeing a name so push a new node.
+    let mut node = |neighbours: &mut Vec<Vec<usize>>, name: &[u8]| {
+        let index = interner.get_or_insert(name) as usize;
+        if index == neighbours.len() {
+            neighbours.push(Vec::with_capacity(10));
+        }
+        index
+    };
+
     for line in input.lines().map(str::as_bytes) {
-        let first = perfect_minimal_hash(&mut lookup, &mut neighbours, line);
+        let first = node(&mut neighbours, line);
 
         // The graph is undirected so each link is bidirectional.
         for chunk in line[5..].chunks(4) {
-            let second = perfect_minimal_hash(&mut lookup, &mut neighbours, chunk);
+            let second = node(&mut neighbours, chunk);
             neighbours[first].push(second);
             neighbours[second].push(first);
         }
@@ -119,25 +132,6 @@ pub fn part2(_input: &Input) -> &'static str {
     "n/a"
 }
 
-/// Each node's name is exactly 3 lowercase ASCII letters. First we calculate a
-/// [perfect hash](https://en.wikipedia.org/wiki/Perfect_hash_function) by converting to a base 26
-/// number. Then we construct a perfect *minimal* hash by using the first index to lookup a
-/// contigous index into the nodes vec.
-fn perfect_minimal_hash(lookup: &mut [usize], nodes: &mut Vec<Vec<usize>>, slice: &[u8]) -> usize {
-    // Base 26 index.
-    let hash = slice[..3].iter().fold(0, |acc, b| 26 * acc + ((b - b'a') as usize));
-    let mut index = lookup[hash];
-
-    // First time seeing this key so push a new node and return its index.
-    if index == usize::MAX {
-        index = nodes.len();
-        lookup[hash] = index;
-        nodes.push(Vec::with_capacity(10));
-    }
-
-    index
-}
-
 /// BFS across the graph to find the furthest nodes from start.
 fn furthest(input: &Input, start: usize) -> usize {
     let mut todo = VecDeque::new();
@@ -1,10 +1,12 @@
 //! # Historian Hysteria
 //!
 //! For part 2, the time needed to allocate memory and grow the map is a large percentage
-//! of the total. Creating the [`FastMap`] with capacity 1000 reduces this.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+//! of the total. The [`tally`] method creates the map with enough capacity for every item
+//! up front which reduces this.
+//!
+//! [`tally`]: aoc_util::iter::TallyOps::tally
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 
 type Input = (Vec<u32>, Vec<u32>);
 
@@ -24,8 +26,7 @@ pub fn part1(input: &Input) -> u32 {
 pub fn part2(input: &Input) -> u32 {
     let (left, right) = input;
 
-    let mut freq = FastMap::with_capacity(1_000);
-    right.iter().for_each(|r| *freq.entry(r).or_insert(0) += 1);
+    let freq = right.iter().tally();
 
-    left.iter().filter_map(|l| freq.get(l).map(|f| l * f)).sum()
+    left.iter().filter_map(|l| freq.get(l).map(|&f| l * f as u32)).sum()
 }
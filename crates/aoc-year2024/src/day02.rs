@@ -12,7 +12,7 @@
 //!
 //! For part two we remove each pair of deltas (or single delta at each end) then replace with
 //! the sum of the delta from the new neighbors on either side.
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Input = (u32, u32);
 
@@ -6,8 +6,8 @@
 //!
 //! For part two the difference of the ASCII values of "M" and "S" is 6. No other combination of
 //! letter has this value, so if both diagonals are a 6 then we have a match.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 pub fn parse(input: &str) -> Grid<u8> {
     Grid::parse(input)
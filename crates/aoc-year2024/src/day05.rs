@@ -3,8 +3,8 @@
 //! The input is constructed so that each possible pair that occurs in a row has a defined
 //! ordering that enables sorting with a custom `Ordering` definition. Numbers are always
 //! 2 digits so storing ordering in a fixed size 100 x 100 array is faster than using a `HashMap`.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 use std::cmp::Ordering::*;
 
 type Input = (usize, usize);
@@ -10,14 +10,18 @@
 //!     Right: (1, 1, 5, 5, 5)
 //! ```
 //!
-//! This allows us to "shortcut" to each obstacle when looking for cycles. The remaining tricky
-//! part is including the extra obstacle which is different for each point on the guard's path.
+//! This allows us to "shortcut" to each obstacle when looking for cycles. The only candidates
+//! for the extra obstacle are cells on the guard's original path, as obstacles anywhere else are
+//! never reached. The remaining tricky part is including the extra obstacle, which is different
+//! for each candidate. Rather than updating the shortcuts in its row and column, each jump checks
+//! if the extra obstacle lies between the current position and the next shortcut, which is
+//! constant time and leaves the shared shortcuts untouched.
 //!
 //! The search can be parallelized across multiple threads as each position is independent.
-use crate::util::grid::*;
-use crate::util::hash::*;
-use crate::util::point::*;
-use crate::util::thread::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::point::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub fn parse(input: &str) -> Grid<u8> {
@@ -26,68 +30,55 @@ pub fn parse(input: &str) -> Grid<u8> {
 
 /// Count distinct positions in the guard's path, which will eventually leave the grid.
 pub fn part1(grid: &Grid<u8>) -> usize {
-    let mut grid = grid.clone();
-    let mut position = grid.find(b'^').unwrap();
-    let mut direction = UP;
-    let mut result = 1;
-
-    while grid.contains(position + direction) {
-        if grid[position + direction] == b'#' {
-            direction = direction.clockwise();
-            continue;
-        }
-
-        let next = position + direction;
-
-        // Avoid double counting when the path crosses itself.
-        if grid[next] == b'.' {
-            result += 1;
-            grid[next] = b'^';
-        }
+    walk(grid).len()
+}
 
-        position = next;
-    }
+/// Check each position on the guard's path for a potential cycle.
+pub fn part2(grid: &Grid<u8>) -> usize {
+    let path = walk(grid);
+    let shortcut = Shortcut::from(grid);
+    let total = AtomicUsize::new(0);
 
-    result
+    // Use as many cores as possible to parallelize the remaining search.
+    spawn_parallel_iterator(&path[1..], |iter| worker(&shortcut, &total, iter));
+    total.into_inner()
 }
 
-/// Follow the guard's path, checking every step for a potential cycle.
-pub fn part2(grid: &Grid<u8>) -> usize {
-    let mut grid = grid.clone();
+/// Each distinct position visited by the guard in order, paired with the direction the guard
+/// was facing when first entering it. The starting position is first.
+pub fn walk(grid: &Grid<u8>) -> Vec<(Point, Point)> {
+    let mut visited = grid.same_size_with(false);
     let mut position = grid.find(b'^').unwrap();
     let mut direction = UP;
     let mut path = Vec::with_capacity(5_000);
 
+    visited[position] = true;
+    path.push((position, direction));
+
     while grid.contains(position + direction) {
         if grid[position + direction] == b'#' {
             direction = direction.clockwise();
+            continue;
         }
 
-        let next = position + direction;
+        position += direction;
 
         // Avoid double counting when the path crosses itself.
-        if grid[next] == b'.' {
+        if !visited[position] {
+            visited[position] = true;
             path.push((position, direction));
-            grid[next] = b'^';
         }
-
-        position = next;
     }
 
-    // Use as many cores as possible to parallelize the remaining search.
-    let shortcut = Shortcut::from(&grid);
-    let total = AtomicUsize::new(0);
-
-    spawn_parallel_iterator(&path, |iter| worker(&shortcut, &total, iter));
-    total.into_inner()
+    path
 }
 
 fn worker(shortcut: &Shortcut, total: &AtomicUsize, iter: ParIter<'_, (Point, Point)>) {
     let mut seen = FastSet::new();
     let result = iter
-        .filter(|(position, direction)| {
+        .filter(|&&(obstacle, direction)| {
             seen.clear();
-            is_cycle(shortcut, &mut seen, *position, *direction)
+            is_cycle(shortcut, &mut seen, obstacle - direction, direction)
         })
         .count();
 
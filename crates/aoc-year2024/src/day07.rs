@@ -42,7 +42,7 @@
 //! * 7 || 9 => 79 => 79 / 10 => 7
 //! * 12 || 34 => 1234 => 1234 / 100 => 12
 //! * 123 || 789 => 123789 => 123789 / 1000 => 123
-use crate::util::parse::*;
+use aoc_util::parse::*;
 
 type Input = (u64, u64);
 
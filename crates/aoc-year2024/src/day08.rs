@@ -1,9 +1,9 @@
 //! # Resonant Collinearity
 //!
 //! Antennas frequencies are grouped together to reduce the O(n²) pairwise comparisons.
-use crate::util::grid::*;
-use crate::util::hash::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::hash::*;
+use aoc_util::point::*;
 
 type Input = (Grid<u8>, FastMap<u8, Vec<Point>>);
 
@@ -15,12 +15,13 @@
 //! When moving a file to a free block, the corresponding heap is popped and then any leftover
 //! space is pushed back to the heap at a smaller index. The heap at index zero is not used
 //! but makes the indexing easier.
+use aoc_util::lookup_table;
 use std::iter::repeat_with;
 
 /// [Triangular numbers](https://en.wikipedia.org/wiki/Triangular_number) offset by two.
 /// Files can be a max size of 9 so we only need the first 10 values, including zero to make
 /// indexing easier.
-const TRIANGLE: [usize; 10] = [0, 0, 1, 3, 6, 10, 15, 21, 28, 36];
+const TRIANGLE: [usize; 10] = lookup_table!(10, |n| n * n.saturating_sub(1) / 2);
 
 /// Remove any trailing newlines and convert to `usize`.
 pub fn parse(input: &str) -> Vec<usize> {
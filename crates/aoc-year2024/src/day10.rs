@@ -3,8 +3,8 @@
 //! [Depth first search](https://en.wikipedia.org/wiki/Depth-first_search) for both parts.
 //! Part two is simpler than part one as we don't need to keep track of already visited points.
 //! Reverse search was slightly faster as my input contained fewer peaks `9` than valleys `0`.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 
 pub fn parse(input: &str) -> Grid<u8> {
     Grid::parse(input)
@@ -22,14 +22,9 @@ fn solve(grid: &Grid<u8>, distinct: bool) -> u32 {
     let mut result = 0;
     let mut seen = grid.same_size_with(-1);
 
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            let point = Point::new(x, y);
-            if grid[point] == b'9' {
-                let id = y * grid.width + x;
-                result += dfs(grid, distinct, &mut seen, id, point);
-            }
-        }
+    for point in grid.find_iter(|&b| b == b'9') {
+        let id = point.y * grid.width + point.x;
+        result += dfs(grid, distinct, &mut seen, id, point);
     }
 
     result
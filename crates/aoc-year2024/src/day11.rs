@@ -5,8 +5,8 @@
 //!
 //! This means that we can store the count of each distinct stone in a small contigous array
 //! that is much faster to process than a recursive memoization approach.
-use crate::util::hash::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
 
 pub fn parse(input: &str) -> Vec<u64> {
     input.iter_unsigned().collect()
@@ -0,0 +1,89 @@
+//! # Garden Groups
+//!
+//! Labels each region using our utility [`Grid`] module, then makes a single pass over every plot
+//! for each part.
+//!
+//! For part one we increment the perimeter for each neighbouring plot belonging to a different
+//! region or out of bounds.
+//!
+//! For part two the number of sides of a polygon is equal to its number of corners, which are
+//! much easier to count. Each plot checks its four diagonal directions, considering the two
+//! orthogonal neighbours and the diagonal neighbour between them. A plot has a convex corner if
+//! neither orthogonal neighbour is in the same region, or a concave corner if both are but the
+//! diagonal is not.
+//!
+//! For example, considering the top right corner of the central plot:
+//!
+//! ```none
+//!     ...            .##            .#.
+//!     .#. > convex   .## > none     .## > concave
+//!     ...            ...            ...
+//! ```
+//!
+//! [`Grid`]: aoc_util::grid
+use aoc_util::grid::*;
+use aoc_util::point::*;
+
+pub struct Input {
+    labels: Grid<usize>,
+    areas: Vec<usize>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let (labels, areas) = grid.label_regions(|a, b| a == b);
+    Input { labels, areas }
+}
+
+pub fn part1(input: &Input) -> usize {
+    price(input, &perimeters(input))
+}
+
+pub fn part2(input: &Input) -> usize {
+    price(input, &corners(input))
+}
+
+/// Perimeter of each region indexed by label.
+pub fn perimeters(input: &Input) -> Vec<usize> {
+    let Input { labels, areas } = input;
+    let mut result = vec![0; areas.len()];
+
+    for y in 0..labels.height {
+        for x in 0..labels.width {
+            let point = Point::new(x, y);
+            let same = |next| labels.contains(next) && labels[next] == labels[point];
+
+            result[labels[point]] += ORTHOGONAL.iter().filter(|&&o| !same(point + o)).count();
+        }
+    }
+
+    result
+}
+
+/// Number of corners of each region indexed by label, equal to its number of sides.
+pub fn corners(input: &Input) -> Vec<usize> {
+    let Input { labels, areas } = input;
+    let mut result = vec![0; areas.len()];
+
+    for y in 0..labels.height {
+        for x in 0..labels.width {
+            let point = Point::new(x, y);
+            let same = |next| labels.contains(next) && labels[next] == labels[point];
+
+            for first in ORTHOGONAL {
+                let second = first.clockwise();
+                let (a, b) = (same(point + first), same(point + second));
+
+                if (!a && !b) || (a && b && !same(point + first + second)) {
+                    result[labels[point]] += 1;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn price(input: &Input, lengths: &[usize]) -> usize {
+    input.areas.iter().zip(lengths).map(|(area, length)| area * length).sum()
+}
@@ -0,0 +1,60 @@
+//! # Claw Contraption
+//!
+//! Each claw machine is a system of two linear equations:
+//!
+//! ```none
+//!     (Button A X) * (A presses) + (Button B X) * (B presses) = Prize X
+//!     (Button A Y) * (A presses) + (Button B Y) * (B presses) = Prize Y
+//! ```
+//!
+//! Shortening the names and representing as a matrix:
+//!
+//! ```none
+//!     [ ax bx ][ a ] = [ px ]
+//!     [ ay by ][ b ] = [ py ]
+//! ```
+//!
+//! Geometrically, this is where the line from the origin in the direction of button A crosses the
+//! line from the prize going backwards in the direction of button B. The [`geometry`] utility
+//! module solves this exactly with Cramer's rule using integer arithmetic, then we check that
+//! the number of presses of each button is a whole number that is not negative.
+//!
+//! If the buttons move the claw in the same direction then the lines are parallel and there's
+//! no unique solution. This never happens with the actual inputs, so we treat it as unwinnable.
+//!
+//! [`geometry`]: aoc_util::geometry
+use aoc_util::geometry::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+
+const OFFSET: i64 = 10_000_000_000_000;
+
+type Claw = [i64; 6];
+
+pub fn parse(input: &str) -> Vec<Claw> {
+    input.iter_signed().chunk::<6>().collect()
+}
+
+pub fn part1(input: &[Claw]) -> i64 {
+    input.iter().map(|&claw| tokens(claw, 0)).sum()
+}
+
+pub fn part2(input: &[Claw]) -> i64 {
+    input.iter().map(|&claw| tokens(claw, OFFSET)).sum()
+}
+
+/// Number of times to press the A and B buttons to move the claw exactly to the prize, or
+/// `None` if the prize can't be won.
+pub fn solve_machine(a: [i64; 2], b: [i64; 2], prize: [i64; 2]) -> Option<[i64; 2]> {
+    let a_presses = Line::new([0, 0], a);
+    let b_presses = Line::new(prize, b.map(|n| -n));
+
+    // If the lines are parallel there's no solution. Integer solutions only.
+    let [a, b] = a_presses.crossing(b_presses).and_then(Crossing::exact)?;
+    (a >= 0 && b >= 0).then_some([a as i64, b as i64])
+}
+
+/// A presses cost 3 tokens and B presses cost 1 token.
+fn tokens([ax, ay, bx, by, px, py]: Claw, offset: i64) -> i64 {
+    solve_machine([ax, ay], [bx, by], [px + offset, py + offset]).map_or(0, |[a, b]| 3 * a + b)
+}
@@ -12,16 +12,15 @@
 //!
 //! Using the [Chinese Remainder Theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem)
 //! we combine the two times into a single time mod 10403 that is the answer.
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::parse::*;
 use std::cmp::Ordering::*;
 
 type Robot = [usize; 4];
 
 pub fn parse(input: &str) -> Vec<Robot> {
     input
-        .iter_signed::<i32>()
-        .chunk::<4>()
+        .lines()
+        .map(|line| line.extract_signed::<i32, 4>())
         .map(|[x, y, dx, dy]| {
             [x as usize, y as usize, dx.rem_euclid(101) as usize, dy.rem_euclid(103) as usize]
         })
@@ -23,9 +23,14 @@
 //! so we skip.
 //!
 //! If any next space is a wall then we cancel the entire move and return right away. Otherwise
-//! all boxes are moved in the *reverse* order that they were found by the search.
-use crate::util::grid::*;
-use crate::util::point::*;
+//! all boxes are moved in the *reverse* order that they were found by the search. Collecting the
+//! affected boxes is split into [`resolve`] so that the grid is only changed once the whole push
+//! is known to succeed.
+//!
+//! [`simulate`] accepts a callback that sees the warehouse after every move, which combined with
+//! [`render`] can be used to visualize each step.
+use aoc_util::grid::*;
+use aoc_util::point::*;
 use std::mem::swap;
 
 type Input<'a> = (Grid<u8>, &'a str);
@@ -37,48 +42,60 @@ pub fn parse(input: &str) -> Input<'_> {
 }
 
 pub fn part1(input: &Input<'_>) -> i32 {
+    gps(&simulate(input, false, |_, _| ()), b'O')
+}
+
+pub fn part2(input: &Input<'_>) -> i32 {
+    gps(&simulate(input, true, |_, _| ()), b'[')
+}
+
+/// Moves the robot through every instruction, returning the final state of the warehouse.
+/// When `stretched` is true boxes are two cells wide. `observe` is called with the grid and
+/// robot position after each move, for example to [`render`] an animation.
+pub fn simulate<F>(input: &Input<'_>, stretched: bool, mut observe: F) -> Grid<u8>
+where
+    F: FnMut(&Grid<u8>, Point),
+{
     let (grid, moves) = input;
 
     // We don't need to move the robot symbol so mark as empty space once located.
-    let mut grid = grid.clone();
+    let mut grid = if stretched { stretch(grid) } else { grid.clone() };
     let mut position = grid.find(b'@').unwrap();
     grid[position] = b'.';
 
+    // Reuse to minimize allocations.
+    let mut todo = Vec::with_capacity(50);
+
     // Treat moves as a single string ignoring any newline characters.
     for b in moves.bytes() {
         match b {
             b'<' => narrow(&mut grid, &mut position, LEFT),
             b'>' => narrow(&mut grid, &mut position, RIGHT),
+            b'^' if stretched => wide(&mut grid, &mut position, UP, &mut todo),
+            b'v' if stretched => wide(&mut grid, &mut position, DOWN, &mut todo),
             b'^' => narrow(&mut grid, &mut position, UP),
             b'v' => narrow(&mut grid, &mut position, DOWN),
-            _ => (),
+            _ => continue,
         }
+        observe(&grid, position);
     }
 
-    gps(&grid, b'O')
+    grid
 }
 
-pub fn part2(input: &Input<'_>) -> i32 {
-    let (grid, moves) = input;
+/// Draws the warehouse with the robot as `@`.
+pub fn render(grid: &Grid<u8>, robot: Point) -> String {
+    let mut result = String::with_capacity(((grid.width + 1) * grid.height) as usize);
 
-    let mut grid = stretch(grid);
-    let mut position = grid.find(b'@').unwrap();
-    grid[position] = b'.';
-
-    // Reuse to minimize allocations.
-    let mut todo = Vec::with_capacity(50);
-
-    for b in moves.bytes() {
-        match b {
-            b'<' => narrow(&mut grid, &mut position, LEFT),
-            b'>' => narrow(&mut grid, &mut position, RIGHT),
-            b'^' => wide(&mut grid, &mut position, UP, &mut todo),
-            b'v' => wide(&mut grid, &mut position, DOWN, &mut todo),
-            _ => (),
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            result.push(if point == robot { '@' } else { grid[point] as char });
         }
+        result.push('\n');
     }
 
-    gps(&grid, b'[')
+    result
 }
 
 fn narrow(grid: &mut Grid<u8>, start: &mut Point, direction: Point) {
@@ -113,11 +130,32 @@ fn wide(grid: &mut Grid<u8>, start: &mut Point, direction: Point, todo: &mut Vec
         return;
     }
 
+    // Move boxes in reverse order so that no box overwrites another that has yet to move.
+    if let Some(boxes) = resolve(grid, *start, direction, todo) {
+        for &point in boxes.iter().rev() {
+            grid[point + direction] = grid[point];
+            grid[point] = b'.';
+        }
+
+        // Move robot
+        *start += direction;
+    }
+}
+
+/// Finds every box cell that a vertical push from `start` would move, without changing the grid.
+/// Returns `None` if any box is blocked by a wall. Each box is added as its left then right cell,
+/// in the order found by the search, using `todo` as scratch space to minimize allocations.
+pub fn resolve<'a>(
+    grid: &Grid<u8>,
+    start: Point,
+    direction: Point,
+    todo: &'a mut Vec<Point>,
+) -> Option<&'a [Point]> {
     // Clear any items from previous push.
     todo.clear();
     // Add dummy item to prevent index of out bounds when checking for previously added boxes.
     todo.push(ORIGIN);
-    todo.push(*start);
+    todo.push(start);
     let mut index = 1;
 
     while index < todo.len() {
@@ -128,8 +166,8 @@ fn wide(grid: &mut Grid<u8>, start: &mut Point, direction: Point, todo: &mut Vec
         let (first, second) = match grid[next] {
             b'[' => (next, next + RIGHT),
             b']' => (next + LEFT, next),
-            b'#' => return, // Return early if there's a wall in the way.
-            _ => continue,  // Open space doesn't add any more items to move.
+            b'#' => return None, // Return early if there's a wall in the way.
+            _ => continue,       // Open space doesn't add any more items to move.
         };
 
         // Check if this box has already been added by the previous box in this row.
@@ -139,14 +177,8 @@ fn wide(grid: &mut Grid<u8>, start: &mut Point, direction: Point, todo: &mut Vec
         }
     }
 
-    // Move boxes in reverse order, skipping the dummy item and robot.
-    for &point in todo[2..].iter().rev() {
-        grid[point + direction] = grid[point];
-        grid[point] = b'.';
-    }
-
-    // Move robot
-    *start += direction;
+    // Skip the dummy item and robot.
+    Some(&todo[2..])
 }
 
 fn stretch(grid: &Grid<u8>) -> Grid<u8> {
@@ -171,16 +203,5 @@ fn stretch(grid: &Grid<u8>) -> Grid<u8> {
 }
 
 fn gps(grid: &Grid<u8>, needle: u8) -> i32 {
-    let mut result = 0;
-
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            let point = Point::new(x, y);
-            if grid[point] == needle {
-                result += 100 * point.y + point.x;
-            }
-        }
-    }
-
-    result
+    grid.find_iter(|&b| b == needle).map(|point| 100 * point.y + point.x).sum()
 }
@@ -8,8 +8,8 @@
 //! Part two is a a BFS *backwards* from the end to the finish, tracing the cost exactly
 //! to find all possible paths. This reuses the cost information from the Dijkstra without
 //! requiring any extra state keeping for the paths.
-use crate::util::grid::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 
 type Input = (i32, usize);
@@ -105,7 +105,7 @@ pub fn parse(input: &str) -> Input {
         }
     }
 
-    (lowest, path.bytes.iter().filter(|&&b| b).count())
+    (lowest, path.count(|&b| b))
 }
 
 pub fn part1(input: &Input) -> i32 {
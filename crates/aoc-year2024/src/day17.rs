@@ -0,0 +1,149 @@
+//! # Chronospatial Computer
+//!
+//! Part one implements the computer specification then runs the provided program. The `b` and `c`
+//! registers are assumed to be always zero in the provided input. The computer uses a resumable
+//! `run` method that returns `Some(out)` to indicate output and `None` to indicate program end.
+//! This is the same flexible approach used by the 2019 [`Intcode`] computer.
+//!
+//! For part two, reverse engineering the assembly shows that it implements the following
+//! algorithm:
+//!
+//! ```none
+//!     while a != 0 {
+//!         b = // Some hash based on value of a
+//!         out b
+//!         a >>= 3
+//!     }
+//! ```
+//!
+//! This means that the final value of `a` must be zero. Starting with this knowledge we work
+//! backwards digit by digit. The right shift wipes out the lowest 3 bits of `a` so there could
+//! be 8 possible previous values. We check each possible value recursively, exploring only
+//! those where the complete output of the program matches the end of the program.
+//!
+//! Although it may seem that checking could grow exponentially to 8¹⁶ potential values,
+//! in practice filtering by correct output keeps the total less than 50. Trying the lower 3 bits
+//! in ascending order means that the first complete match found is also the smallest.
+//!
+//! The [`Computer`] makes no assumptions about the program so can run arbitrary programs with
+//! any starting registers. It's an iterator over the output values.
+//!
+//! [`Intcode`]: ../../aoc_year2019/intcode/index.html
+use aoc_util::parse::*;
+use std::array::from_fn;
+use std::ops::ControlFlow;
+
+pub struct Input {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub program: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut iter = input.iter_unsigned();
+    let [a, b, c] = from_fn(|_| iter.next().unwrap());
+    Input { a, b, c, program: iter.collect() }
+}
+
+pub fn part1(input: &Input) -> String {
+    let computer = Computer::new(&input.program, input.a, input.b, input.c);
+    let out: Vec<_> = computer.map(|n| n.to_string()).collect();
+    out.join(",")
+}
+
+pub fn part2(input: &Input) -> u64 {
+    quine(&input.program, input.b, input.c).unwrap()
+}
+
+/// Finds the lowest value of register `a` that makes the program output a copy of itself,
+/// assuming that the program shifts `a` right by 3 bits each time around its loop.
+pub fn quine(program: &[u64], b: u64, c: u64) -> Option<u64> {
+    // Start with known final value of `a`.
+    helper(program, b, c, program.len(), 0).break_value()
+}
+
+fn helper(program: &[u64], b: u64, c: u64, index: usize, a: u64) -> ControlFlow<u64> {
+    if index == 0 {
+        return ControlFlow::Break(a);
+    }
+
+    // Try all 8 combination of lower 3 bits.
+    for i in 0..8 {
+        let next_a = (a << 3) | i;
+        // The program halts once `a` is zero, so every earlier value must be non-zero.
+        if next_a == 0 {
+            continue;
+        }
+
+        if Computer::new(program, next_a, b, c).eq(program[index - 1..].iter().copied()) {
+            helper(program, b, c, index - 1, next_a)?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Interpreter for the 3-bit computer, returning each output value in turn.
+pub struct Computer<'a> {
+    program: &'a [u64],
+    a: u64,
+    b: u64,
+    c: u64,
+    ip: usize,
+}
+
+impl Computer<'_> {
+    pub fn new(program: &[u64], a: u64, b: u64, c: u64) -> Computer<'_> {
+        Computer { program, a, b, c, ip: 0 }
+    }
+
+    /// Current values of the `a`, `b` and `c` registers.
+    pub fn registers(&self) -> [u64; 3] {
+        [self.a, self.b, self.c]
+    }
+}
+
+impl Iterator for Computer<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.ip + 1 < self.program.len() {
+            // Convenience closures.
+            let literal = || self.program[self.ip + 1];
+            let combo = || match self.program[self.ip + 1] {
+                n @ 0..4 => n,
+                4 => self.a,
+                5 => self.b,
+                6 => self.c,
+                _ => unreachable!(),
+            };
+
+            // Computer specification.
+            match self.program[self.ip] {
+                0 => self.a = self.a.checked_shr(combo() as u32).unwrap_or(0),
+                1 => self.b ^= literal(),
+                2 => self.b = combo() % 8,
+                3 => {
+                    if self.a != 0 {
+                        self.ip = literal() as usize;
+                        continue;
+                    }
+                }
+                4 => self.b ^= self.c,
+                5 => {
+                    let out = combo() % 8;
+                    self.ip += 2;
+                    return Some(out);
+                }
+                6 => self.b = self.a.checked_shr(combo() as u32).unwrap_or(0),
+                7 => self.c = self.a.checked_shr(combo() as u32).unwrap_or(0),
+                _ => unreachable!(),
+            }
+
+            self.ip += 2;
+        }
+
+        None
+    }
+}
@@ -27,11 +27,11 @@
 //!   This is the oldest byte that we encountered blocking the way.
 //!   Set `t` to the byte's time and push position to the dequeue.
 //! * Restart flood fill from new position until we reach the exit.
-use crate::util::grid::*;
-use crate::util::heap::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::grid::*;
+use aoc_util::heap::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::collections::VecDeque;
 
 pub fn parse(input: &str) -> Grid<i32> {
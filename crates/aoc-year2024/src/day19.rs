@@ -0,0 +1,78 @@
+//! # Linen Layout
+//!
+//! Solves both parts simultaneously. Part one is the number of designs with non-zero possible
+//! combinations.
+//!
+//! All the towels are matched against a design at once with an
+//! [Aho-Corasick](https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm) automaton from
+//! the shared [`automaton`] module. Matches are returned in order of their end position, so by
+//! the time a towel ending at `end` is found, the number of ways to make the design up to the
+//! towel's start is already final. Each match adds that number to the ways to reach `end`.
+//!
+//! Each design is independent, so designs are counted in parallel across multiple threads.
+//!
+//! [`automaton`]: aoc_util::automaton
+use aoc_util::automaton::*;
+use aoc_util::thread::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type Input = (usize, usize);
+
+/// Matches every towel pattern at once.
+pub struct Towels {
+    automaton: Automaton,
+}
+
+impl Towels {
+    pub fn new(patterns: &str) -> Self {
+        Towels { automaton: Automaton::new(patterns.split(", ")) }
+    }
+
+    /// Number of different ways that the towels can be arranged to make `design`.
+    pub fn arrangements(&self, design: &[u8]) -> usize {
+        // There's 1 way to create the empty prefix.
+        let mut ways = vec![0; design.len() + 1];
+        ways[0] = 1;
+
+        for Match { start, end, .. } in self.automaton.find_iter(design) {
+            ways[end] += ways[start];
+        }
+
+        // Last element is the total possible combinations.
+        ways[design.len()]
+    }
+}
+
+pub fn parse(input: &str) -> Input {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let towels = Towels::new(prefix);
+    let designs: Vec<_> = suffix.lines().map(str::as_bytes).collect();
+
+    // Use as many cores as possible to parallelize the calculation.
+    let part_one = AtomicUsize::new(0);
+    let part_two = AtomicUsize::new(0);
+
+    spawn_parallel_iterator(&designs, |iter| {
+        let mut possible = 0;
+        let mut total = 0;
+
+        for design in iter {
+            let ways = towels.arrangements(design);
+            possible += usize::from(ways > 0);
+            total += ways;
+        }
+
+        part_one.fetch_add(possible, Ordering::Relaxed);
+        part_two.fetch_add(total, Ordering::Relaxed);
+    });
+
+    (part_one.into_inner(), part_two.into_inner())
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.0
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.1
+}
@@ -31,38 +31,20 @@
 //!     ###      ###
 //!      #        #
 //! ```
-use crate::util::grid::*;
-use crate::util::point::*;
-use crate::util::thread::*;
+use aoc_util::grid::*;
+use aoc_util::point::*;
+use aoc_util::thread::*;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 /// Create a grid the same size as input with the time taken from start to any location.
 pub fn parse(input: &str) -> Grid<i32> {
     let grid = Grid::parse(input);
     let start = grid.find(b'S').unwrap();
-    let end = grid.find(b'E').unwrap();
+    let distance = grid.distance_from([start], |b| b != b'#');
 
-    let mut time = grid.same_size_with(i32::MAX);
-    let mut elapsed = 0;
-
-    // Find starting direction, assuming start position is surrounded by 3 walls.
-    let mut position = start;
-    let mut direction = ORTHOGONAL.into_iter().find(|&o| grid[position + o] != b'#').unwrap();
-
-    while position != end {
-        time[position] = elapsed;
-        elapsed += 1;
-
-        // There are no branches so we only ever need to go straight ahead or turn left or right.
-        direction = [direction, direction.clockwise(), direction.counter_clockwise()]
-            .into_iter()
-            .find(|&d| grid[position + d] != b'#')
-            .unwrap();
-        position += direction;
-    }
-
-    time[end] = elapsed;
-    time
+    // Unreachable walls keep a sentinel value of `i32::MAX`.
+    let bytes = distance.bytes.iter().map(|&d| i32::try_from(d).unwrap_or(i32::MAX)).collect();
+    Grid { width: grid.width, height: grid.height, bytes }
 }
 
 pub fn part1(time: &Grid<i32>) -> u32 {
@@ -3,9 +3,9 @@
 //! Each key sequence always end in `A`. This means that we can consider each group of button
 //! presses between `A`s independently using a recursive approach with memoization to efficiently
 //! compute the minimum presses needed for any depth of chained robots.
-use crate::util::hash::*;
-use crate::util::parse::*;
-use crate::util::point::*;
+use aoc_util::hash::*;
+use aoc_util::parse::*;
+use aoc_util::point::*;
 use std::iter::{once, repeat_n};
 
 type Input = (Vec<(String, usize)>, Combinations);
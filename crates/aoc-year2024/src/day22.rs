@@ -20,9 +20,10 @@
 //!
 //! A SIMD variant processes 8 hashes at a time, taking about 60% of the time of the scalar version.
 //! The bottleneck is that disjoint indices must be written in sequence reducing the amount of work
-//! that can be parallelized.
-use crate::util::parse::*;
-use crate::util::thread::*;
+//! that can be parallelized. Enabling the `compare` feature builds both versions side by side
+//! so that they can be checked against each other.
+use aoc_util::parse::*;
+use aoc_util::thread::*;
 use std::sync::Mutex;
 
 type Input = (u64, u16);
@@ -32,16 +33,14 @@ struct Exclusive {
     part_two: Vec<u16>,
 }
 
+#[cfg(not(feature = "simd"))]
 pub fn parse(input: &str) -> Input {
-    let mutex = Mutex::new(Exclusive { part_one: 0, part_two: vec![0; 130321] });
-
-    #[cfg(not(feature = "simd"))]
-    scalar::parallel(input, &mutex);
-    #[cfg(feature = "simd")]
-    simd::parallel(input, &mutex);
+    scalar::parse(input)
+}
 
-    let Exclusive { part_one, part_two } = mutex.into_inner().unwrap();
-    (part_one, *part_two.iter().max().unwrap())
+#[cfg(feature = "simd")]
+pub fn parse(input: &str) -> Input {
+    simd::parse(input)
 }
 
 pub fn part1(input: &Input) -> u64 {
@@ -52,12 +51,25 @@ pub fn part2(input: &Input) -> u16 {
     input.1
 }
 
-#[cfg(not(feature = "simd"))]
-mod scalar {
+/// Shared by both implementations, which only differ in how the secret numbers are processed.
+fn solve(input: &str, parallel: fn(&str, &Mutex<Exclusive>)) -> Input {
+    let mutex = Mutex::new(Exclusive { part_one: 0, part_two: vec![0; 130321] });
+    parallel(input, &mutex);
+
+    let Exclusive { part_one, part_two } = mutex.into_inner().unwrap();
+    (part_one, *part_two.iter().max().unwrap())
+}
+
+#[cfg(any(not(feature = "simd"), feature = "compare"))]
+pub mod scalar {
     use super::*;
 
+    pub fn parse(input: &str) -> Input {
+        solve(input, parallel)
+    }
+
     // Use as many cores as possible to parallelize the remaining search.
-    pub(super) fn parallel(input: &str, mutex: &Mutex<Exclusive>) {
+    fn parallel(input: &str, mutex: &Mutex<Exclusive>) {
         let numbers: Vec<_> = input.iter_unsigned().collect();
         spawn_parallel_iterator(&numbers, |iter| worker(mutex, iter));
     }
@@ -124,14 +136,18 @@ mod scalar {
 }
 
 #[cfg(feature = "simd")]
-mod simd {
+pub mod simd {
     use super::*;
     use std::simd::Simd;
     use std::simd::num::SimdUint as _;
 
     type Vector = Simd<u32, 8>;
 
-    pub(super) fn parallel(input: &str, mutex: &Mutex<Exclusive>) {
+    pub fn parse(input: &str) -> Input {
+        solve(input, parallel)
+    }
+
+    fn parallel(input: &str, mutex: &Mutex<Exclusive>) {
         let mut numbers: Vec<_> = input.iter_unsigned().collect();
 
         // Add zero elements so that size is a multiple of 8.
@@ -0,0 +1,83 @@
+//! # LAN Party
+//!
+//! This is the [Clique problem](https://en.wikipedia.org/wiki/Clique_problem). Each two character
+//! computer name is converted to an index from 0..676 and the network is stored as a
+//! [`BitGraph`] where the neighbours of each computer are a bitset packed into `u64` words.
+//!
+//! For part one we count triangles (cliques of size 3) that contain at least one computer
+//! starting with `t`. For each such computer `a` in turn, the third computer of every triangle
+//! through the edge `a-b` is in the intersection of the neighbours of `a` and `b`, found with a
+//! bitwise AND then a popcount. Computers starting with `t` that have already been processed are
+//! masked out so that each triangle is only counted once.
+//!
+//! Part two finds the maximum clique using the general
+//! [Bron–Kerbosch algorithm](https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm)
+//! with pivoting from the [`graph`] module.
+//!
+//! [`BitGraph`]: aoc_util::graph::BitGraph
+//! [`graph`]: aoc_util::graph
+use aoc_util::graph::*;
+use std::cmp::Ordering;
+
+const SIZE: usize = 26 * 26;
+
+/// Convert each character pair `xy` to an index from 0..676 so that we can use much faster bitset
+/// operations instead of a `HashMap`.
+pub fn parse(input: &str) -> BitGraph {
+    let mut graph = BitGraph::new(SIZE);
+    let to_index = |b: &[u8]| 26 * to_usize(b[0]) + to_usize(b[1]);
+
+    for edge in input.as_bytes().chunks(6) {
+        graph.insert(to_index(&edge[..2]), to_index(&edge[3..5]));
+    }
+
+    graph
+}
+
+pub fn part1(input: &BitGraph) -> u32 {
+    // Computers that may still be part of an uncounted triangle.
+    let mut allowed = vec![u64::MAX; SIZE.div_ceil(64)];
+    let mut triangles = 0;
+
+    // Only consider nodes starting with `t`.
+    for a in 494..520 {
+        allowed[a / 64] &= !(1 << (a % 64));
+        let first: Vec<_> = input.row(a).iter().zip(&allowed).map(|(n, m)| n & m).collect();
+
+        for b in input.neighbours(a).filter(|&b| allowed[b / 64] & (1 << (b % 64)) != 0) {
+            // Only count computers after `b` so that each pair `b, c` is counted once.
+            let after = |i: usize| match i.cmp(&(b / 64)) {
+                Ordering::Less => 0,
+                Ordering::Equal => u64::MAX.checked_shl(b as u32 % 64 + 1).unwrap_or(0),
+                Ordering::Greater => u64::MAX,
+            };
+
+            triangles += first
+                .iter()
+                .zip(input.row(b))
+                .enumerate()
+                .map(|(i, (f, n))| (f & n & after(i)).count_ones())
+                .sum::<u32>();
+        }
+    }
+
+    triangles
+}
+
+pub fn part2(input: &BitGraph) -> String {
+    // Convert each index back into 2 character identifiers sorted alphabetically.
+    let names: Vec<_> = max_clique(input)
+        .into_iter()
+        .map(|n| [to_char(n / 26), to_char(n % 26)].iter().collect::<String>())
+        .collect();
+
+    names.join(",")
+}
+
+fn to_usize(b: u8) -> usize {
+    (b - b'a') as usize
+}
+
+fn to_char(u: usize) -> char {
+    ((u as u8) + b'a') as char
+}
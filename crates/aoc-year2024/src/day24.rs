@@ -50,9 +50,9 @@
 //!
 //! We only need to find swapped outputs (not fix them) so the result is the labels of gates
 //! that breaks the rules in alphabetical order.
-use crate::util::hash::*;
-use crate::util::iter::*;
-use crate::util::parse::*;
+use aoc_util::hash::*;
+use aoc_util::iter::*;
+use aoc_util::parse::*;
 use std::collections::VecDeque;
 
 type Input<'a> = (&'a str, Vec<[&'a str; 5]>);
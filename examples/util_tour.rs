@@ -0,0 +1,95 @@
+//! A short tour of the utility modules, solving a small made up puzzle end to end.
+//!
+//! Run with `cargo run --example util_tour`.
+// Every year crate is a dependency of the example target, but is only used through `aoc`.
+#![allow(unused_crate_dependencies)]
+use aoc::util::ansi::*;
+use aoc::util::grid::*;
+use aoc::util::hash::*;
+use aoc::util::heap::*;
+use aoc::util::iter::*;
+use aoc::util::math::*;
+use aoc::util::md5::*;
+use aoc::util::parse::*;
+use aoc::util::point::*;
+use aoc::util::slice::*;
+use aoc::util::thread::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const MAZE: &str = "\
+S.#.....
+.##.###.
+....#..E
+.##...#.";
+
+const NUMBERS: &str = "Blocks at x=3 y=-2, x=5 y=7 and x=-1 y=4";
+
+fn main() {
+    // Parse integers from surrounding text, then group them into points.
+    let points: Vec<_> =
+        NUMBERS.iter_signed::<i32>().chunk::<2>().map(|[x, y]| Point::new(x, y)).collect();
+    let farthest = points.iter().map(|p| p.manhattan(ORIGIN)).max().unwrap();
+    println!("Parsed {} points, farthest is {farthest} from the origin", points.len());
+
+    // Find the shortest path through a maze with Dijkstra's algorithm.
+    let grid = Grid::parse(MAZE);
+    let start = grid.find(b'S').unwrap();
+    let end = grid.find(b'E').unwrap();
+    let mut cost = grid.same_size_with(u32::MAX);
+    let mut todo = MinHeap::new();
+
+    cost[start] = 0;
+    todo.push(0, start);
+
+    while let Some((steps, point)) = todo.pop() {
+        if point == end {
+            let (Point { x: x1, y: y1 }, Point { x: x2, y: y2 }) = (start, end);
+            println!(
+                "Shortest path from ({x1}, {y1}) to ({x2}, {y2}) takes {BOLD}{steps}{RESET} steps"
+            );
+            break;
+        }
+        for next in ORTHOGONAL.map(|o| point + o) {
+            if grid.contains(next) && grid[next] != b'#' && steps + 1 < cost[next] {
+                cost[next] = steps + 1;
+                todo.push(steps + 1, next);
+            }
+        }
+    }
+
+    // Count each symbol then keep the three most common.
+    let freq = MAZE.bytes().filter(u8::is_ascii_punctuation).tally();
+    let top: TopK<_, 3> = freq.values().copied().collect();
+    let counts: Vec<_> = top.as_slice().iter().map(ToString::to_string).collect();
+    println!("Most common symbol counts {}", counts.join(", "));
+
+    // Remember states cheaply with the fast hasher.
+    let mut seen = FastSet::new();
+    let repeats = [1, 3, 1, 2, 3].into_iter().filter(|&n| !seen.insert(n)).count();
+    println!("Saw {repeats} repeated values");
+
+    // Try every ordering of digits to find the largest number divisible by 4.
+    let mut best = 0;
+    [1_u32, 2, 3, 4].as_mut_slice().permutations(|digits| {
+        let n: u32 = digits.fold_decimal();
+        if n % 4 == 0 {
+            best = best.max(n);
+        }
+    });
+    println!("Largest permutation divisible by 4 is {best}, lcm of 4 and 6 is {}", 4.lcm(6));
+
+    // Hash a message.
+    let message = b"abc";
+    let mut buffer = vec![0; buffer_size(message.len())];
+    buffer[..message.len()].copy_from_slice(message);
+    let (a, b, c, d) = hash(&mut buffer, message.len());
+    println!("MD5 of abc is {YELLOW}{a:08x}{b:08x}{c:08x}{d:08x}{RESET}");
+
+    // Share work between threads.
+    let items: Vec<_> = (1..=1000).collect();
+    let total = AtomicU32::new(0);
+    spawn_parallel_iterator(&items, |iter| {
+        total.fetch_add(iter.sum(), Ordering::Relaxed);
+    });
+    println!("Sum of 1 to 1000 is {} using {} threads", total.into_inner(), threads());
+}
@@ -0,0 +1,130 @@
+//! Local cache of known correct answers, used to check results after each run.
+//!
+//! Answers are stored alongside the inputs in `answers/yearYYYY/dayDD.txt` with one line per part.
+//! An empty line means that the answer for that part is not yet known. An optional third line has
+//! the runtime in nanoseconds of the last full run, shown by `--list`.
+//!
+//! Answers tracked elsewhere can be imported in bulk from either:
+//! * The cache directory of the [`aocd`](https://github.com/wimglenn/advent-of-code-data) tool,
+//!   containing files named like `2015_01a_answer.txt` and `2015_01b_answer.txt`.
+//! * A plain text file with one day per line, for example `2015 1 280 1797`. The second answer is
+//!   optional. Blank lines and lines starting with `#` are ignored.
+use aoc::util::parse::*;
+use std::fs::{create_dir_all, read_dir, read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub(crate) fn path(year: u32, day: u32) -> PathBuf {
+    Path::new("answers").join(format!("year{year}")).join(format!("day{day:02}.txt"))
+}
+
+/// Returns the known answers for both parts.
+pub(crate) fn load(year: u32, day: u32) -> [Option<String>; 2] {
+    let data = read_to_string(path(year, day)).unwrap_or_default();
+    let mut lines = data.lines().map(str::trim).map(|line| {
+        let known = !line.is_empty();
+        known.then(|| line.to_owned())
+    });
+
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/// Returns the runtime of the last full run, if recorded.
+pub(crate) fn runtime(year: u32, day: u32) -> Option<Duration> {
+    let data = read_to_string(path(year, day)).ok()?;
+    data.lines().nth(2)?.trim().parse().ok().map(Duration::from_nanos)
+}
+
+/// Records the answer for a single part, preserving the other part and the runtime.
+pub(crate) fn save(year: u32, day: u32, part: usize, answer: &str) -> io::Result<()> {
+    let mut answers = load(year, day);
+    answers[part - 1] = Some(answer.to_owned());
+    store(year, day, answers, runtime(year, day))
+}
+
+/// Records the runtime of a full run, preserving the answers. Nothing is written unless answers
+/// are already cached for the day, so that runs without a cache don't create one.
+pub(crate) fn save_runtime(year: u32, day: u32, elapsed: Duration) -> io::Result<()> {
+    if !path(year, day).exists() {
+        return Ok(());
+    }
+    store(year, day, load(year, day), Some(elapsed))
+}
+
+fn store(
+    year: u32,
+    day: u32,
+    answers: [Option<String>; 2],
+    runtime: Option<Duration>,
+) -> io::Result<()> {
+    let path = path(year, day);
+    create_dir_all(path.parent().unwrap())?;
+
+    let [first, second] = answers.map(Option::unwrap_or_default);
+    match runtime {
+        Some(runtime) => write(path, format!("{first}\n{second}\n{}\n", runtime.as_nanos())),
+        None => write(path, format!("{first}\n{second}\n")),
+    }
+}
+
+/// Imports answers from an `aocd` cache directory or a plain text file, returning the
+/// `(year, day, part)` of each answer saved. Existing answers for other parts are preserved.
+pub(crate) fn import(source: &Path) -> io::Result<Vec<(u32, u32, usize)>> {
+    let mut found = Vec::new();
+
+    if source.is_dir() {
+        for entry in read_dir(source)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            // For example `2015_01a_answer.txt`.
+            let Some(key) = name.strip_suffix("_answer.txt") else {
+                continue;
+            };
+            let part = match key.as_bytes().last() {
+                Some(b'a') => 1,
+                Some(b'b') => 2,
+                _ => continue,
+            };
+            let mut iter = key.iter_unsigned();
+            let (Some(year), Some(day)) = (iter.next(), iter.next()) else {
+                continue;
+            };
+
+            found.push((year, day, part, read_to_string(&path)?.trim().to_owned()));
+        }
+    } else {
+        for line in read_to_string(source)?.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+            let [year, day, answers @ ..] = tokens.as_slice() else {
+                continue;
+            };
+            let (Some(year), Some(day)) = (year.iter_unsigned().next(), day.iter_unsigned().next())
+            else {
+                continue;
+            };
+
+            for (index, answer) in answers.iter().take(2).enumerate() {
+                found.push((year, day, index + 1, (*answer).to_owned()));
+            }
+        }
+    }
+
+    let mut imported = Vec::new();
+
+    for (year, day, part, answer) in found {
+        if !answer.is_empty() {
+            save(year, day, part, &answer)?;
+            imported.push((year, day, part));
+        }
+    }
+
+    imported.sort_unstable();
+    Ok(imported)
+}
@@ -0,0 +1,34 @@
+//! Repeated timing of a solution to reduce noise.
+//!
+//! A single wall clock sample is easily skewed by cold caches, CPU frequency scaling or other
+//! processes, making it hard to tell whether a micro-optimization helped. [`measure`] first runs
+//! a number of untimed warmup iterations, then collects samples and summarizes them. The median
+//! is the most robust to outliers, while a large standard deviation compared to the mean is a
+//! hint that the machine was busy.
+use crate::Stats;
+use std::iter::repeat_with;
+use std::time::Duration;
+
+/// Calls `sample` `warmup` times discarding the result, then `iterations` times (at least once)
+/// collecting the durations that it returns.
+pub(crate) fn measure<F>(warmup: usize, iterations: usize, mut sample: F) -> Stats
+where
+    F: FnMut() -> Duration,
+{
+    for _ in 0..warmup {
+        sample();
+    }
+
+    let mut samples: Vec<_> = repeat_with(|| sample().as_nanos()).take(iterations.max(1)).collect();
+    samples.sort_unstable();
+
+    let n = samples.len() as u128;
+    let min = samples[0];
+    let median = samples[samples.len() / 2];
+    let mean = samples.iter().sum::<u128>() / n;
+    let variance = samples.iter().map(|&s| s.abs_diff(mean).pow(2)).sum::<u128>() / n;
+
+    let [min, median, mean, stddev] =
+        [min, median, mean, variance.isqrt()].map(|ns| Duration::from_nanos(ns as u64));
+    Stats { min, median, mean, stddev }
+}
@@ -5,68 +5,31 @@
 //! [badge]: https://img.shields.io/badge/github-blue?style=for-the-badge&logo=github&labelColor=grey
 //! [link]: https://github.com/maneatingape/advent-of-code-rust
 
-// Portable SIMD API is enabled by "simd" feature.
-#![cfg_attr(feature = "simd", allow(unstable_features), feature(portable_simd))]
 // Configure rustdoc.
 #![doc(html_logo_url = "https://maneatingape.github.io/advent-of-code-rust/logo.png")]
-
-macro_rules! library {
-    ($year:tt $description:literal $($day:tt),*) => {
-        #[doc = concat!("# ", $description)]
-        pub mod $year {$(pub mod $day;)*}
-    }
-}
-
-library!(util "Utility modules to handle common recurring Advent of Code patterns."
-    ansi, bitset, grid, hash, heap, integer, iter, math, md5, parse, point, slice, thread
-);
-
-library!(year2015 "Help Santa by solving puzzles to fix the weather machine's snow function."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2016 "Defeat the Easter Bunny to save Christmas."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2017 "A technical support callout from the Elves escalates rapidly."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2018 "Travel through time to restore the festive timeline."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2019 "Rescue Santa from deep space with a solar system voyage."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25, intcode
-);
-
-library!(year2020 "What could go wrong trying to enjoy a well deserved vacation?"
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2021 "Retrieve the keys to Santa's sleigh with an underwater submarine adventure."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2022 "Assist the Elves on their annual jungle expedition."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2023 "Restore global snow production."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-
-library!(year2024 "Locate the Chief Historian in time for the big Christmas sleigh launch."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
+// Each year is a separate crate so that changing one day only recompiles that year.
+// Re-exporting them keeps the original `aoc::year2015::day01` paths.
+#![allow(clippy::pub_use)]
+
+#[doc(inline)]
+pub use aoc_util as util;
+#[doc(inline)]
+pub use aoc_year2015 as year2015;
+#[doc(inline)]
+pub use aoc_year2016 as year2016;
+#[doc(inline)]
+pub use aoc_year2017 as year2017;
+#[doc(inline)]
+pub use aoc_year2018 as year2018;
+#[doc(inline)]
+pub use aoc_year2019 as year2019;
+#[doc(inline)]
+pub use aoc_year2020 as year2020;
+#[doc(inline)]
+pub use aoc_year2021 as year2021;
+#[doc(inline)]
+pub use aoc_year2022 as year2022;
+#[doc(inline)]
+pub use aoc_year2023 as year2023;
+#[doc(inline)]
+pub use aoc_year2024 as year2024;
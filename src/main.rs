@@ -1,25 +1,284 @@
+// Every year crate is a dependency of the binary target, but is only used through `aoc`.
+#![allow(unused_crate_dependencies)]
+
 use aoc::util::ansi::*;
 use aoc::util::parse::*;
+use aoc::util::thread::{limit_threads, spawn_parallel_iterator};
 use aoc::*;
-use std::env::args;
+use std::cmp::Reverse;
+use std::env::{args, var};
+use std::fmt::{self, Write as _};
 use std::fs::read_to_string;
+use std::io::{self, Read as _};
 use std::iter::empty;
 use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
+mod answers;
+mod bench;
+mod memory;
+#[cfg(feature = "online")]
+mod online;
+mod scaffold;
+mod tui;
+mod watch;
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: memory::Tracking = memory::Tracking;
+
 fn main() {
-    // Parse command line options
+    // Optionally generate a skeleton solution for a new day then exit.
+    if args().nth(1).is_some_and(|a| a == "new" || a == "scaffold") {
+        new(&args().skip(2).collect::<Vec<_>>());
+        return;
+    }
+
+    // Optionally import known answers then exit.
+    if args().nth(1).is_some_and(|a| a == "import") {
+        import(&args().skip(2).collect::<Vec<_>>());
+        return;
+    }
+
+    let solutions = select();
+
+    // Optionally do something other than running the solutions then exit.
+    if tools(&solutions) {
+        return;
+    }
+
+    // Optionally limit the time each solution can take, for example `--timeout 10s`.
+    let Ok(timeout) = timeout() else {
+        return;
+    };
+
+    // Optionally cap resources for reproducible benchmarks, for example `--max-threads 4` and
+    // `--max-mem 512M`.
+    if max_threads().is_err() || max_mem().is_err() {
+        return;
+    }
+
+    // Optionally only run one part, for example `--part 1` to skip a slow part two.
+    let Ok(part) = part() else {
+        return;
+    };
+
+    // Optionally browse solutions in an interactive grid, running days on demand.
+    if args().any(|a| a == "--tui") {
+        tui::dashboard(&solutions, part, timeout);
+        return;
+    }
+
+    // Optionally benchmark each solution then exit, for example `--bench --iterations 100`.
+    if args().any(|a| a == "--bench") {
+        let (Ok(warmup), Ok(iterations)) = (count("--warmup", 3), count("--iterations", 20)) else {
+            return;
+        };
+        benchmark(&solutions, part, warmup, iterations);
+        return;
+    }
+
+    // Optionally choose the output format, for example `--output json` to emit one JSON object
+    // per solution with timings in nanoseconds. `--plain` is shorthand for `--output plain`.
+    // `--report md` or `--report csv` prints a table of runtimes instead of the answers.
+    let Some(format) = output_format() else {
+        eprintln!(
+            "{BOLD}{RED}Unknown output format, expected pretty, plain, json, md or csv{RESET}"
+        );
+        return;
+    };
+
+    // Optionally read the input for a single day from stdin instead of the `input` directory,
+    // for example `cat input.txt | cargo run -- 2022 16 --stdin`.
+    let stdin =
+        if args().any(|a| a == "--stdin") { stdin(&solutions).map(Some) } else { Some(None) };
+    let Some(piped) = stdin else {
+        return;
+    };
+
+    // Optionally run solutions on every core at once, still printing results in order.
+    let parallel = args().any(|a| a == "--parallel");
+    let wall = Instant::now();
+    let mut precomputed = parallel
+        .then(|| run_parallel(&solutions, piped.as_ref(), part, timeout))
+        .map(Vec::into_iter);
+
+    // Print output for each solution.
+    let mut timings = Vec::new();
+    let mut breakdown = Vec::new();
+    let mut checks = Vec::new();
+
+    for solution in &solutions {
+        let Solution { year, day, path, .. } = solution;
+        let outcome = match &mut precomputed {
+            Some(outcomes) => outcomes.next().unwrap(),
+            None => run(solution, piped.as_ref(), part, timeout),
+        };
+
+        let (answers, elapsed) = match outcome {
+            Outcome::Missing => {
+                missing(*year, *day, path, format);
+                continue;
+            }
+            Outcome::Timeout(elapsed) => {
+                timed_out(*year, *day, elapsed, format);
+                continue;
+            }
+            Outcome::Done(answers, elapsed) => (answers, elapsed),
+        };
+        // Skip timings that are skewed by a timed out solution still running in the background.
+        if !answers.skewed {
+            timings.push((*year, *day, elapsed));
+            breakdown.push((*year, *day, answers.timings));
+
+            // Remember how long a full run on our own input took, for `--list`.
+            if piped.is_none() && part.is_none() && !parallel {
+                if let Err(error) = answers::save_runtime(*year, *day, elapsed) {
+                    eprintln!("{BOLD}{RED}{error}{RESET}");
+                }
+            }
+        }
+
+        // Known answers are only for our own inputs.
+        let known = if piped.is_some() { [None, None] } else { answers::load(*year, *day) };
+        let check = verify(&answers, known, part);
+        show(*year, *day, &answers, &check, format);
+        checks.push((*year, *day, check));
+    }
+
+    if parallel && format == Format::Pretty {
+        aggregate(&timings, wall);
+    }
+
+    // Optionally print a table of runtimes.
+    if matches!(format, Format::Markdown | Format::Csv) {
+        report(&breakdown, format);
+    }
+
+    // Optionally print totals and the slowest days, for example `--slowest 20`.
+    if format == Format::Pretty {
+        if args().any(|a| a == "--totals") {
+            totals(&timings, 2 * solutions.len());
+        }
+        if let Some(n) = slowest_count() {
+            slowest(&mut timings, n);
+        }
+    }
+
+    // Compare against known answers, exiting with an error if any are wrong so that the runner
+    // can be used as a regression test against real inputs.
+    if !summarize(&checks, format) {
+        exit(1);
+    }
+}
+
+/// Submits, downloads, watches or lists instead of running, returning `true` if any of these
+/// options is present.
+fn tools(solutions: &[Solution]) -> bool {
+    // Optionally submit a single answer then exit.
+    #[cfg(feature = "online")]
+    if let Some(index) = args().position(|a| a == "--submit") {
+        let options: Vec<_> = args().skip(index + 1).collect();
+        submit(solutions, &options);
+        return true;
+    }
+
+    // Optionally download any missing inputs then exit.
+    #[cfg(feature = "online")]
+    if args().any(|a| a == "--download") {
+        download(solutions);
+        return true;
+    }
+
+    // Optionally re-run a single day every time its source or input file changes, for example
+    // `cargo run --release -- 2022 16 --watch`.
+    if args().any(|a| a == "--watch") {
+        watch::watch(solutions);
+        return true;
+    }
+
+    // Optionally list solutions, whether each input is present and any previously known answers
+    // without running anything.
+    if args().any(|a| a == "--list") {
+        list(solutions);
+        return true;
+    }
+
+    false
+}
+
+/// Runs a single solution, reading its input from the `input` directory unless piped.
+fn run(
+    solution: &Solution,
+    piped: Option<&String>,
+    part: Option<u32>,
+    timeout: Option<Duration>,
+) -> Outcome {
+    let Solution { year, day, path, wrapper } = solution;
+    let Some(data) = piped.cloned().or_else(|| read_to_string(path).ok()) else {
+        return Outcome::Missing;
+    };
+
+    memory::reset(*year, *day);
+    let skewed = ABANDONED.load(Ordering::Relaxed);
+    let instant = Instant::now();
+    let result = execute(*wrapper, data, part, timeout);
+    memory::finish();
+    match result {
+        Some(answers) => Outcome::Done(Answers { skewed, ..answers }, instant.elapsed()),
+        None => Outcome::Timeout(instant.elapsed()),
+    }
+}
+
+/// Runs every solution using a pool of worker threads, returning the outcomes in the same order
+/// as the solutions.
+fn run_parallel(
+    solutions: &[Solution],
+    piped: Option<&String>,
+    part: Option<u32>,
+    timeout: Option<Duration>,
+) -> Vec<Outcome> {
+    eprintln!(
+        "{BOLD}{YELLOW}Solutions compete for cores so per day timings aren't comparable{RESET}"
+    );
+
+    let indices: Vec<_> = (0..solutions.len()).collect();
+    let outcomes = Mutex::new(Vec::with_capacity(solutions.len()));
+
+    spawn_parallel_iterator(&indices, |iter| {
+        for &index in iter {
+            let outcome = run(&solutions[index], piped, part, timeout);
+            outcomes.lock().unwrap().push((index, outcome));
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_unstable_by_key(|&(index, _)| index);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+/// Solutions chosen on the command line, for example `year2023::day01` or `2023 1`. All
+/// solutions if none are specified.
+fn select() -> Vec<Solution> {
     let (year, day) = match args().nth(1) {
         Some(arg) => {
             let str = arg.as_str();
             let mut iter = str.iter_unsigned();
-            (iter.next(), iter.next())
+            let year = iter.next();
+            let day = iter.next().or_else(|| {
+                let next = args().nth(2).filter(|a| a.bytes().all(|b| b.is_ascii_digit()));
+                year.and(next).map(|a| a.as_str().unsigned())
+            });
+            (year, day)
         }
         None => (None, None),
     };
 
     // Filter solutions
-    let solutions: Vec<_> = empty()
+    empty()
         .chain(year2015())
         .chain(year2016())
         .chain(year2017())
@@ -32,39 +291,723 @@ fn main() {
         .chain(year2024())
         .filter(|solution| year.is_none_or(|y: u32| y == solution.year))
         .filter(|solution| day.is_none_or(|d: u32| d == solution.day))
-        .collect();
-
-    // Pretty print output for each solution.
-    let mut duration = Duration::ZERO;
+        .collect()
+}
 
-    for Solution { year, day, path, wrapper } in &solutions {
-        if let Ok(data) = read_to_string(path) {
-            let instant = Instant::now();
-            let (part1, part2) = wrapper(data);
-            duration += instant.elapsed();
+/// Output format from `--output` or `--report`, defaulting to pretty unless `--plain` is present.
+fn output_format() -> Option<Format> {
+    match option("--output").or_else(|| option("--report")).as_deref() {
+        Some("json") => Some(Format::Json),
+        Some("plain") => Some(Format::Plain),
+        Some("pretty") => Some(Format::Pretty),
+        Some("md") => Some(Format::Markdown),
+        Some("csv") => Some(Format::Csv),
+        Some(_) => None,
+        None if args().any(|a| a == "--plain") => Some(Format::Plain),
+        None => Some(Format::Pretty),
+    }
+}
 
+/// Prints the answers for one solution.
+fn show(year: u32, day: u32, answers: &Answers, check: &[Check; 2], format: Format) {
+    match format {
+        Format::Pretty => {
             println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
-            println!("    Part 1: {part1}");
-            println!("    Part 2: {part2}");
+            if check[0] != Check::Skipped {
+                println!("    Part 1: {}{}", answers.part1, check[0]);
+            }
+            if check[1] != Check::Skipped {
+                println!("    Part 2: {}{}", answers.part2, check[1]);
+            }
+        }
+        Format::Plain => {
+            println!("{year},{day},{},{}", field(&answers.part1), field(&answers.part2));
+        }
+        Format::Json => json(year, day, answers, check, memory::peak()),
+        // Reports are printed once every solution has run.
+        Format::Markdown | Format::Csv => (),
+    }
+}
+
+/// Compares each part that ran against the known answer, if any.
+fn verify(answers: &Answers, known: [Option<String>; 2], part: Option<u32>) -> [Check; 2] {
+    let [known1, known2] = known;
+    let check = |index: u32, actual: &str, known: Option<String>| {
+        if part.is_some_and(|p| p != index) {
+            Check::Skipped
+        } else if let Some(expected) = known {
+            if actual == expected { Check::Pass } else { Check::Fail(expected) }
         } else {
+            Check::Unknown
+        }
+    };
+
+    [check(1, &answers.part1, known1), check(2, &answers.part2, known2)]
+}
+
+/// Prints how many known answers matched and lists any that didn't, returning true if every
+/// answer was correct.
+fn summarize(checks: &[(u32, u32, [Check; 2])], format: Format) -> bool {
+    let mut passed = 0;
+    let mut failed = Vec::new();
+
+    for (year, day, check) in checks {
+        for (part, check) in (1..).zip(check) {
+            match check {
+                Check::Pass => passed += 1,
+                Check::Fail(_) => failed.push((year, day, part)),
+                Check::Skipped | Check::Unknown => (),
+            }
+        }
+    }
+
+    if format == Format::Pretty && passed + failed.len() > 0 {
+        println!("{BOLD}{GREEN}{passed} PASS{RESET}");
+        if !failed.is_empty() {
+            println!("{BOLD}{RED}{} FAIL{RESET}", failed.len());
+        }
+        for (year, day, part) in &failed {
+            println!("    {RED}{year} Day {day:02} Part {part}{RESET}");
+        }
+    }
+
+    failed.is_empty()
+}
+
+/// Reads all of stdin as the input for the only selected solution.
+fn stdin(solutions: &[Solution]) -> Option<String> {
+    if solutions.len() != 1 {
+        eprintln!("{BOLD}{RED}--stdin needs exactly one day, for example 2022 16{RESET}");
+        return None;
+    }
+
+    let mut data = String::new();
+    if let Err(error) = io::stdin().read_to_string(&mut data) {
+        eprintln!("{BOLD}{RED}Unable to read stdin: {error}{RESET}");
+        return None;
+    }
+    Some(data)
+}
+
+/// Explains where to put an input file that doesn't exist.
+fn missing(year: u32, day: u32, path: &Path, format: Format) {
+    if format == Format::Json {
+        println!("{{\"year\":{year},\"day\":{day},\"status\":\"missing\"}}");
+    } else if format == Format::Plain {
+        eprintln!("{year},{day},missing");
+    } else {
+        eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
+        eprintln!("    Missing input!");
+        eprintln!("    Place input file in {BOLD}{WHITE}{}{RESET}", path.display());
+        #[cfg(feature = "online")]
+        eprintln!("    or download it with {BOLD}{WHITE}--download{RESET}");
+    }
+}
+
+/// Reports a solution that didn't finish within the time limit.
+fn timed_out(year: u32, day: u32, elapsed: Duration, format: Format) {
+    match format {
+        Format::Pretty | Format::Markdown | Format::Csv => {
             eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
-            eprintln!("    Missing input!");
-            eprintln!("    Place input file in {BOLD}{WHITE}{}{RESET}", path.display());
+            eprintln!("    Timed out after {} ms", elapsed.as_millis());
+            eprintln!("    Still running in the background so later timings won't be reported");
+        }
+        Format::Plain => eprintln!("{year},{day},timeout"),
+        Format::Json => println!(
+            "{{\"year\":{year},\"day\":{day},\"status\":\"timeout\",\"total_ns\":{}}}",
+            elapsed.as_nanos()
+        ),
+    }
+}
+
+/// Prints a single line JSON object with the answers and timings of one solution. A part that
+/// didn't run has a `null` answer. Peak memory is `null` unless `--max-mem` enabled tracking.
+fn json(year: u32, day: u32, answers: &Answers, check: &[Check; 2], peak: Option<usize>) {
+    let part1 = if check[0] == Check::Skipped { "null".to_owned() } else { quote(&answers.part1) };
+    let part2 = if check[1] == Check::Skipped { "null".to_owned() } else { quote(&answers.part2) };
+    let peak = peak.map_or_else(|| "null".to_owned(), |peak| peak.to_string());
+
+    // Timings skewed by a timed out solution are `null`.
+    let [parse, solve1, solve2] = answers.timings.map(|d| d.as_nanos());
+    let [parse, solve1, solve2, total] = [parse, solve1, solve2, parse + solve1 + solve2]
+        .map(|ns| if answers.skewed { "null".to_owned() } else { ns.to_string() });
+
+    println!(
+        "{{\"year\":{year},\"day\":{day},\"status\":\"ok\",\"part1\":{part1},\
+         \"part2\":{part2},\"parse_ns\":{parse},\"part1_ns\":{solve1},\"part2_ns\":{solve2},\
+         \"total_ns\":{total},\"peak_bytes\":{peak}}}"
+    );
+}
+
+/// Runs each solution repeatedly and prints statistics of the combined parse and solve time.
+fn benchmark(solutions: &[Solution], part: Option<u32>, warmup: usize, iterations: usize) {
+    let micros = |elapsed: Duration| {
+        let nanos = elapsed.as_nanos();
+        format!("{}.{:03} μs", nanos / 1000, nanos % 1000)
+    };
+
+    for Solution { year, day, path, wrapper } in solutions {
+        let Ok(data) = read_to_string(path) else {
+            missing(*year, *day, path, Format::Pretty);
+            continue;
+        };
+
+        let Stats { min, median, mean, stddev } =
+            bench::measure(warmup, iterations, || wrapper(data.clone(), part).timings.iter().sum());
+
+        println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+        println!("    Min    {:>14}", micros(min));
+        println!("    Median {:>14}", micros(median));
+        println!("    Mean   {:>14}", micros(mean));
+        println!("    Stddev {:>14}", micros(stddev));
+    }
+}
+
+/// Compares the wall clock time of a parallel run to the sum of the individual runtimes.
+fn aggregate(timings: &[(u32, u32, Duration)], wall: Instant) {
+    let aggregate: Duration = timings.iter().map(|&(_, _, elapsed)| elapsed).sum();
+    println!("{BOLD}{WHITE}🕓 {} ms wall clock{RESET}", wall.elapsed().as_millis());
+    println!("{BOLD}{WHITE}🕓 {} ms aggregate{RESET}", aggregate.as_millis());
+}
+
+/// Prints each solution, whether its input is present and any previously known answers and
+/// runtime.
+fn list(solutions: &[Solution]) {
+    for Solution { year, day, path, .. } in solutions {
+        println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+
+        if path.exists() {
+            println!("    {GREEN}Input present{RESET}");
+        } else {
+            println!("    {RED}Missing input{RESET} {}", path.display());
+        }
+
+        let [part1, part2] = answers::load(*year, *day);
+        if let Some(answer) = part1 {
+            println!("    Part 1: {answer}");
+        }
+        if let Some(answer) = part2 {
+            println!("    Part 2: {answer}");
+        }
+        if let Some(runtime) = answers::runtime(*year, *day) {
+            println!("    Last run: {} μs", runtime.as_micros());
+        }
+    }
+}
+
+/// Prints the grand total and the subtotal of each year with its share of the total time.
+fn totals(timings: &[(u32, u32, Duration)], stars: usize) {
+    let duration: Duration = timings.iter().map(|&(_, _, elapsed)| elapsed).sum();
+
+    println!("{BOLD}{YELLOW}⭐ {stars}{RESET}");
+    println!("{BOLD}{WHITE}🕓 {} ms{RESET}", duration.as_millis());
+
+    // Timings are already in year order.
+    for chunk in timings.chunk_by(|a, b| a.0 == b.0) {
+        let year = chunk[0].0;
+        let subtotal: Duration = chunk.iter().map(|&(_, _, elapsed)| elapsed).sum();
+        let (millis, percent) = (subtotal.as_millis(), share(subtotal, duration));
+        println!("    {YELLOW}{year}{RESET} {millis:>6} ms {percent:>6}");
+    }
+}
+
+/// Prints the `n` slowest days in descending order of runtime.
+fn slowest(timings: &mut [(u32, u32, Duration)], n: usize) {
+    let duration: Duration = timings.iter().map(|&(_, _, elapsed)| elapsed).sum();
+    timings.sort_unstable_by_key(|&(_, _, elapsed)| Reverse(elapsed));
+
+    println!("{BOLD}{WHITE}Slowest{RESET}");
+    for &(year, day, elapsed) in timings.iter().take(n) {
+        let (micros, percent) = (elapsed.as_micros(), share(elapsed, duration));
+        println!("    {YELLOW}{year} Day {day:02}{RESET} {micros:>9} μs {percent:>6}");
+    }
+}
+
+/// Number of slowest days to print. `--slowest` defaults to 10 if not followed by a number and
+/// `--totals` also includes the 10 slowest days.
+fn slowest_count() -> Option<usize> {
+    if args().any(|a| a == "--slowest") {
+        let n =
+            option("--slowest").filter(|a| !a.is_empty() && a.bytes().all(|b| b.is_ascii_digit()));
+        Some(n.map_or(10, |a| a.as_str().unsigned()))
+    } else {
+        args().any(|a| a == "--totals").then_some(10)
+    }
+}
+
+/// Percentage of the total time to one decimal place, using integer math.
+fn share(elapsed: Duration, total: Duration) -> String {
+    let tenths = 1000 * elapsed.as_nanos() / total.as_nanos().max(1);
+    format!("{}.{}%", tenths / 10, tenths % 10)
+}
+
+/// Prints the parse and solve time of each day in microseconds followed by a subtotal for each
+/// year, as either a Markdown table or comma separated values, ready to paste into release notes.
+fn report(breakdown: &[(u32, u32, [Duration; 3])], format: Format) {
+    let row = |year: &str, day: &str, timings: [Duration; 3]| {
+        let [parse, part1, part2] = timings.map(|d| d.as_micros());
+        let total = parse + part1 + part2;
+
+        if format == Format::Csv {
+            println!("{year},{day},{parse},{part1},{part2},{total}");
+        } else {
+            println!("| {year} | {day} | {parse} | {part1} | {part2} | {total} |");
+        }
+    };
+
+    if format == Format::Csv {
+        println!("year,day,parse_us,part1_us,part2_us,total_us");
+    } else {
+        println!("| Year | Day | Parse (μs) | Part 1 (μs) | Part 2 (μs) | Total (μs) |");
+        println!("| --- | --- | --: | --: | --: | --: |");
+    }
+
+    // Solutions are already in year order.
+    for chunk in breakdown.chunk_by(|a, b| a.0 == b.0) {
+        let mut subtotal = [Duration::ZERO; 3];
+
+        for &(year, day, timings) in chunk {
+            row(&year.to_string(), &day.to_string(), timings);
+            for (total, elapsed) in subtotal.iter_mut().zip(timings) {
+                *total += elapsed;
+            }
+        }
+
+        let year = chunk[0].0.to_string();
+        if format == Format::Csv {
+            row(&year, "total", subtotal);
+        } else {
+            row(&format!("**{year}**"), "**Total**", subtotal);
+        }
+    }
+}
+
+/// Runs a single solution then submits the answer for one part, for example `--submit 2024 5 1`.
+/// If the part is omitted then submits the first part without a known correct answer.
+#[cfg(feature = "online")]
+fn submit(solutions: &[Solution], options: &[String]) {
+    use online::Verdict::*;
+
+    let usage = || eprintln!("{BOLD}{RED}Usage: --submit <year> <day> [part]{RESET}");
+    let number = |s: &String| s.parse::<u32>().ok();
+
+    let [year, day, rest @ ..] = options else {
+        usage();
+        return;
+    };
+    let (Some(year), Some(day)) = (number(year), number(day)) else {
+        usage();
+        return;
+    };
+
+    // Day 25 only has one puzzle. Skip trailing flags such as `--plain` when looking for a part.
+    let parts = if day == 25 { 1 } else { 2 };
+    let part = if let Some(part) = rest.first().filter(|a| !a.starts_with('-')) {
+        let Some(part @ 1..=2) = number(part).filter(|&part| part as usize <= parts) else {
+            usage();
+            return;
+        };
+        part
+    } else {
+        let Some(index) = answers::load(year, day)[..parts].iter().position(Option::is_none) else {
+            println!("{BOLD}{WHITE}{year} Day {day:02} already solved{RESET}");
+            return;
+        };
+        index as u32 + 1
+    };
+
+    let Some(Solution { path, wrapper, .. }) =
+        solutions.iter().find(|solution| solution.year == year && solution.day == day)
+    else {
+        eprintln!("{BOLD}{RED}{year} Day {day:02} not found{RESET}");
+        return;
+    };
+    let Ok(data) = read_to_string(path) else {
+        eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
+        eprintln!("    Missing input!");
+        return;
+    };
+
+    let Answers { part1, part2, .. } = wrapper(data, Some(part));
+    let answer = if part == 1 { part1 } else { part2 };
+    println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+    println!("    Submitting part {part}: {answer}");
+
+    match online::submit(year, day, part as usize, &answer) {
+        Ok(Correct) => println!("    {BOLD}{GREEN}Correct!{RESET}"),
+        Ok(TooHigh) => println!("    {BOLD}{RED}Too high{RESET}"),
+        Ok(TooLow) => println!("    {BOLD}{RED}Too low{RESET}"),
+        Ok(Incorrect) => println!("    {BOLD}{RED}Incorrect{RESET}"),
+        Ok(Wait(time)) => println!("    {BOLD}{RED}Submitted too recently, wait {time}{RESET}"),
+        Ok(AlreadySolved) => println!("    {BOLD}{WHITE}Already solved{RESET}"),
+        Ok(Unknown) => println!("    {BOLD}{RED}Unrecognized response{RESET}"),
+        Err(message) => eprintln!("    {BOLD}{RED}{message}{RESET}"),
+    }
+}
+
+/// Downloads every missing input, stopping at the first error, for example `year2023 --download`.
+#[cfg(feature = "online")]
+fn download(solutions: &[Solution]) {
+    use std::thread::sleep;
+
+    let mut downloaded = 0;
+
+    for Solution { year, day, path, .. } in solutions {
+        if path.exists() {
+            continue;
+        }
+        if downloaded > 0 {
+            sleep(online::DOWNLOAD_DELAY);
+        }
+
+        println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+        if let Err(message) = online::download(*year, *day, path) {
+            eprintln!("    {BOLD}{RED}{message}{RESET}");
+            return;
+        }
+        println!("    {GREEN}Downloaded{RESET} {}", path.display());
+        downloaded += 1;
+    }
+
+    println!("{BOLD}{GREEN}Downloaded {downloaded} inputs{RESET}");
+}
+
+/// Location of the input for a day, by default `input/yearYYYY/dayDD.txt`. Either `--input-dir`
+/// or the `AOC_INPUT_DIR` environment variable changes the root directory. If the value contains
+/// `{year}` or `{day}` then it's a template for the whole path instead, for example
+/// `--input-dir ~/inputs/{year}/{day}.txt`, where the day is padded to two digits.
+fn input_path(year: u32, day: u32) -> PathBuf {
+    let root = option("--input-dir").or_else(|| var("AOC_INPUT_DIR").ok());
+
+    match root {
+        Some(template) if template.contains("{year}") || template.contains("{day}") => {
+            let path = template.replace("{year}", &year.to_string());
+            PathBuf::from(path.replace("{day}", &format!("{day:02}")))
+        }
+        root => {
+            let root = root.map_or_else(|| PathBuf::from("input"), PathBuf::from);
+            root.join(format!("year{year}")).join(format!("day{day:02}.txt"))
+        }
+    }
+}
+
+/// Parses a duration in seconds with an optional `s` suffix, or milliseconds with an `ms` suffix,
+/// for example `10s` or `500ms`. Returns `None` for anything else, including units such as `m`.
+fn duration(arg: &str) -> Option<Duration> {
+    let (digits, millis) = if let Some(digits) = arg.strip_suffix("ms") {
+        (digits, 1)
+    } else {
+        (arg.strip_suffix('s').unwrap_or(arg), 1000)
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse::<u64>().ok()?.checked_mul(millis).map(Duration::from_millis)
+}
+
+/// Value following a command line flag.
+fn option(flag: &str) -> Option<String> {
+    args().skip_while(|a| a != flag).nth(1)
+}
+
+/// Part from `--part`, if present. Returns `Err` after printing the usage if the part isn't
+/// `1` or `2`.
+fn part() -> Result<Option<u32>, ()> {
+    match option("--part").map(|a| a.parse()) {
+        Some(Ok(part @ 1..=2)) => Ok(Some(part)),
+        Some(_) => {
+            eprintln!("{BOLD}{RED}Usage: --part <1 or 2>{RESET}");
+            Err(())
+        }
+        None => Ok(None),
+    }
+}
+
+/// Number following `flag`, or `default` if absent. Returns `Err` after printing the usage if it
+/// isn't a number.
+fn count(flag: &str, default: usize) -> Result<usize, ()> {
+    match option(flag).map(|a| a.parse()) {
+        Some(Ok(n)) => Ok(n),
+        Some(Err(_)) => {
+            eprintln!("{BOLD}{RED}Usage: {flag} <number>{RESET}");
+            Err(())
+        }
+        None => Ok(default),
+    }
+}
+
+/// Limits worker threads from `--max-threads`, if present. Returns `Err` after printing the usage
+/// if the limit isn't a positive number.
+fn max_threads() -> Result<(), ()> {
+    match option("--max-threads").map(|a| a.parse()) {
+        Some(Ok(n @ 1..)) => {
+            limit_threads(n);
+            Ok(())
+        }
+        Some(_) => {
+            eprintln!("{BOLD}{RED}Usage: --max-threads <number>, for example 4{RESET}");
+            Err(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Enables the memory limit from `--max-mem`, if present. Returns `Err` after printing the usage
+/// if the limit can't be parsed. Memory is shared by every solution running at the same time, so
+/// the limit is ignored with `--parallel`. Allocations can only be tracked with the `memory`
+/// feature.
+fn max_mem() -> Result<(), ()> {
+    if cfg!(not(feature = "memory")) {
+        if option("--max-mem").is_some() {
+            eprintln!("{BOLD}{RED}--max-mem needs the memory feature, ignoring it{RESET}");
+        }
+        return Ok(());
+    }
+    if args().any(|a| a == "--parallel") {
+        if option("--max-mem").is_some() {
+            eprintln!("{BOLD}{YELLOW}--max-mem is ignored with --parallel{RESET}");
+        }
+        return Ok(());
+    }
+
+    match option("--max-mem").map(|a| bytes(&a)) {
+        Some(Some(limit)) => {
+            memory::enable(limit);
+            Ok(())
+        }
+        Some(None) => {
+            eprintln!("{BOLD}{RED}Usage: --max-mem <bytes>, for example 512M or 2G{RESET}");
+            Err(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Parses a number of bytes with an optional `K`, `M` or `G` suffix, for example `512M`.
+/// Returns `None` for anything else, including suffixes such as `MB`.
+fn bytes(arg: &str) -> Option<usize> {
+    let (digits, shift) = match arg.as_bytes().last()?.to_ascii_uppercase() {
+        b'K' => (&arg[..arg.len() - 1], 10),
+        b'M' => (&arg[..arg.len() - 1], 20),
+        b'G' => (&arg[..arg.len() - 1], 30),
+        _ => (arg, 0),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse::<usize>().ok()?.checked_mul(1 << shift)
+}
+
+/// Quotes an answer for comma separated output if it contains a comma, quote or newline.
+fn field(answer: &str) -> String {
+    if answer.contains([',', '"', '\n']) {
+        format!("\"{}\"", answer.replace('"', "\"\""))
+    } else {
+        answer.to_owned()
+    }
+}
+
+/// Escapes an answer as a JSON string.
+fn quote(answer: &str) -> String {
+    let mut result = String::from("\"");
+
+    for c in answer.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(result, "\\u{:04x}", c as u32);
+            }
+            c => result.push(c),
         }
     }
 
-    // Optionally print totals.
-    if args().any(|a| a == "--totals") {
-        println!("{BOLD}{YELLOW}⭐ {}{RESET}", 2 * solutions.len());
-        println!("{BOLD}{WHITE}🕓 {} ms{RESET}", duration.as_millis());
+    result.push('"');
+    result
+}
+
+/// Set once a solution has timed out. Its thread can't be stopped and keeps competing for the
+/// CPU, so the timings of every later solution are skewed.
+static ABANDONED: AtomicBool = AtomicBool::new(false);
+
+/// Time limit for each solution, for example `--timeout 10s`. Solutions are run on a worker
+/// thread to enforce the limit, so this isn't available with the `no-threads` feature. Returns
+/// `Err` after printing the usage if the limit can't be parsed.
+fn timeout() -> Result<Option<Duration>, ()> {
+    let timeout = match option("--timeout").map(|a| duration(&a)) {
+        Some(Some(timeout)) => Some(timeout),
+        Some(None) => {
+            eprintln!("{BOLD}{RED}Usage: --timeout <duration>, for example 10s or 500ms{RESET}");
+            return Err(());
+        }
+        None => None,
+    };
+    if cfg!(feature = "no-threads") && timeout.is_some() {
+        eprintln!("{BOLD}{RED}--timeout needs threads, ignoring it{RESET}");
+        return Ok(None);
     }
+    Ok(timeout)
+}
+
+/// Runs a solution on a worker thread if there is a time limit. If the deadline passes then
+/// returns `None` and the thread is abandoned to finish (or not) in the background.
+#[cfg(not(feature = "no-threads"))]
+fn execute(
+    wrapper: Wrapper,
+    data: String,
+    part: Option<u32>,
+    timeout: Option<Duration>,
+) -> Option<Answers> {
+    use std::sync::mpsc::{RecvTimeoutError, channel};
+    use std::thread;
+
+    let Some(timeout) = timeout else {
+        return Some(wrapper(data, part));
+    };
+
+    let (tx, rx) = channel();
+    thread::spawn(move || tx.send(wrapper(data, part)));
+
+    match rx.recv_timeout(timeout) {
+        Ok(answers) => Some(answers),
+        Err(RecvTimeoutError::Timeout) => {
+            ABANDONED.store(true, Ordering::Relaxed);
+            None
+        }
+        Err(RecvTimeoutError::Disconnected) => panic!("Solution panicked"),
+    }
+}
+
+/// Without threads there is no way to interrupt a solution, so it always runs to completion.
+#[cfg(feature = "no-threads")]
+#[expect(clippy::unnecessary_wraps)]
+fn execute(
+    wrapper: Wrapper,
+    data: String,
+    part: Option<u32>,
+    _: Option<Duration>,
+) -> Option<Answers> {
+    Some(wrapper(data, part))
+}
+
+/// Creates and registers a skeleton solution, for example `new 2025 5` or `scaffold 2025 5`.
+/// The empty input file respects `--input-dir`.
+fn new(options: &[String]) {
+    let usage = || eprintln!("{BOLD}{RED}Usage: new <year> <day> or scaffold <year> <day>{RESET}");
+    let number = |s: &String| s.parse::<u32>().ok();
+
+    let [year, day, ..] = options else {
+        usage();
+        return;
+    };
+    // The range of each number is checked by `scaffold::new` before it touches any files.
+    let (Some(year), Some(day)) = (number(year), number(day)) else {
+        usage();
+        return;
+    };
+
+    match scaffold::new(year, day, input_path(year, day)) {
+        Ok(paths) => {
+            println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+            for path in paths {
+                println!("    {GREEN}Updated{RESET} {}", path.display());
+            }
+        }
+        Err(error) => eprintln!("{BOLD}{RED}{error}{RESET}"),
+    }
+}
+
+/// Imports known answers from an `aocd` cache directory or text file, for example
+/// `import ~/.config/aocd/<token>`.
+fn import(options: &[String]) {
+    let [source, ..] = options else {
+        eprintln!("{BOLD}{RED}Usage: import <directory or file>{RESET}");
+        return;
+    };
+
+    match answers::import(Path::new(source)) {
+        Ok(imported) => {
+            for (year, day, part) in &imported {
+                println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET} Part {part}");
+            }
+            println!("{BOLD}{GREEN}Imported {} answers{RESET}", imported.len());
+        }
+        Err(error) => eprintln!("{BOLD}{RED}{error}{RESET}"),
+    }
+}
+
+/// Runs both parts, or only one part if specified. A part that doesn't run has an empty answer.
+type Wrapper = fn(String, Option<u32>) -> Answers;
+
+struct Answers {
+    part1: String,
+    part2: String,
+    /// Time taken to parse the input, then to solve each part.
+    timings: [Duration; 3],
+    /// A timed out solution was still running in the background, so the timings are unreliable.
+    skewed: bool,
+}
+
+/// Result of running a single solution.
+enum Outcome {
+    Missing,
+    Timeout(Duration),
+    Done(Answers, Duration),
+}
+
+/// Result of comparing an answer against the known answer.
+#[derive(PartialEq, Eq)]
+enum Check {
+    /// The part didn't run.
+    Skipped,
+    Unknown,
+    Pass,
+    /// Contains the expected answer.
+    Fail(String),
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Skipped | Check::Unknown => Ok(()),
+            Check::Pass => write!(f, " {GREEN}PASS{RESET}"),
+            Check::Fail(expected) => write!(f, " {RED}FAIL{RESET} expected {expected}"),
+        }
+    }
+}
+
+/// Summary of repeated samples of the same solution.
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Pretty,
+    Plain,
+    Json,
+    Markdown,
+    Csv,
+}
+
+/// Calls a function, also returning how long it took.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let instant = Instant::now();
+    let result = f();
+    (result, instant.elapsed())
 }
 
 struct Solution {
     year: u32,
     day: u32,
     path: PathBuf,
-    wrapper: fn(String) -> (String, String),
+    wrapper: Wrapper,
 }
 
 macro_rules! run {
@@ -73,19 +1016,23 @@ macro_rules! run {
             vec![$({
                 let year = stringify!($year);
                 let day = stringify!($day);
-                let path = Path::new("input").join(year).join(day).with_extension("txt");
+                let (year, day) = (year.unsigned(), day.unsigned());
+                let path = input_path(year, day);
 
-                let wrapper = |data: String| {
+                let wrapper = |data: String, part: Option<u32>| {
                     use $year::$day::*;
 
-                    let input = parse(&data);
-                    let part1 = part1(&input);
-                    let part2 = part2(&input);
+                    let skip = (String::new(), Duration::ZERO);
+                    let (input, parse) = timed(|| parse(&data));
+                    let (part1, solve1) =
+                        if part == Some(2) { skip.clone() } else { timed(|| part1(&input).to_string()) };
+                    let (part2, solve2) =
+                        if part == Some(1) { skip } else { timed(|| part2(&input).to_string()) };
 
-                    (part1.to_string(), part2.to_string())
+                    Answers { part1, part2, timings: [parse, solve1, solve2], skewed: false }
                 };
 
-                Solution { year: year.unsigned(), day: day.unsigned(), path, wrapper }
+                Solution { year, day, path, wrapper }
             },)*]
         }
     }
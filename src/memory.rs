@@ -0,0 +1,154 @@
+//! Global allocator that measures and caps memory usage.
+//!
+//! Every allocation is passed straight through to the system allocator, while two atomic
+//! counters track the number of bytes currently allocated and the highest value seen since the
+//! last [`reset`]. Between [`reset`] and [`finish`] an allocation that would take the solution
+//! over the `--max-mem` limit is refused instead, so reading inputs and printing answers aren't
+//! limited. A global allocator can't unwind, so the refused allocation aborts the whole run after
+//! printing which day went over the limit.
+//!
+//! Replacing the global allocator needs `unsafe` code and adds an atomic load to every
+//! allocation, so the allocator is only installed with the opt-in `memory` feature. Without it
+//! [`peak`] is always `None`.
+//!
+//! The counters are shared by every thread, so updating them on each allocation would slow down
+//! multithreaded solutions and skew their timings. Tracking is off until [`enable`] is called.
+//! Memory allocated before then may be freed afterwards, so the current total is signed.
+#[cfg(feature = "memory")]
+use aoc::util::ansi::*;
+#[cfg(feature = "memory")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "memory")]
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU32, Ordering::Relaxed};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static BASE: AtomicIsize = AtomicIsize::new(0);
+static MAX: AtomicIsize = AtomicIsize::new(isize::MAX);
+static LIMIT: AtomicIsize = AtomicIsize::new(isize::MAX);
+static YEAR: AtomicU32 = AtomicU32::new(0);
+static DAY: AtomicU32 = AtomicU32::new(0);
+
+#[cfg(feature = "memory")]
+pub(crate) struct Tracking;
+
+#[cfg(feature = "memory")]
+#[allow(unsafe_code)]
+// SAFETY: Every method either delegates to the system allocator with exactly the same arguments
+// or returns null to refuse the allocation, which the `GlobalAlloc` contract allows.
+unsafe impl GlobalAlloc for Tracking {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !reserve(layout.size()) {
+            return null_mut();
+        }
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        let ptr = unsafe { System.alloc(layout) };
+        if ptr.is_null() {
+            release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if !reserve(layout.size()) {
+            return null_mut();
+        }
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if ptr.is_null() {
+            release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        release(layout.size());
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // Only growth counts towards the limit, shrinking is always allowed.
+        let extra = new_size.saturating_sub(layout.size());
+        if !reserve(extra) {
+            return null_mut();
+        }
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`.
+        let next = unsafe { System.realloc(ptr, layout, new_size) };
+        if next.is_null() {
+            release(extra);
+        } else {
+            release(layout.size().saturating_sub(new_size));
+        }
+        next
+    }
+}
+
+/// Counts an allocation, returning `false` without counting it if it would go over the limit.
+#[cfg(feature = "memory")]
+#[inline]
+fn reserve(size: usize) -> bool {
+    if !ENABLED.load(Relaxed) {
+        return true;
+    }
+
+    let size = size as isize;
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    if current - BASE.load(Relaxed) > LIMIT.load(Relaxed) {
+        CURRENT.fetch_sub(size, Relaxed);
+        exceeded();
+        return false;
+    }
+    PEAK.fetch_max(current, Relaxed);
+    true
+}
+
+#[cfg(feature = "memory")]
+#[inline]
+fn release(size: usize) {
+    if ENABLED.load(Relaxed) {
+        CURRENT.fetch_sub(size as isize, Relaxed);
+    }
+}
+
+/// Reports the day that went over the limit, just before the refused allocation aborts the run.
+#[cfg(feature = "memory")]
+#[cold]
+fn exceeded() {
+    // Printing may allocate, so stop tracking first or those allocations would be refused too.
+    ENABLED.store(false, Relaxed);
+    let (year, day) = (YEAR.load(Relaxed), DAY.load(Relaxed));
+    let limit = LIMIT.load(Relaxed) / 1024;
+    eprintln!("{BOLD}{RED}{year} Day {day:02} exceeded the memory limit of {limit} KB{RESET}");
+}
+
+/// Starts tracking allocations, refusing any that would take a solution over `limit` bytes.
+pub(crate) fn enable(limit: usize) {
+    MAX.store(limit.try_into().unwrap_or(isize::MAX), Relaxed);
+    ENABLED.store(true, Relaxed);
+}
+
+/// Starts a new measurement for a solution from the amount of memory currently allocated,
+/// applying the limit until [`finish`].
+pub(crate) fn reset(year: u32, day: u32) {
+    YEAR.store(year, Relaxed);
+    DAY.store(day, Relaxed);
+    LIMIT.store(MAX.load(Relaxed), Relaxed);
+    let current = CURRENT.load(Relaxed);
+    BASE.store(current, Relaxed);
+    PEAK.store(current, Relaxed);
+}
+
+/// Stops applying the limit once a solution has returned. The peak is kept for reporting.
+pub(crate) fn finish() {
+    LIMIT.store(isize::MAX, Relaxed);
+}
+
+/// Highest number of extra bytes allocated at once since the last reset, or `None` if tracking
+/// is not enabled.
+pub(crate) fn peak() -> Option<usize> {
+    let peak = PEAK.load(Relaxed) - BASE.load(Relaxed);
+    ENABLED.load(Relaxed).then_some(peak.max(0) as usize)
+}
@@ -0,0 +1,134 @@
+//! Optional integration with the Advent of Code website, enabled by the `online` feature.
+//!
+//! To keep the crate dependency free, HTTPS requests are made with the `curl` command line tool
+//! which must be installed. Requests are authenticated using the session cookie from the
+//! `AOC_SESSION` environment variable, falling back to the `~/.config/aocd/token` file used by
+//! the popular `aocd` Python tool.
+//!
+//! Inputs are only downloaded if the file is missing, so each is fetched at most once. Multiple
+//! downloads are spaced [`DOWNLOAD_DELAY`] apart.
+//!
+//! The website asks that automated tools limit their request rate, so submissions are spaced at
+//! least a minute apart. The time of the most recent submission is stored in
+//! `answers/last_submission.txt`.
+use crate::answers;
+use std::env::var;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::Write as _;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const USER_AGENT: &str = "github.com/maneatingape/advent-of-code-rust";
+const RATE_LIMIT: u64 = 60;
+pub(crate) const DOWNLOAD_DELAY: Duration = Duration::from_secs(1);
+
+pub(crate) enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(String),
+    AlreadySolved,
+    Unknown,
+}
+
+/// Submits an answer, recording it in the local cache if correct.
+pub(crate) fn submit(year: u32, day: u32, part: usize, answer: &str) -> Result<Verdict, String> {
+    if let Some(accepted) = &answers::load(year, day)[part - 1] {
+        return Err(format!("Answer {accepted} has already been accepted"));
+    }
+
+    let last = Path::new("answers").join("last_submission.txt");
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let previous = read_to_string(&last).ok().and_then(|s| s.trim().parse().ok()).unwrap_or(0);
+
+    if now < previous + RATE_LIMIT {
+        return Err(format!("Rate limited, try again in {}s", previous + RATE_LIMIT - now));
+    }
+
+    let url = format!("https://adventofcode.com/{year}/day/{day}/answer");
+    let body = request(&url, &[format!("level={part}"), format!("answer={answer}")])?;
+
+    create_dir_all("answers").map_err(|e| e.to_string())?;
+    write(last, now.to_string()).map_err(|e| e.to_string())?;
+
+    let verdict = parse_response(&body);
+    if matches!(verdict, Verdict::Correct) {
+        answers::save(year, day, part, answer).map_err(|e| e.to_string())?;
+    }
+
+    Ok(verdict)
+}
+
+/// Downloads a puzzle input to `path`, creating any missing parent directories.
+pub(crate) fn download(year: u32, day: u32, path: &Path) -> Result<(), String> {
+    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+    let body = request(&url, &[])?;
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    write(path, body).map_err(|e| e.to_string())
+}
+
+/// The response is a full HTML page, with the interesting text inside the `<article>` element.
+fn parse_response(body: &str) -> Verdict {
+    if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if body.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if body.contains("That's not the right answer") {
+        Verdict::Incorrect
+    } else if let Some((_, suffix)) = body.split_once("You have ") {
+        let wait = suffix.split_once(" left to wait").map_or("", |(wait, _)| wait);
+        Verdict::Wait(wait.to_owned())
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Session cookie from the environment or the `aocd` token file.
+fn session() -> Result<String, String> {
+    if let Ok(session) = var("AOC_SESSION") {
+        return Ok(session);
+    }
+
+    let home = var("HOME").or_else(|_| var("USERPROFILE")).map_err(|e| format!("HOME {e}"))?;
+    let token = Path::new(&home).join(".config").join("aocd").join("token");
+    read_to_string(&token).map_err(|e| format!("AOC_SESSION not set and {}: {e}", token.display()))
+}
+
+/// Makes a `GET` request, or a `POST` request if there are any `name=value` form fields.
+/// Values are URL encoded by curl. The session cookie is passed in a config file on stdin
+/// instead of on the command line, where other users could see it.
+fn request(url: &str, form: &[String]) -> Result<String, String> {
+    let config = format!("cookie = \"session={}\"\n", session()?.trim());
+
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--user-agent", USER_AGENT])
+        .args(["--config", "-"])
+        .args(form.iter().flat_map(|field| ["--data-urlencode", field.as_str()]))
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Unable to run curl: {e}"))?;
+
+    // Dropping stdin closes the pipe so that curl stops reading the config.
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(config.as_bytes()).map_err(|e| format!("Unable to run curl: {e}"))?;
+    }
+    let output = child.wait_with_output().map_err(|e| format!("Unable to run curl: {e}"))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_owned())
+    }
+}
@@ -0,0 +1,215 @@
+//! Generates a skeleton solution for a new day.
+//!
+//! Creates the source, test and empty input files from a template, then declares the module in
+//! its year crate and registers the day with the `run!`, `test!` and `benchmark!` macros.
+//!
+//! A new year also needs a new crate in the `crates` directory. This is added as a dependency
+//! of the top level package and re-exported by its library, then appended as a new macro
+//! invocation after the last existing year.
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SOURCE: &str = "\
+//! # Title
+//!
+//! Description.
+pub fn parse(_input: &str) -> Vec<u32> {
+    vec![]
+}
+
+pub fn part1(_input: &[u32]) -> u32 {
+    123
+}
+
+pub fn part2(_input: &[u32]) -> u32 {
+    456
+}
+";
+
+const MANIFEST: &str = "\
+[package]
+name = \"aoc-{year}\"
+description = \"Advent of Code {number}.\"
+version.workspace = true
+edition.workspace = true
+rust-version.workspace = true
+
+[dependencies]
+aoc-util.workspace = true
+
+[lints]
+workspace = true
+";
+
+const TEST: &str = "\
+use aoc::{year}::{day}::*;
+
+const EXAMPLE: &str = \"\";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 123);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 456);
+}
+";
+
+/// Returns the paths of every file created or modified.
+/// The empty input file is created at `input`, unless it already exists.
+/// Nothing is touched unless the year is from 2015 to the current year and the day from 1 to 25.
+pub(crate) fn new(year: u32, day: u32, input: PathBuf) -> io::Result<Vec<PathBuf>> {
+    let latest = current_year();
+    if !(2015..=latest).contains(&year) || !(1..=25).contains(&day) {
+        let message = format!("Year must be from 2015 to {latest} and day from 1 to 25");
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+    }
+
+    let year = format!("year{year}");
+    let day = format!("day{day:02}");
+
+    let krate = Path::new("crates").join(format!("aoc-{year}"));
+    let source = krate.join("src").join(&day).with_extension("rs");
+    if source.exists() {
+        let message = format!("{} already exists", source.display());
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, message));
+    }
+
+    let test = Path::new("tests").join(&year).join(&day).with_extension("rs");
+    let modules = krate.join("src").join("lib.rs");
+    let main = Path::new("src").join("main.rs");
+    let tests = Path::new("tests").join("test.rs");
+    let benchmark = Path::new("benches").join("benchmark.rs");
+    let mut paths = Vec::new();
+
+    if !krate.exists() {
+        let manifest = krate.join("Cargo.toml");
+        let package = Path::new("Cargo.toml");
+        let lib = Path::new("src").join("lib.rs");
+
+        let number = &year[4..];
+        create(&manifest, &MANIFEST.replace("{year}", &year).replace("{number}", number))?;
+        create(&modules, &format!("//! # Advent of Code {number}.\n\n"))?;
+
+        let dependency = format!("aoc-{year} = {{ path = \"crates/aoc-{year}\" }}");
+        insert_after_last(package, "crates/aoc-year", &dependency)?;
+        let export = format!("#[doc(inline)]\npub use aoc_{year} as {year};");
+        insert_after_last(&lib, "pub use aoc_year", &export)?;
+
+        paths.extend([manifest, package.to_path_buf(), lib]);
+    }
+
+    create(&source, SOURCE)?;
+    create(&test, &TEST.replace("{year}", &year).replace("{day}", &day))?;
+    if !input.exists() {
+        create(&input, "")?;
+    }
+
+    declare(&modules, &day)?;
+    register(&main, "run", &year, &day)?;
+    register(&tests, "test", &year, &day)?;
+    register(&benchmark, "benchmark", &year, &day)?;
+
+    // The runner also needs to chain the solutions for a new year.
+    let text = read_to_string(&main)?;
+    let chain = format!(".chain({year}())");
+    if !text.contains(&chain) {
+        let last = text.rfind(".chain(").unwrap();
+        let end = last + text[last..].find('\n').unwrap() + 1;
+        let indent = &text[text[..last].rfind('\n').unwrap() + 1..last];
+        let text = format!("{}{indent}{chain}\n{}", &text[..end], &text[end..]);
+        write(&main, text)?;
+    }
+
+    paths.extend([source, test, input, modules, main, tests, benchmark]);
+    Ok(paths)
+}
+
+/// Current year in UTC, converting days since the Unix epoch to a date with
+/// [this algorithm](https://howardhinnant.github.io/date_algorithms.html#civil_from_days).
+fn current_year() -> u32 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    // Shift the epoch to March 1st of year 0, so that leap days are at the end of each year.
+    let days = seconds / 86400 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // January and February belong to the next calendar year.
+    let month = (5 * day_of_year + 2) / 153;
+    (400 * era + year_of_era + u64::from(month >= 10)) as u32
+}
+
+fn create(path: &Path, contents: &str) -> io::Result<()> {
+    create_dir_all(path.parent().unwrap())?;
+    write(path, contents)
+}
+
+/// Adds a `pub mod` declaration to a year crate, keeping days sorted before any helper modules.
+fn declare(path: &Path, day: &str) -> io::Result<()> {
+    let text = read_to_string(path)?;
+    let start = text.find("pub mod ").unwrap_or(text.len());
+
+    let mut items: Vec<_> = text[start..]
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod ")?.strip_suffix(';'))
+        .map(str::to_owned)
+        .collect();
+
+    if !items.iter().any(|item| item == day) {
+        items.push(day.to_owned());
+    }
+    items.sort_unstable_by_key(|item| (!item.starts_with("day"), item.clone()));
+
+    let lines: String = items.iter().map(|item| format!("pub mod {item};\n")).collect();
+    write(path, format!("{}{lines}", &text[..start]))
+}
+
+/// Inserts `line` after the last line containing `marker`.
+fn insert_after_last(path: &Path, marker: &str, line: &str) -> io::Result<()> {
+    let text = read_to_string(path)?;
+    let last = text.rfind(marker).unwrap();
+    let end = last + text[last..].find('\n').map_or(text.len() - last, |i| i + 1);
+    write(path, format!("{}{line}\n{}", &text[..end], &text[end..]))
+}
+
+/// Adds a day to the macro invocation for a year, keeping days sorted and wrapped 13 per line.
+/// If the year is missing then a new invocation is appended after the last one.
+fn register(path: &Path, name: &str, year: &str, day: &str) -> io::Result<()> {
+    let text = read_to_string(path)?;
+    let prefix = format!("{name}!(");
+    let header = format!("{prefix}{year}");
+
+    let (start, end, header, mut items) = if let Some(start) = text.find(&header) {
+        let header_end = start + text[start..].find('\n').unwrap();
+        let end = header_end + text[header_end..].find(");").unwrap() + 2;
+        let items: Vec<_> = text[header_end..end - 2]
+            .split(|c: char| c == ',' || c.is_ascii_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(str::to_owned)
+            .collect();
+        (start, end, text[start..header_end].to_owned(), items)
+    } else {
+        let last = text.rfind(&prefix).unwrap();
+        let end = last + text[last..].find(");").unwrap() + 2;
+        let header = format!("\n\n{header}");
+        (end, end, header, Vec::new())
+    };
+
+    if !items.iter().any(|item| item == day) {
+        items.push(day.to_owned());
+    }
+    // Days sort before any helper modules such as `intcode`.
+    items.sort_unstable_by_key(|item| (!item.starts_with("day"), item.clone()));
+
+    let lines: Vec<_> = items.chunks(13).map(|chunk| chunk.join(", ")).collect();
+    let block = format!("{header}\n    {}\n);", lines.join(",\n    "));
+    write(path, format!("{}{block}{}", &text[..start], &text[end..]))
+}
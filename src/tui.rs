@@ -0,0 +1,300 @@
+//! Interactive dashboard showing every selected solution in a grid of years by days.
+//!
+//! Each cell shows one star per part, colored by comparing the answer to the known answer:
+//! green for pass, red for fail and yellow if there's nothing to compare against. Details of
+//! the selected day, including the answers and runtimes, are shown below the grid. Solutions
+//! only run when asked, so opening the dashboard is instant.
+//!
+//! To avoid dependencies the terminal is switched into raw mode with the `stty` command, then
+//! drawn on the alternate screen with [ANSI escape codes](https://en.wikipedia.org/wiki/ANSI_escape_code).
+//! The previous terminal state is restored on exit, even if a solution panics.
+use crate::{Check, Outcome, Solution, answers, run, verify};
+use aoc::util::ansi::*;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, Read as _, Write as _};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const ALTERNATE_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const MAIN_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+const REVERSE: &str = "\x1b[7m";
+const CLEAR_LINE: &str = "\x1b[K";
+
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Run,
+    RunAll,
+    Quit,
+    Other,
+}
+
+/// Raw mode terminal, restored to its original settings when dropped.
+struct Terminal {
+    saved: String,
+}
+
+impl Terminal {
+    fn new() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+        print!("{ALTERNATE_SCREEN}");
+        Ok(Terminal { saved: saved.trim().to_owned() })
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        print!("{MAIN_SCREEN}");
+        io::stdout().flush().unwrap_or_default();
+
+        if stty(&[&self.saved]).is_err() {
+            eprintln!("{BOLD}{RED}Unable to restore the terminal, try running stty sane{RESET}");
+        }
+    }
+}
+
+/// Latest result for a single solution.
+struct Cell {
+    outcome: Outcome,
+    checks: [Check; 2],
+}
+
+struct Dashboard<'a> {
+    solutions: &'a [Solution],
+    years: Vec<u32>,
+    cells: Vec<Option<Cell>>,
+    row: usize,
+    column: usize,
+}
+
+/// Shows the dashboard until the user quits.
+pub(crate) fn dashboard(solutions: &[Solution], part: Option<u32>, timeout: Option<Duration>) {
+    let Some(first) = solutions.first() else {
+        return;
+    };
+
+    let mut years: Vec<_> = solutions.iter().map(|s| s.year).collect();
+    years.dedup();
+
+    let cells = solutions.iter().map(|_| None).collect();
+    let column = first.day as usize - 1;
+    let mut dashboard = Dashboard { solutions, years, cells, row: 0, column };
+
+    let _terminal = match Terminal::new() {
+        Ok(terminal) => terminal,
+        Err(error) => {
+            eprintln!("{BOLD}{RED}Unable to switch the terminal to raw mode: {error}{RESET}");
+            return;
+        }
+    };
+
+    loop {
+        dashboard.draw();
+
+        match read_key() {
+            Key::Up => dashboard.row = dashboard.row.saturating_sub(1),
+            Key::Down => dashboard.row = (dashboard.row + 1).min(dashboard.years.len() - 1),
+            Key::Left => dashboard.column = dashboard.column.saturating_sub(1),
+            Key::Right => dashboard.column = (dashboard.column + 1).min(24),
+            Key::Run => {
+                if let Some(index) = dashboard.selected() {
+                    dashboard.run(index, part, timeout);
+                }
+            }
+            Key::RunAll => {
+                for index in 0..solutions.len() {
+                    if dashboard.cells[index].is_none() {
+                        dashboard.run(index, part, timeout);
+                        dashboard.draw();
+                    }
+                }
+            }
+            Key::Quit => break,
+            Key::Other => (),
+        }
+    }
+}
+
+impl Dashboard<'_> {
+    /// Index of the solution under the cursor, if there is one.
+    fn selected(&self) -> Option<usize> {
+        self.index(self.years[self.row], self.column as u32 + 1)
+    }
+
+    fn index(&self, year: u32, day: u32) -> Option<usize> {
+        self.solutions.iter().position(|s| s.year == year && s.day == day)
+    }
+
+    fn run(&mut self, index: usize, part: Option<u32>, timeout: Option<Duration>) {
+        let solution = &self.solutions[index];
+        let outcome = run(solution, None, part, timeout);
+
+        let checks = match &outcome {
+            Outcome::Done(answers, _) => {
+                verify(answers, answers::load(solution.year, solution.day), part)
+            }
+            _ => [Check::Skipped, Check::Skipped],
+        };
+
+        self.cells[index] = Some(Cell { outcome, checks });
+    }
+
+    fn draw(&self) {
+        let mut frame = String::new();
+        let _ = write!(frame, "{HOME}{BOLD}{WHITE}Advent of Code{RESET}  ");
+        let _ = write!(frame, "arrows move, enter run, a run all, q quit{CLEAR_LINE}\r\n\r\n");
+
+        frame.push_str("     ");
+        for day in 1..26 {
+            let _ = write!(frame, "{day:>3}");
+        }
+        frame.push_str("  stars      time\r\n");
+
+        for (row, &year) in self.years.iter().enumerate() {
+            let mut stars = 0;
+            let mut total = Duration::ZERO;
+            let _ = write!(frame, "{BOLD}{YELLOW}{year}{RESET} ");
+
+            for column in 0..25 {
+                let highlight = if (row, column) == (self.row, self.column) { REVERSE } else { "" };
+                let cell = self.index(year, column as u32 + 1).map(|index| &self.cells[index]);
+
+                let glyph = match cell {
+                    None => "   ".to_owned(),
+                    Some(None) => "  .".to_owned(),
+                    Some(Some(Cell { outcome: Outcome::Missing, .. })) => format!(" {RED}--"),
+                    Some(Some(Cell { outcome: Outcome::Timeout(_), .. })) => format!(" {RED}TT"),
+                    Some(Some(Cell { outcome: Outcome::Done(answers, elapsed), checks })) => {
+                        stars += checks.iter().filter(|&c| *c == Check::Pass).count();
+                        if !answers.skewed {
+                            total += *elapsed;
+                        }
+                        format!(" {}{}", star(&checks[0]), star(&checks[1]))
+                    }
+                };
+                let _ = write!(frame, "{highlight}{glyph}{RESET}");
+            }
+
+            let _ = write!(frame, "  {stars:>5} {:>9}{CLEAR_LINE}\r\n", format_duration(total));
+        }
+
+        frame.push_str(CLEAR_LINE);
+        frame.push_str("\r\n");
+        self.details(&mut frame);
+        frame.push_str(CLEAR);
+
+        // Nothing useful can be done if the terminal has gone away.
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(frame.as_bytes()).and_then(|()| stdout.flush()).unwrap_or_default();
+    }
+
+    /// Answers and timings of the selected solution.
+    fn details(&self, frame: &mut String) {
+        let (year, day) = (self.years[self.row], self.column + 1);
+        let _ = write!(frame, "{BOLD}{YELLOW}{year} Day {day:02}{RESET}  ");
+
+        let Some(index) = self.selected() else {
+            let _ = write!(frame, "Not solved{CLEAR_LINE}\r\n");
+            return;
+        };
+
+        match &self.cells[index] {
+            None => {
+                let _ = write!(frame, "Press enter to run{CLEAR_LINE}\r\n");
+            }
+            Some(Cell { outcome: Outcome::Missing, .. }) => {
+                let path = self.solutions[index].path.display();
+                let _ = write!(frame, "{RED}Missing input{RESET} {path}{CLEAR_LINE}\r\n");
+            }
+            Some(Cell { outcome: Outcome::Timeout(elapsed), .. }) => {
+                let elapsed = format_duration(*elapsed);
+                let _ = write!(frame, "{RED}Timed out{RESET} after {elapsed}, ");
+                let _ = write!(frame, "still running in the background{CLEAR_LINE}\r\n");
+            }
+            Some(Cell { outcome: Outcome::Done(answers, elapsed), checks }) => {
+                let [parse, part1, part2] = if answers.skewed {
+                    // A timed out solution is still running in the background.
+                    let _ = write!(frame, "{YELLOW}Timings skewed by a timed out day{RESET}");
+                    let _ = write!(frame, "{CLEAR_LINE}\r\n");
+                    ["-".to_owned(), "-".to_owned(), "-".to_owned()]
+                } else {
+                    let _ = write!(frame, "{}{CLEAR_LINE}\r\n", format_duration(*elapsed));
+                    answers.timings.map(format_duration)
+                };
+                let _ = write!(frame, "    Parse  {parse:>9}{CLEAR_LINE}\r\n");
+
+                for (part, answer, time, check) in
+                    [(1, &answers.part1, part1, &checks[0]), (2, &answers.part2, part2, &checks[1])]
+                {
+                    let answer = answer.replace('\n', "\r\n");
+                    let _ =
+                        write!(frame, "    Part {part} {time:>9}  {answer}{check}{CLEAR_LINE}\r\n");
+                }
+            }
+        }
+    }
+}
+
+fn star(check: &Check) -> String {
+    match check {
+        Check::Skipped => " ".to_owned(),
+        Check::Unknown => format!("{YELLOW}*"),
+        Check::Pass => format!("{GREEN}*"),
+        Check::Fail(_) => format!("{RED}x"),
+    }
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    if elapsed < Duration::from_millis(10) {
+        format!("{} μs", elapsed.as_micros())
+    } else {
+        format!("{} ms", elapsed.as_millis())
+    }
+}
+
+/// Blocks until a key is pressed. Arrow keys are sent as three byte escape sequences.
+fn read_key() -> Key {
+    let mut stdin = io::stdin().lock();
+    let mut next = || {
+        let mut buffer = [0];
+        stdin.read_exact(&mut buffer).map_or(b'q', |()| buffer[0])
+    };
+
+    match next() {
+        0x1b if next() == b'[' => match next() {
+            b'A' => Key::Up,
+            b'B' => Key::Down,
+            b'C' => Key::Right,
+            b'D' => Key::Left,
+            _ => Key::Other,
+        },
+        b'k' => Key::Up,
+        b'j' => Key::Down,
+        b'l' => Key::Right,
+        b'h' => Key::Left,
+        b'\r' | b'\n' | b' ' | b'r' => Key::Run,
+        b'a' => Key::RunAll,
+        // Raw mode means that Ctrl+C is an ordinary key press instead of a signal.
+        b'q' | 0x03 => Key::Quit,
+        _ => Key::Other,
+    }
+}
+
+/// Runs `stty` against the controlling terminal, returning its output.
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(File::open("/dev/tty")?)
+        .stderr(Stdio::inherit())
+        .output()?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(io::Error::other("stty failed"))
+    }
+}
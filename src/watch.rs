@@ -0,0 +1,107 @@
+//! Re-runs a solution whenever its source or input changes.
+//!
+//! Solutions are compiled into the runner, so a running binary can't pick up an edited source
+//! file. Instead each run is a child `cargo run` with the same arguments and features minus
+//! `--watch`, which recompiles only if needed and then prints answers and timings as usual.
+//!
+//! A day can depend on shared modules in its year, such as the 2019 intcode computer, as well as
+//! the utilities, so every file in both crates is watched along with the input. Changes are
+//! detected by polling modification times, which needs no dependencies and is cheap for a few
+//! dozen files.
+use crate::Solution;
+use aoc::util::ansi::*;
+use std::env::{args, var};
+use std::fs::{metadata, read_dir};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+/// How often to check the files for changes.
+const INTERVAL: Duration = Duration::from_millis(250);
+
+/// Runs once immediately, then again every time the source or input of the only selected
+/// solution is modified. Only returns if there isn't exactly one solution.
+pub(crate) fn watch(solutions: &[Solution]) {
+    let [Solution { year, path, .. }] = solutions else {
+        eprintln!("{BOLD}{RED}--watch needs exactly one day, for example 2022 16{RESET}");
+        return;
+    };
+
+    let crates = Path::new("crates");
+    poll(&[crates.join(format!("aoc-year{year}")), crates.join("aoc-util"), path.clone()]);
+}
+
+/// Calls [`rerun`] immediately, then again whenever a file is added, removed or modified in
+/// any of the paths.
+fn poll(paths: &[PathBuf]) -> ! {
+    let modified = || {
+        let mut files = Vec::new();
+        for path in paths {
+            walk(path, &mut files);
+        }
+        files
+    };
+    let mut previous = None;
+
+    loop {
+        let current = modified();
+        if previous.as_ref() != Some(&current) {
+            rerun();
+            println!("{BOLD}{WHITE}Watching for changes, press Ctrl+C to stop{RESET}");
+            previous = Some(current);
+        }
+        sleep(INTERVAL);
+    }
+}
+
+/// Modification time of a file, or of every file in a directory and its subdirectories.
+/// Missing paths are recorded too, so that creating one counts as a change.
+fn walk(path: &Path, files: &mut Vec<(PathBuf, Option<SystemTime>)>) {
+    if let Ok(entries) = read_dir(path) {
+        let mut entries: Vec<_> =
+            entries.filter_map(|entry| entry.ok().map(|e| e.path())).collect();
+        entries.sort_unstable();
+        for entry in &entries {
+            walk(entry, files);
+        }
+    } else {
+        files.push((path.to_path_buf(), metadata(path).and_then(|m| m.modified()).ok()));
+    }
+}
+
+/// Builds and runs the current sources with the same profile and features as this binary.
+fn rerun() {
+    let cargo = var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let features = features();
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+    command.arg("--").args(args().skip(1).filter(|a| a != "--watch"));
+
+    // Build errors are printed by cargo itself, so keep watching regardless of the exit status.
+    if let Err(error) = command.status() {
+        eprintln!("{BOLD}{RED}Unable to run cargo: {error}{RESET}");
+    }
+}
+
+/// Features that this binary was built with, so that the child is built the same way.
+fn features() -> Vec<&'static str> {
+    [
+        ("compare", cfg!(feature = "compare")),
+        ("frivolity", cfg!(feature = "frivolity")),
+        ("memory", cfg!(feature = "memory")),
+        ("no-threads", cfg!(feature = "no-threads")),
+        ("online", cfg!(feature = "online")),
+        ("pin", cfg!(feature = "pin")),
+        ("simd", cfg!(feature = "simd")),
+    ]
+    .into_iter()
+    .filter_map(|(name, enabled)| enabled.then_some(name))
+    .collect()
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests and benchmarks.
+
+/// Deterministic pseudo random numbers from a
+/// [linear congruential generator](https://en.wikipedia.org/wiki/Linear_congruential_generator),
+/// so that any failure is reproducible.
+pub(crate) struct Lcg(u64);
+
+impl Lcg {
+    pub(crate) fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    /// The top 31 bits of the state, as the low bits have short periods.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 =
+            self.0.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        self.0 >> 33
+    }
+
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        self.next_u64() as usize % n
+    }
+
+    pub(crate) fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+}
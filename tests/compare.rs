@@ -0,0 +1,126 @@
+//! Checks that the scalar and SIMD versions of each kernel agree on the same random inputs.
+//!
+//! Run with `cargo +nightly test --release --features compare --test compare`. The `compare`
+//! feature builds both versions side by side, which a normal `simd` build does not.
+// Every year crate is a dependency of the test target, but is only used through `aoc`.
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "compare")]
+
+mod common;
+
+use aoc::util::md5;
+use aoc::util::point::*;
+use aoc::{year2018, year2019, year2022, year2023, year2024};
+use common::Lcg;
+use std::iter::repeat_with;
+
+fn md5_lanes<const N: usize>(rng: &mut Lcg) {
+    for size in 0..56 {
+        let mut buffers = [[0; 64]; N];
+        for buffer in &mut buffers {
+            buffer[..size].iter_mut().for_each(|b| *b = rng.next_u64() as u8);
+        }
+
+        let mut copies = buffers;
+        let (a, b, c, d) = md5::simd::hash::<N>(&mut copies, size);
+
+        for (lane, buffer) in buffers.iter_mut().enumerate() {
+            let expected = md5::hash(buffer, size);
+            assert_eq!((a[lane], b[lane], c[lane], d[lane]), expected, "size {size} lane {lane}");
+        }
+    }
+}
+
+#[test]
+fn md5_test() {
+    let mut rng = Lcg::new(1);
+    md5_lanes::<8>(&mut rng);
+    md5_lanes::<16>(&mut rng);
+    md5_lanes::<32>(&mut rng);
+}
+
+#[test]
+fn year2018_day18_test() {
+    use year2018::day18::*;
+    let mut rng = Lcg::new(2);
+
+    for _ in 0..20 {
+        let width = 1 + rng.below(50);
+        let height = 1 + rng.below(50);
+        let input: String = repeat_with(|| {
+            repeat_with(|| rng.pick(b".|#") as char).take(width).chain(['\n']).collect::<String>()
+        })
+        .take(height)
+        .collect();
+
+        let mut area = parse(&input);
+        for generation in 0..100 {
+            let next = scalar::step(&area);
+            assert!(next == simd::step(&area), "generation {generation} of\n{input}");
+            area = next;
+        }
+    }
+}
+
+#[test]
+fn year2019_day16_test() {
+    use year2019::day16::*;
+    let mut rng = Lcg::new(3);
+
+    for _ in 0..20 {
+        let size = 8 + rng.below(100);
+        let digits: Vec<_> = repeat_with(|| rng.below(10)).take(size).collect();
+        let start = rng.below(size * 10);
+        let upper = start + rng.below(size * 10);
+
+        let expected = scalar::compute(&digits, size, start, upper);
+        assert_eq!(simd::compute(&digits, size, start, upper), expected, "{digits:?} {start}");
+    }
+}
+
+#[test]
+fn year2022_day09_test() {
+    use year2022::day09::*;
+
+    for front_x in -3..=3 {
+        for front_y in -3..=3 {
+            let front = Point::new(front_x, front_y);
+            let mut first = ORIGIN;
+            let mut second = ORIGIN;
+
+            assert_eq!(scalar::follow(front, &mut first), simd::follow(front, &mut second));
+            assert_eq!(first, second, "front {front:?}");
+        }
+    }
+}
+
+#[test]
+fn year2023_day15_test() {
+    use year2023::day15::*;
+    let mut rng = Lcg::new(4);
+
+    let input: Vec<_> =
+        repeat_with(|| rng.pick(b"abcdefghijklmnopqrstuvwxyz-=0123456789")).take(1000).collect();
+
+    // Include slices near the start of the input and longer than 16 bytes, which fall back to
+    // the scalar hash.
+    for _ in 0..1000 {
+        let end = rng.below(input.len() + 1);
+        let start = end.saturating_sub(rng.below(24));
+        let slice = &input[start..end];
+        assert_eq!(simd::hash_in(&input, end, slice), hash(slice), "{start}..{end}");
+    }
+}
+
+#[test]
+fn year2024_day22_test() {
+    use year2024::day22::*;
+    let mut rng = Lcg::new(5);
+
+    // Include counts that aren't a multiple of the number of lanes.
+    for count in [1, 7, 8, 9, 100] {
+        let input: String =
+            repeat_with(|| format!("{}\n", rng.next_u64() & 0xffffff)).take(count).collect();
+        assert_eq!(scalar::parse(&input), simd::parse(&input), "{input}");
+    }
+}
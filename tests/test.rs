@@ -28,6 +28,11 @@
 //     assert_eq!(part2(&input), 456);
 // }
 
+// Every year crate is a dependency of the test target, but is only used through `aoc`.
+#![allow(unused_crate_dependencies)]
+
+mod common;
+
 macro_rules! test {
     ($year:tt $($day:tt),*) => {
         pub mod $year {$(pub mod $day;)*}
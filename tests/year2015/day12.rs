@@ -13,3 +13,14 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(input), 4);
 }
+
+#[test]
+fn sum_numbers_test() {
+    assert_eq!(sum_numbers("[1,2,3]", false), 6);
+    assert_eq!(sum_numbers(r#"{"a":{"b":4},"c":-1}"#, false), 3);
+    assert_eq!(sum_numbers(r#"[-1,{"a":1}]"#, false), 0);
+    assert_eq!(sum_numbers("[]", false), 0);
+    assert_eq!(sum_numbers(r#"[1,"red",5]"#, true), 6);
+    assert_eq!(sum_numbers(r#"{"d":"red","e":[1,2,3,4],"f":5}"#, true), 0);
+    assert_eq!(sum_numbers(r#"{"red":1,"b":[2,{"c":"red","d":3}]}"#, true), 3);
+}
@@ -1,3 +1,5 @@
+use aoc::util::iter::*;
+use aoc::util::parse::*;
 use aoc::year2015::day15::*;
 
 const EXAMPLE: &str = "\
@@ -17,3 +19,11 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 57600000);
 }
+
+#[test]
+fn hill_climb_test() {
+    // Splitting each ingredient into two halves doesn't change the best recipe.
+    let input: Vec<Ingredient> = EXAMPLE.iter_signed().chunk::<5>().collect();
+    let doubled: Vec<_> = input.iter().flat_map(|&ingredient| [ingredient, ingredient]).collect();
+    assert_eq!(best_score(&doubled, 500), (62842880, 57600000));
+}
@@ -1,3 +1,5 @@
+use aoc::year2015::rpg::*;
+
 #[test]
 fn part1_test() {
     // No example data
@@ -7,3 +9,20 @@ fn part1_test() {
 fn part2_test() {
     // No example data
 }
+
+#[test]
+fn fight_test() {
+    // Example fight from the puzzle description.
+    let hero = Stats { health: 8, damage: 5, armor: 5 };
+    let boss = Stats { health: 12, damage: 7, armor: 2 };
+    assert_eq!(hero.turns_to_defeat(boss), 4);
+    assert!(hero.defeats(boss));
+}
+
+#[test]
+fn loadouts_test() {
+    let loadouts: Vec<_> = loadouts().collect();
+    // 5 weapons * 6 armor choices * 22 ring choices.
+    assert_eq!(loadouts.len(), 660);
+    assert_eq!(loadouts.iter().map(|item| item.cost).min(), Some(8));
+}
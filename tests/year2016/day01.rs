@@ -14,3 +14,10 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), 4);
 }
+
+#[test]
+fn part2_overlap_test() {
+    // Walking back along a previous segment revisits the nearest block first.
+    let input = parse("R2, L2, L4, L2, L3");
+    assert_eq!(part2(&input), 1);
+}
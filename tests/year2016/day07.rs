@@ -23,3 +23,20 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(input), 3);
 }
+
+#[test]
+fn supports_tls_test() {
+    assert!(supports_tls(b"abba[mnop]qrst"));
+    assert!(!supports_tls(b"abcd[bddb]xyyx"));
+    assert!(!supports_tls(b"aaaa[qwer]tyui"));
+    assert!(supports_tls(b"ioxxoj[asdfgh]zxcvbn"));
+    assert!(!supports_tls(b"xyyx[ab[cddc]ef]gh"));
+}
+
+#[test]
+fn supports_ssl_test() {
+    assert!(supports_ssl(b"aba[bab]xyz"));
+    assert!(!supports_ssl(b"xyx[xyx]xyx"));
+    assert!(supports_ssl(b"aaa[kek]eke"));
+    assert!(supports_ssl(b"zazbz[bzb]cdb"));
+}
@@ -1,3 +1,11 @@
+use aoc::year2016::day08::*;
+
+const EXAMPLE: &str = "\
+rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1";
+
 #[test]
 fn part1_test() {
     // No example data
@@ -7,3 +15,14 @@ fn part1_test() {
 fn part2_test() {
     // No example data
 }
+
+#[test]
+fn display_test() {
+    let mut display = Display::new(7, 3);
+    for line in EXAMPLE.lines() {
+        display.apply(line);
+    }
+
+    assert_eq!(display.grid.render(), ".#..#.#\n#.#....\n.#.....");
+    assert_eq!(display.lit(), 6);
+}
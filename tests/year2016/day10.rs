@@ -1,3 +1,13 @@
+use aoc::year2016::day10::*;
+
+const EXAMPLE: &str = "\
+value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2";
+
 #[test]
 fn part1_test() {
     // No example data
@@ -5,5 +15,19 @@ fn part1_test() {
 
 #[test]
 fn part2_test() {
-    // No example data
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 30);
+}
+
+#[test]
+fn factory_test() {
+    let factory = factory(EXAMPLE);
+    assert_eq!(factory.comparer(2, 5), Some(2));
+    assert_eq!(factory.comparer(2, 3), Some(1));
+    assert_eq!(factory.comparer(3, 5), Some(0));
+    assert_eq!(factory.comparer(17, 61), None);
+    assert_eq!(factory.output(0), Some(5));
+    assert_eq!(factory.output(1), Some(2));
+    assert_eq!(factory.output(2), Some(3));
+    assert_eq!(factory.output(3), None);
 }
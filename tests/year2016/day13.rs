@@ -1,9 +1,42 @@
+use aoc::year2016::day13::*;
+
 #[test]
 fn part1_test() {
-    // No example data
+    assert_eq!(bfs(10, 7, 4).0, 11);
+    assert_eq!(astar(10, 7, 4), Some(11));
+}
+
+#[test]
+fn path_outside_bounds_test() {
+    // The only way to the target goes around outside the initial 52 by 52 bounds.
+    assert_eq!(bfs(755, 50, 48).0, 156);
+    assert_eq!(astar(755, 50, 48), Some(156));
 }
 
 #[test]
 fn part2_test() {
     // No example data
 }
+
+#[test]
+fn is_open_test() {
+    let expected = [".#.####.##", "..#..#...#", "#....##...", "###.#.###.", ".##..#..#."];
+
+    for (y, row) in expected.iter().enumerate() {
+        for (x, b) in row.bytes().enumerate() {
+            assert_eq!(is_open(10, x, y), b == b'.');
+        }
+    }
+}
+
+#[test]
+fn astar_matches_bfs_test() {
+    for favorite in [10, 1352, 1362] {
+        for (x, y) in [(31, 39), (5, 5), (20, 10)] {
+            let (steps, _) = bfs(favorite, x, y);
+            if steps > 0 {
+                assert_eq!(astar(favorite, x, y), Some(steps));
+            }
+        }
+    }
+}
@@ -13,3 +13,11 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 370);
 }
+
+#[test]
+fn paths_test() {
+    assert_eq!(shortest_path("kglvqrro"), "DDUDRLRRUDRD");
+    assert_eq!(shortest_path("ulqzkmiv"), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
+    assert_eq!(longest_path_len("kglvqrro"), 492);
+    assert_eq!(longest_path_len("ulqzkmiv"), 830);
+}
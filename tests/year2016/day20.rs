@@ -17,3 +17,19 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 2);
 }
+
+#[test]
+fn overlapping_test() {
+    // Adjacent, nested, duplicate and unsorted ranges.
+    let input = parse("3-5\n0-2\n10-20\n12-15\n10-20\n6-6\n21-21\n8-8");
+    assert_eq!(input.len(), 3);
+    assert_eq!(part1(&input), 7);
+    assert_eq!(part2(&input), (1 << 32) - 20);
+}
+
+#[test]
+fn leading_gap_test() {
+    let input = parse("1-4294967295");
+    assert_eq!(part1(&input), 0);
+    assert_eq!(part2(&input), 1);
+}
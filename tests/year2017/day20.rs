@@ -1,4 +1,6 @@
+use crate::common::Lcg;
 use aoc::year2017::day20::*;
+use std::iter::repeat_with;
 
 const EXAMPLE: &str = "\
 p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
@@ -17,3 +19,35 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 1);
 }
+
+#[test]
+fn collide_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(collide(&input), [Some(2), Some(2), Some(2), None]);
+    assert_eq!(collide(&input), simulate(&input, 10));
+
+    // Deterministic pseudo random particles.
+    let mut rng = Lcg::new(12345);
+    let mut random = || rng.below(7) as i32 - 3;
+
+    let mut destroyed = 0;
+
+    for _ in 0..20 {
+        let text: Vec<_> = repeat_with(|| {
+            let v: Vec<_> = repeat_with(&mut random).take(9).collect();
+            format!(
+                "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
+                v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7], v[8]
+            )
+        })
+        .take(50)
+        .collect();
+
+        let input = parse(&text.join("\n"));
+        let expected = simulate(&input, 100);
+        assert_eq!(collide(&input), expected);
+        destroyed += expected.iter().filter(|time| time.is_some()).count();
+    }
+
+    assert!(destroyed > 0);
+}
@@ -1,3 +1,4 @@
+use aoc::util::point::*;
 use aoc::year2018::day13::*;
 
 const FIRST_EXAMPLE: &str = r"/->-\         .
@@ -26,3 +27,26 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), "6,4");
 }
+
+#[test]
+fn simulate_test() {
+    let mut mine = parse(FIRST_EXAMPLE);
+    assert_eq!(mine.simulate(true), Some(Point::new(7, 3)));
+
+    let mut mine = parse(SECOND_EXAMPLE);
+    let mut ticks = 0;
+    assert_eq!(mine.simulate_with(false, |_| ticks += 1), None);
+    assert_eq!(ticks, 3);
+    assert_eq!(mine.last_cart(), Some(Point::new(6, 4)));
+}
+
+#[test]
+fn render_test() {
+    let mut mine = parse("->--<-");
+    assert_eq!(mine.render(), "->--<-");
+
+    let mut frames = Vec::new();
+    mine.simulate_with(false, |mine| frames.push(mine.render()));
+    assert_eq!(frames, ["--><--", "------"]);
+    assert_eq!(mine.last_cart(), None);
+}
@@ -13,3 +13,23 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 0);
 }
+
+#[test]
+fn examples_test() {
+    let input = parse("^ENWWW(NEEE|SSE(EE|N))$");
+    assert_eq!(part1(&input), 10);
+
+    let input = parse("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$");
+    assert_eq!(part1(&input), 18);
+
+    let input = parse("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$");
+    assert_eq!(part1(&input), 23);
+}
+
+#[test]
+fn diverging_branches_test() {
+    // Each option ends in a different room, so the final step happens twice.
+    let input = parse("^N(E|W)N$");
+    assert_eq!(part1(&input), 3);
+    assert_eq!(input.bytes.iter().filter(|&&d| d != u32::MAX).count(), 6);
+}
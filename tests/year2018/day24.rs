@@ -20,3 +20,14 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 51);
 }
+
+#[test]
+fn fight_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(fight(&input, 0), Outcome { winner: Kind::Infection, units: 5216 });
+    assert_eq!(fight(&input, 1570), Outcome { winner: Kind::Immune, units: 51 });
+
+    let mut rounds = 0;
+    fight_with_hook(&input, 1570, |round| rounds = round.turn);
+    assert!(rounds > 0);
+}
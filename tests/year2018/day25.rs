@@ -23,3 +23,9 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), "n/a");
 }
+
+#[test]
+fn constellations_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(constellations(&input), [8, 1, 1]);
+}
@@ -23,3 +23,21 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(input), 117607927195067);
 }
+
+#[test]
+fn query_test() {
+    let shuffle = deck(EXAMPLE, 10);
+    let result = [9, 2, 5, 8, 1, 4, 7, 0, 3, 6];
+
+    for (position, card) in result.into_iter().enumerate() {
+        let position = position as i128;
+        assert_eq!(shuffle.card_at(position), card);
+        assert_eq!(shuffle.position_of(card), position);
+    }
+
+    assert_eq!(shuffle.compose(&shuffle.invert()), Affine::identity(10));
+
+    let shuffle = deck(EXAMPLE, 10007);
+    let thrice = shuffle.compose(&shuffle).compose(&shuffle);
+    assert_eq!(shuffle.pow(3), thrice);
+}
@@ -1,9 +1,31 @@
+use aoc::year2019::day23::*;
+
+// Computer 0 starts two chains of packets at computers 1 and 25. Each computer adds its address
+// to `y` and passes the packet on to the next address, until the hop count in `x` runs out and
+// the packet goes to the NAT. Both chains visit every address once so arrive with the same `y`.
+const EXAMPLE: &str = "3,72,1005,72,17,104,1,104,50,104,0,104,25,104,50,104,0,3,73,1008,73,-1,76,1005,76,17,3,74,1006,73,63,1001,72,1,75,1008,75,50,76,1006,76,46,1101,0,0,75,4,75,1001,73,-1,73,4,73,1,74,72,74,4,74,1106,0,17,104,255,104,0,4,74,1106,0,17,0,0,0,0,0";
+
 #[test]
 fn part1_test() {
-    // No example data
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 1225);
 }
 
 #[test]
 fn part2_test() {
-    // No example data
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 1225);
+}
+
+#[test]
+fn network_test() {
+    let code: Vec<_> = EXAMPLE.split(',').map(|s| s.parse().unwrap()).collect();
+    let mut packets = Vec::new();
+    network(&code, |packet| packets.push(packet));
+
+    // 52 packets for each chain including the one to the NAT, then the NAT waking up computer 0,
+    // which passes the packet straight back.
+    assert_eq!(packets.len(), 106);
+    assert_eq!(packets.iter().filter(|p| p.to == NAT).count(), 3);
+    assert_eq!(packets.iter().filter(|p| p.from == NAT).count(), 1);
 }
@@ -1,3 +1,4 @@
+use aoc::year2020::console::{Exit, patch, patches, repair, run};
 use aoc::year2020::day08::*;
 
 const EXAMPLE: &str = "\
@@ -22,3 +23,18 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 8);
 }
+
+#[test]
+fn repair_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(repair(&input), Some((7, 8)));
+    assert_eq!(run(&patch(&input, 7)), Exit::Halt(8));
+    assert_eq!(patches(&input).collect::<Vec<_>>(), [0, 2, 4, 7]);
+
+    // Brute force agrees with the linear approach
+    let brute = patches(&input).find_map(|index| match run(&patch(&input, index)) {
+        Exit::Halt(acc) => Some((index, acc)),
+        Exit::Loop(_) => None,
+    });
+    assert_eq!(brute, repair(&input));
+}
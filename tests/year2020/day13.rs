@@ -15,3 +15,23 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 1068781);
 }
+
+#[test]
+fn earliest_timestamp_test() {
+    assert_eq!(earliest_timestamp(&[(0, 17), (2, 13), (3, 19)]), Ok(3417));
+    assert_eq!(earliest_timestamp(&[(0, 67), (1, 7), (2, 59), (3, 61)]), Ok(754018));
+    assert_eq!(earliest_timestamp(&[(0, 67), (2, 7), (3, 59), (4, 61)]), Ok(779210));
+    assert_eq!(earliest_timestamp(&[(0, 67), (1, 7), (3, 59), (4, 61)]), Ok(1261476));
+    assert_eq!(earliest_timestamp(&[(0, 1789), (1, 37), (2, 47), (3, 1889)]), Ok(1202161486));
+}
+
+#[test]
+fn edge_cases_test() {
+    // Offset larger than the bus id.
+    assert_eq!(earliest_timestamp(&[(0, 3), (10, 5)]), Ok(0));
+    // Ids that share a factor but have consistent offsets.
+    assert_eq!(earliest_timestamp(&[(0, 4), (2, 6)]), Ok(4));
+    // Ids that share a factor with contradictory offsets.
+    let expected = Err("No timestamp satisfies every bus offset".to_owned());
+    assert_eq!(earliest_timestamp(&[(0, 4), (1, 6)]), expected);
+}
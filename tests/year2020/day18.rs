@@ -19,3 +19,23 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 694173);
 }
+
+#[test]
+fn evaluate_test() {
+    assert_eq!(evaluate("2 * 3 + (4 * 5)", &LEFT_TO_RIGHT), 26);
+    assert_eq!(evaluate("2 * 3 + (4 * 5)", &ADDITION_FIRST), 46);
+    // Multiplication first is the usual convention.
+    assert_eq!(evaluate("2 * 3 + (4 * 5)", &[(b'+', 1), (b'*', 2)]), 26);
+    assert_eq!(evaluate("1 + 2 * 3 + 4", &[(b'+', 1), (b'*', 2)]), 11);
+
+    let nested = format!("{}7{}", "(".repeat(100_000), ")".repeat(100_000));
+    assert_eq!(evaluate(&nested, &LEFT_TO_RIGHT), 7);
+}
+
+#[test]
+fn evaluator_test() {
+    // Stacks are reused between expressions.
+    let mut evaluator = Evaluator::new(&ADDITION_FIRST);
+    assert_eq!(evaluator.evaluate("2 * 3 + (4 * 5)"), 46);
+    assert_eq!(evaluator.evaluate("1 + 2"), 3);
+}
@@ -120,3 +120,12 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 273);
 }
+
+#[test]
+fn assemble_test() {
+    let input = parse(EXAMPLE);
+    let image = assemble(&input);
+    assert_eq!(image.width, 24);
+    assert_eq!(image.height, 24);
+    assert_eq!(image.bytes.iter().filter(|&&b| b == b'#').count(), 303);
+}
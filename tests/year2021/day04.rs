@@ -1,4 +1,5 @@
 use aoc::year2021::day04::*;
+use std::array::from_fn;
 
 const EXAMPLE: &str = "\
 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
@@ -32,3 +33,16 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 1924);
 }
+
+#[test]
+fn win_turn_test() {
+    let to_turn = from_fn(|n| if n < 25 { 24 - n } else { usize::MAX });
+
+    // Bottom row is drawn first.
+    let board: Board = from_fn(|i| i);
+    assert_eq!(win_turn(&board, &to_turn), 4);
+
+    // Board that never wins as its numbers are never drawn.
+    let board = [99; 25];
+    assert_eq!(win_turn(&board, &to_turn), usize::MAX);
+}
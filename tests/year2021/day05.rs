@@ -1,3 +1,5 @@
+use aoc::util::iter::*;
+use aoc::util::parse::*;
 use aoc::year2021::day05::*;
 
 const EXAMPLE: &str = "\
@@ -23,3 +25,15 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 12);
 }
+
+#[test]
+fn count_overlaps_test() {
+    let lines: Vec<_> = EXAMPLE.iter_unsigned().chunk::<4>().collect();
+    assert_eq!(count_overlaps(&lines, false), 5);
+    assert_eq!(count_overlaps(&lines, true), 12);
+
+    // Moving the lines far from the origin uses the sparse row sweep instead of the grid.
+    let far: Vec<_> = lines.iter().map(|line| line.map(|n| n + 1_000_000_000)).collect();
+    assert_eq!(count_overlaps(&far, false), 5);
+    assert_eq!(count_overlaps(&far, true), 12);
+}
@@ -20,3 +20,10 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 36);
 }
+
+#[test]
+fn compact_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(input.size, 5);
+    assert_eq!(input.weights.iter().sum::<u32>(), 17);
+}
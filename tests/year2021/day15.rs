@@ -23,3 +23,10 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 315);
 }
+
+#[test]
+fn variants_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(materialized(&input), 315);
+    assert_eq!(implicit(&input), 315);
+}
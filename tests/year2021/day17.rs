@@ -13,3 +13,41 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 112);
 }
+
+#[test]
+fn hits_test() {
+    for example in [EXAMPLE, "target area: x=34..67, y=-215..-186", "target area: x=1..3, y=-3..-1"]
+    {
+        let input = parse(example);
+        let mut actual = hits(&input);
+        let mut expected = simulate(&input);
+
+        actual.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(actual, expected);
+    }
+}
+
+fn simulate(input: &[i32; 4]) -> Vec<(i32, i32)> {
+    let &[left, right, bottom, top] = input;
+    let mut result = Vec::new();
+
+    for dx in 1..=right {
+        for dy in bottom..-bottom {
+            let (mut x, mut y, mut vx, mut vy) = (0, 0, dx, dy);
+
+            while x <= right && y >= bottom {
+                if x >= left && y <= top {
+                    result.push((dx, dy));
+                    break;
+                }
+                x += vx;
+                y += vy;
+                vx -= vx.signum();
+                vy -= 1;
+            }
+        }
+    }
+
+    result
+}
@@ -22,3 +22,21 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), "n/a");
 }
+
+#[test]
+fn step_test() {
+    let (state, moved) = step(parse("...>>>>>..."));
+    assert!(moved);
+    assert_eq!(render(&state), "...>>>>.>..\n");
+
+    let (state, moved) = step(state);
+    assert!(moved);
+    assert_eq!(render(&state), "...>>>.>.>.\n");
+
+    let (state, moved) = step(parse("..........\n.>v....v..\n.......>..\n.........."));
+    assert!(moved);
+    assert_eq!(render(&state), "..........\n.>........\n..v....v>.\n..........\n");
+
+    let (_, moved) = step(parse(">v\nv>"));
+    assert!(!moved);
+}
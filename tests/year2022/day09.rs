@@ -21,3 +21,12 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 1);
 }
+
+#[test]
+fn simulate_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(simulate::<1>(&input), 21);
+    assert_eq!(simulate::<2>(&input), 13);
+    // Longer ropes than the puzzle asks for never leave the start.
+    assert_eq!(simulate::<20>(&input), 1);
+}
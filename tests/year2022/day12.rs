@@ -1,3 +1,4 @@
+use aoc::util::point::*;
 use aoc::year2022::day12::*;
 
 const EXAMPLE: &str = "\
@@ -18,3 +19,11 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 29);
 }
+
+#[test]
+fn distance_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(input.distance[input.end], 0);
+    assert_eq!(input.distance[Point::new(0, 4)], 29);
+    assert_eq!(input.distance.bytes.iter().filter(|&&d| d == u32::MAX).count(), 0);
+}
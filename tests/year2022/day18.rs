@@ -26,3 +26,28 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 58);
 }
+
+#[test]
+fn volume_test() {
+    let mut volume = Volume::new();
+    assert!(volume.is_empty());
+
+    // A hollow 3x3x3 shell has an internal air pocket that water can't reach.
+    for x in 1..4 {
+        for y in 1..4 {
+            for z in 1..4 {
+                if (x, y, z) != (2, 2, 2) {
+                    volume.insert(x, y, z);
+                }
+            }
+        }
+    }
+
+    assert_eq!(volume.len(), 26);
+    assert!(!volume.contains(2, 2, 2));
+    assert_eq!(volume.touching(&volume.complement()), 54 + 6);
+
+    let water = volume.flood_fill((0, 0, 0));
+    assert!(!water.contains(2, 2, 2));
+    assert_eq!(volume.touching(&water), 54);
+}
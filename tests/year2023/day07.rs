@@ -1,4 +1,7 @@
+use crate::common::Lcg;
 use aoc::year2023::day07::*;
+use std::array::from_fn;
+use std::iter::repeat_with;
 
 const EXAMPLE: &str = "\
 32T3K 765
@@ -18,3 +21,95 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 5905);
 }
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Tries every useful replacement for each joker, keeping the strongest type. Any card not
+/// already in the hand is equivalent to any other, so only one of those needs to be tried.
+fn naive_classify(cards: [u8; 5], jokers: bool) -> Kind {
+    if jokers {
+        if let Some(index) = cards.iter().position(|&b| b == b'J') {
+            let absent = CARDS.iter().find(|b| **b != b'J' && !cards.contains(b));
+            return CARDS
+                .iter()
+                .filter(|&&b| b != b'J' && (cards.contains(&b) || Some(&b) == absent))
+                .map(|&b| {
+                    let mut next = cards;
+                    next[index] = b;
+                    naive_classify(next, true)
+                })
+                .max()
+                .unwrap();
+        }
+    }
+
+    let mut counts: Vec<_> =
+        CARDS.iter().map(|b| cards.iter().filter(|&c| c == b).count()).filter(|&c| c > 0).collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    match counts[..] {
+        [5] => Kind::FiveOfAKind,
+        [4, 1] => Kind::FourOfAKind,
+        [3, 2] => Kind::FullHouse,
+        [3, 1, 1] => Kind::ThreeOfAKind,
+        [2, 2, 1] => Kind::TwoPair,
+        [2, 1, 1, 1] => Kind::OnePair,
+        _ => Kind::HighCard,
+    }
+}
+
+/// Sorts with a comparator on the naive type then the value of each card in turn.
+fn naive_winnings(hands: &[([u8; 5], usize)], jokers: bool) -> usize {
+    let order: &[u8] = if jokers { b"J23456789TQKA" } else { CARDS };
+    let strength = |cards: &[u8; 5]| cards.map(|b| order.iter().position(|&o| o == b).unwrap());
+
+    let mut hands: Vec<_> =
+        hands.iter().map(|&(cards, bid)| (naive_classify(cards, jokers), cards, bid)).collect();
+    hands.sort_by(|(a, x, _), (b, y, _)| a.cmp(b).then_with(|| strength(x).cmp(&strength(y))));
+    hands.iter().enumerate().map(|(i, (_, _, bid))| (i + 1) * bid).sum()
+}
+
+#[test]
+fn classify_test() {
+    assert_eq!(classify(*b"JJJJJ", true), Kind::FiveOfAKind);
+    assert_eq!(classify(*b"JJJJJ", false), Kind::FiveOfAKind);
+    assert_eq!(classify(*b"2345J", true), Kind::OnePair);
+    assert_eq!(classify(*b"22J33", true), Kind::FullHouse);
+    assert_eq!(classify(*b"2JJ34", true), Kind::ThreeOfAKind);
+    assert_eq!(classify(*b"KTJJT", false), Kind::TwoPair);
+    assert_eq!(classify(*b"KTJJT", true), Kind::FourOfAKind);
+
+    // Every hand made from a small alphabet that includes jokers.
+    let alphabet = b"23JKA";
+    for n in 0..alphabet.len().pow(5) {
+        let cards: [u8; 5] = from_fn(|i| alphabet[n / alphabet.len().pow(i as u32) % 5]);
+        for jokers in [false, true] {
+            assert_eq!(classify(cards, jokers), naive_classify(cards, jokers), "{cards:?}");
+        }
+    }
+}
+
+#[test]
+fn winnings_test() {
+    // Deterministic pseudo random hands.
+    let mut rng = Lcg::new(12345);
+
+    for _ in 0..20 {
+        let hands: Vec<_> = repeat_with(|| {
+            // Bias towards jokers and a few repeated cards to hit the edge cases.
+            let cards = from_fn(|_| if rng.below(4) == 0 { b'J' } else { rng.pick(CARDS) });
+            (cards, 1 + rng.below(1000))
+        })
+        .take(200)
+        .collect();
+
+        let text: Vec<_> = hands
+            .iter()
+            .map(|(cards, bid)| format!("{} {bid}", String::from_utf8_lossy(cards)))
+            .collect();
+        let input = parse(&text.join("\n"));
+
+        assert_eq!(part1(&input), naive_winnings(&hands, false));
+        assert_eq!(part2(&input), naive_winnings(&hands, true));
+    }
+}
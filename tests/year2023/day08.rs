@@ -25,8 +25,63 @@ fn part1_test() {
     assert_eq!(part1(&input), 6);
 }
 
+#[test]
+fn part1_unreachable_test() {
+    let input = parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+    assert_eq!(part1(&input), 0);
+}
+
 #[test]
 fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), 6);
 }
+
+#[test]
+fn method_test() {
+    // The second ghost reaches two different `Z` nodes within its loop.
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(method(&input), Method::Crt);
+
+    // The only ghost stays on `ZZZ` forever, hitting it at every offset of its loop.
+    let input = parse(FIRST_EXAMPLE);
+    assert_eq!(method(&input), Method::Crt);
+
+    // Every ghost reaches its single `Z` node after exactly one period.
+    let ghosts = [
+        Ghost { prefix: 1, period: 3, hits: vec![3] },
+        Ghost { prefix: 2, period: 4, hits: vec![4] },
+    ];
+    assert_eq!(earliest(&ghosts), Some((12, Method::Lcm)));
+}
+
+#[test]
+fn earliest_test() {
+    // Offsets not aligned with the period.
+    let ghosts = [
+        Ghost { prefix: 0, period: 5, hits: vec![2] },
+        Ghost { prefix: 0, period: 7, hits: vec![3] },
+    ];
+    assert_eq!(earliest(&ghosts), Some((17, Method::Crt)));
+
+    // A shared hit before one ghost enters its loop.
+    let ghosts = [
+        Ghost { prefix: 4, period: 2, hits: vec![1, 5] },
+        Ghost { prefix: 0, period: 3, hits: vec![1] },
+    ];
+    assert_eq!(earliest(&ghosts), Some((1, Method::Crt)));
+
+    // Solutions before the prefix are ignored for loop only hits.
+    let ghosts = [
+        Ghost { prefix: 10, period: 3, hits: vec![10] },
+        Ghost { prefix: 0, period: 1, hits: vec![0] },
+    ];
+    assert_eq!(earliest(&ghosts), Some((10, Method::Crt)));
+
+    // Incompatible offsets never line up.
+    let ghosts = [
+        Ghost { prefix: 0, period: 4, hits: vec![1] },
+        Ghost { prefix: 0, period: 6, hits: vec![2] },
+    ];
+    assert_eq!(earliest(&ghosts), None);
+}
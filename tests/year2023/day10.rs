@@ -18,6 +18,30 @@ const SECOND_EXAMPLE: &str = "\
 .L--J.L--J.
 ...........";
 
+const THIRD_EXAMPLE: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+const FOURTH_EXAMPLE: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
 #[test]
 fn part1_test() {
     let input = parse(FIRST_EXAMPLE);
@@ -29,3 +53,13 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), 4);
 }
+
+#[test]
+fn scan_line_test() {
+    for (example, expected) in
+        [(FIRST_EXAMPLE, 1), (SECOND_EXAMPLE, 4), (THIRD_EXAMPLE, 8), (FOURTH_EXAMPLE, 10)]
+    {
+        assert_eq!(part2(&parse(example)), expected);
+        assert_eq!(scan_line(example), expected);
+    }
+}
@@ -13,3 +13,11 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 145);
 }
+
+#[test]
+fn hash_test() {
+    assert_eq!(hash(b"HASH"), 52);
+    assert_eq!(hash(b"rn"), 0);
+    assert_eq!(hash(b"qp"), 1);
+    assert_eq!(hash(b""), 0);
+}
@@ -1,3 +1,4 @@
+use aoc::util::interval::*;
 use aoc::year2023::day19::*;
 
 const EXAMPLE: &str = "\
@@ -30,3 +31,14 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 167409079868000);
 }
+
+#[test]
+fn split_test() {
+    let input = parse(EXAMPLE);
+    let mut branches = Vec::new();
+    split(&input, "in", PartRange::full(), |key, range| branches.push((key, range)));
+
+    let low = PartRange { s: Interval::new(1, 1351), ..PartRange::full() };
+    let high = PartRange { s: Interval::new(1351, 4001), ..PartRange::full() };
+    assert_eq!(branches, [("px", low), ("qqz", high)]);
+}
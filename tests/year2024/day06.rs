@@ -1,3 +1,4 @@
+use aoc::util::point::*;
 use aoc::year2024::day06::*;
 
 const EXAMPLE: &str = "\
@@ -23,3 +24,13 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 6);
 }
+
+#[test]
+fn walk_test() {
+    let input = parse(EXAMPLE);
+    let path = walk(&input);
+
+    assert_eq!(path.len(), 41);
+    assert_eq!(path[0], (Point::new(4, 6), UP));
+    assert_eq!(path[1], (Point::new(4, 5), UP));
+}
@@ -33,3 +33,15 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), 368);
 }
+
+#[test]
+fn perimeters_test() {
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(perimeters(&input), [40, 8, 8]);
+}
+
+#[test]
+fn corners_test() {
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(corners(&input), [12, 4, 4]);
+}
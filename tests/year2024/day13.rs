@@ -28,3 +28,16 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 875318608908);
 }
+
+#[test]
+fn solve_machine_test() {
+    assert_eq!(solve_machine([94, 34], [22, 67], [8400, 5400]), Some([80, 40]));
+    assert_eq!(solve_machine([26, 66], [67, 21], [12748, 12176]), None);
+    assert_eq!(
+        solve_machine([26, 66], [67, 21], [10000000012748, 10000000012176]),
+        Some([118679050709, 103199174542])
+    );
+    // Parallel buttons or a solution that needs negative presses.
+    assert_eq!(solve_machine([1, 1], [2, 2], [6, 6]), None);
+    assert_eq!(solve_machine([1, 0], [0, 1], [-1, 5]), None);
+}
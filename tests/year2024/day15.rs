@@ -1,3 +1,5 @@
+use aoc::util::grid::*;
+use aoc::util::point::*;
 use aoc::year2024::day15::*;
 
 const EXAMPLE: &str = "\
@@ -34,3 +36,67 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 9021);
 }
+
+const SMALL: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+#[test]
+fn resolve_test() {
+    let mut todo = Vec::new();
+    let point = |x, y| Point::new(x, y);
+
+    // Boxes fan out into a diamond.
+    let grid = Grid::parse("########\n#......#\n#.[][].#\n#..[]..#\n#...@..#\n########");
+    let boxes = resolve(&grid, point(4, 4), UP, &mut todo);
+    let expected = [point(3, 3), point(4, 3), point(2, 2), point(3, 2), point(4, 2), point(5, 2)];
+    assert_eq!(boxes, Some(&expected[..]));
+
+    // A wall above only one of the top boxes blocks the entire push.
+    let grid = Grid::parse("########\n#.#....#\n#.[][].#\n#..[]..#\n#...@..#\n########");
+    assert_eq!(resolve(&grid, point(4, 4), UP, &mut todo), None);
+
+    // Boxes stacked exactly on top of each other are only added once.
+    let grid = Grid::parse("########\n#......#\n#..[]..#\n#..[]..#\n#..@...#\n########");
+    let boxes = resolve(&grid, point(3, 4), UP, &mut todo);
+    let expected = [point(3, 3), point(4, 3), point(3, 2), point(4, 2)];
+    assert_eq!(boxes, Some(&expected[..]));
+
+    // Pushing down works the same way.
+    let grid = Grid::parse("########\n#...@..#\n#..[]..#\n#.[][].#\n#......#\n########");
+    let boxes = resolve(&grid, point(4, 1), DOWN, &mut todo).map(<[Point]>::len);
+    assert_eq!(boxes, Some(6));
+}
+
+#[test]
+fn simulate_test() {
+    let input = parse(SMALL);
+    let mut steps = 0;
+    let mut last = String::new();
+
+    simulate(&input, true, |grid, robot| {
+        steps += 1;
+        last = render(grid, robot);
+    });
+
+    assert_eq!(steps, 11);
+    assert_eq!(
+        last,
+        "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+"
+    );
+}
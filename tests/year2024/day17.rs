@@ -25,3 +25,35 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), 117440);
 }
+
+#[test]
+fn computer_test() {
+    // If register C contains 9, the program 2,6 would set register B to 1.
+    let mut computer = Computer::new(&[2, 6], 0, 0, 9);
+    assert_eq!(computer.next(), None);
+    assert_eq!(computer.registers(), [0, 1, 9]);
+
+    // If register A contains 10, the program 5,0,5,1,5,4 would output 0,1,2.
+    let computer = Computer::new(&[5, 0, 5, 1, 5, 4], 10, 0, 0);
+    assert_eq!(computer.collect::<Vec<_>>(), [0, 1, 2]);
+
+    // If register A contains 2024, the program 0,1,5,4,3,0 would output 4,2,5,6,7,7,7,7,3,1,0
+    // and leave 0 in register A.
+    let mut computer = Computer::new(&[0, 1, 5, 4, 3, 0], 2024, 0, 0);
+    assert_eq!(computer.by_ref().collect::<Vec<_>>(), [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+    assert_eq!(computer.registers()[0], 0);
+
+    // If register B contains 2024 and register C contains 43690, the program 4,0 would set
+    // register B to 44354.
+    let mut computer = Computer::new(&[4, 0], 0, 2024, 43690);
+    assert_eq!(computer.next(), None);
+    assert_eq!(computer.registers()[1], 44354);
+}
+
+#[test]
+fn quine_test() {
+    let program = [0, 3, 5, 4, 3, 0];
+    let a = quine(&program, 0, 0).unwrap();
+    assert_eq!(a, 117440);
+    assert!(Computer::new(&program, a, 0, 0).eq(program));
+}
@@ -23,3 +23,12 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 16);
 }
+
+#[test]
+fn arrangements_test() {
+    let towels = Towels::new("r, wr, b, g, bwu, rb, gb, br");
+    assert_eq!(towels.arrangements(b"brwrr"), 2);
+    assert_eq!(towels.arrangements(b"gbbr"), 4);
+    assert_eq!(towels.arrangements(b"rrbgbr"), 6);
+    assert_eq!(towels.arrangements(b"ubwu"), 0);
+}
@@ -1,3 +1,4 @@
+use aoc::util::graph::*;
 use aoc::year2024::day23::*;
 
 const EXAMPLE: &str = "\
@@ -40,8 +41,32 @@ fn part1_test() {
     assert_eq!(part1(&input), 7);
 }
 
+#[test]
+fn part1_last_bit_test() {
+    // `cl` and `tr` are both the last bit of a `u64` word.
+    let input = parse("tr-cl\ncl-ex\nex-tr\ntr-zz\ncl-zz");
+    assert_eq!(part1(&input), 2);
+}
+
 #[test]
 fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), "co,de,ka,ta");
 }
+
+#[test]
+fn max_clique_test() {
+    // Complete graph plus a pendant vertex.
+    let mut graph = BitGraph::new(70);
+    for a in 60..66 {
+        for b in (a + 1)..66 {
+            graph.insert(a, b);
+        }
+    }
+    graph.insert(0, 65);
+    assert_eq!(max_clique(&graph), [60, 61, 62, 63, 64, 65]);
+
+    // Graph with no edges has cliques of a single vertex.
+    let graph = BitGraph::new(3);
+    assert_eq!(max_clique(&graph).len(), 1);
+}